@@ -0,0 +1,7513 @@
+//! State and behavior of the TUI.
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    io::{self, Write},
+    path::PathBuf,
+    process::Stdio,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aws_config::SdkConfig;
+use chrono::Utc;
+use color_eyre::Result;
+use futures::{StreamExt, future, stream};
+use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
+use ratatui::{
+    DefaultTerminal,
+    crossterm::{
+        event::{
+            DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+            KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+        },
+        execute,
+    },
+    layout::{Position, Rect},
+    widgets::{ListState, TableState},
+};
+use regex::Regex;
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    process::Command,
+    task::JoinHandle,
+};
+
+use aws_logs_tui::aws::{self, error::AwsError};
+
+use crate::{
+    cache,
+    config::{AlertPattern, ColumnConfig, Config},
+    event::{AppEvent, EventHandler},
+    history::History,
+    log_level::LogLevel,
+    session::{FavoriteFunction, SavedQuery, Session},
+    theme::Theme,
+    time_range::{self, TimeRange, epoch_millis},
+    timestamp::{TimestampFormat, TimestampStyle},
+};
+use export::{ExportFile, ExportFormat};
+use json_tree::JsonTree;
+use keymap::{Action, Mode};
+use query_builder::QueryBuilder;
+use text_input::TextInput;
+use toast::{Severity, Toasts};
+use volume::Volume;
+
+mod clipboard;
+mod export;
+mod json_tree;
+mod keymap;
+mod log_format;
+mod query_builder;
+mod text_input;
+mod toast;
+mod ui;
+mod volume;
+
+// How often the app is woken up without any other event, to animate and redraw.
+const TICK_RATE: Duration = Duration::from_millis(100);
+
+// Upper bound on the log events loaded into the log viewer at once, older log events are
+// loaded in pages of this size when scrolling past the oldest loaded log event.
+const MAX_LOG_EVENTS: usize = 10_000;
+// Upper bound on the log streams listed for a log group, as busy log groups have thousands.
+const MAX_LOG_STREAMS: usize = 500;
+// Most log streams CloudWatch Logs filters, and tails, the log events of at once.
+const MAX_MARKED_LOG_STREAMS: usize = 100;
+// Most log groups Live Tail tails at once.
+const MAX_TAILED_LOG_GROUPS: usize = 10;
+
+// Most log groups to search at once, as each is searched by calls of its own.
+const MAX_SEARCHED_LOG_GROUPS: usize = 20;
+
+// Share of the main area taken by the list, in percent, and how far it is resized at once.
+const DEFAULT_LIST_PERCENT: u16 = 50;
+const MIN_LIST_PERCENT: u16 = 10;
+const MAX_LIST_PERCENT: u16 = 90;
+const LIST_PERCENT_STEP: u16 = 10;
+
+// Item of the time range picker to input a time range, rather than pick a preset.
+const CUSTOM_TIME_RANGE: &str = "custom...";
+
+const DEFAULT_INSIGHTS_QUERY: &str =
+    "fields @timestamp, @message | sort @timestamp desc | limit 100";
+
+const DEFAULT_INVOKE_PAYLOAD: &str = "{}";
+
+// The most recent executions of a state machine to list.
+const MAX_EXECUTIONS: i32 = 100;
+
+// The most messages of a dead-letter queue to peek at.
+const MAX_FAILED_EVENTS: usize = 50;
+
+// How long an AWS Lambda invocation runs for at most.
+const MAX_INVOCATION_TIME: Duration = Duration::from_secs(15 * 60);
+
+// How long AWS Lambda retries an asynchronous invocation for at most, before it fails the event.
+const MAX_EVENT_AGE: Duration = Duration::from_secs(6 * 60 * 60);
+
+// The most recent builds of a CodeBuild project to list.
+const MAX_BUILDS: usize = 50;
+
+// The most recently viewed log groups to remember.
+const MAX_RECENT_LOG_GROUPS: usize = 20;
+
+// The most recent CloudTrail events of a resource to list.
+const MAX_TRAIL_EVENTS: usize = 200;
+
+// How long the selection of the list stays the same before the details of the selected item
+// are loaded, so scrolling through the list doesn't load those of every item scrolled past.
+const SELECTION_DEBOUNCE: Duration = Duration::from_millis(250);
+
+// Most functions to load the details of at once, such as their tags, when loading them for
+// every function.
+const MAX_CONCURRENT_LOADS: usize = 8;
+
+// How many columns log messages are scrolled horizontally at a time, when not wrapped.
+const COLUMN_SCROLL_STEP: usize = 8;
+
+// Log events downloaded per page, the most that `FilterLogEvents` returns at once.
+const DOWNLOAD_PAGE_SIZE: i32 = 10_000;
+
+// How long to wait between checks on how an export of a log group to S3 is going.
+const EXPORT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+// Credentials that expire again this soon after being loaded again aren't being renewed, so
+// aren't loaded again.
+const MIN_CREDENTIALS_LIFETIME: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+struct FunctionList {
+    functions: Option<Vec<aws::lambda::Function>>,
+    loading: bool,
+    // When the functions last finished loading, successfully or not.
+    loaded_at: Option<Instant>,
+    // The task loading the functions, stopped when loading them again.
+    task: Option<JoinHandle<()>>,
+    // Regions still loading pages of functions.
+    loading_regions: usize,
+    // Whether the functions are those cached by an earlier run, listed until all pages of
+    // them load again, and the pages loaded so far, unless loading them failed.
+    cached: bool,
+    loaded: Option<Vec<aws::lambda::Function>>,
+    // Functions starred as favorites, listed before the others.
+    favorites: HashSet<aws::lambda::Function>,
+    // Functions marked to tail together.
+    marked: HashSet<aws::lambda::Function>,
+    // The function selected when the app last quit, and how far the functions were scrolled,
+    // restored once the function is listed.
+    restore: Option<(aws::lambda::Function, usize)>,
+    // The fuzzy find query narrowing the listed functions, and whether it is being edited.
+    query: TextInput,
+    finding: bool,
+    // Tags narrowing the listed functions, such as `team=payments`, loaded for every function
+    // once they are filtered by tag.
+    tag_filter: String,
+    tags: HashMap<aws::lambda::Function, FunctionDetails<Vec<(String, String)>>>,
+    // When each function last logged, in milliseconds since the Unix epoch, unless it never has.
+    last_activity: HashMap<aws::lambda::Function, FunctionDetails<Option<i64>>>,
+    // The tasks loading details of every function, such as their tags, stopped when switching
+    // to another profile or region.
+    detail_tasks: Vec<JoinHandle<()>>,
+    // Summaries of the functions, as listed, and the runtime and architecture the listed
+    // functions are narrowed to, if any.
+    summaries: HashMap<aws::lambda::Function, aws::lambda::FunctionSummary>,
+    runtime_filter: Option<String>,
+    architecture_filter: Option<String>,
+    sort: FunctionSort,
+    // The listed functions matching the query, best match first.
+    matches: Vec<FunctionMatch>,
+    // Whether the matches are grouped by the CloudFormation stack they were deployed with, from
+    // their tags, loaded for every function meanwhile, and the stacks collapsed to a heading.
+    grouped: bool,
+    collapsed: HashSet<Option<String>>,
+    // The rows listed, the matches under the headings of their stacks while grouped.
+    rows: Vec<FunctionRow>,
+    // Selection within `rows`, rather than within `functions`.
+    state: ListState,
+    // Configurations of the functions selected so far, loaded once selected.
+    configurations:
+        HashMap<aws::lambda::Function, FunctionDetails<aws::lambda::FunctionConfiguration>>,
+    // Metrics of the functions selected so far, over each time range they were selected in.
+    metrics: HashMap<
+        (aws::lambda::Function, TimeRange),
+        FunctionDetails<aws::cloudwatch_metrics::FunctionMetrics>,
+    >,
+}
+
+/// Details of a function or service, such as its configuration, loaded in the background once
+/// it is selected.
+#[derive(Debug)]
+enum FunctionDetails<T> {
+    Loading,
+    Loaded(T),
+    Failed(AwsError),
+}
+
+/// A row of the listed functions.
+#[derive(Debug)]
+enum FunctionRow {
+    /// The heading of the matches deployed with a CloudFormation stack, or with none, and how
+    /// many there are.
+    Stack { name: Option<String>, count: usize },
+    /// A function, by its index in `FunctionList::matches`.
+    Function(usize),
+}
+
+/// A function matching the fuzzy find query.
+#[derive(Debug)]
+struct FunctionMatch {
+    // Index of the function in `FunctionList::functions`.
+    index: usize,
+    // Indices of the characters of the function name matching the query.
+    positions: Vec<usize>,
+}
+
+impl FunctionList {
+    fn selected(&self) -> Option<&aws::lambda::Function> {
+        let FunctionRow::Function(i) = self.rows.get(self.state.selected()?)? else {
+            return None;
+        };
+        self.functions.as_ref()?.get(self.matches.get(*i)?.index)
+    }
+
+    /// The stack whose heading is selected, while the functions are grouped by stack.
+    fn selected_stack(&self) -> Option<&Option<String>> {
+        match self.rows.get(self.state.selected()?)? {
+            FunctionRow::Stack { name, .. } => Some(name),
+            FunctionRow::Function(_) => None,
+        }
+    }
+
+    /// The CloudFormation stack `function` was deployed with, once its tags are loaded.
+    fn stack_name(&self, function: &aws::lambda::Function) -> Option<&str> {
+        match self.tags.get(function) {
+            Some(FunctionDetails::Loaded(tags)) => aws::lambda::stack_name(tags),
+            _ => None,
+        }
+    }
+
+    /// Group the functions by stack, or stop grouping them.
+    fn toggle_grouped(&mut self) {
+        self.grouped = !self.grouped;
+        let selected = self.selected().cloned();
+        self.update_matches(selected);
+    }
+
+    /// Collapse the functions of the selected stack to its heading, or expand them again.
+    fn toggle_stack(&mut self) {
+        let Some(stack) = self.selected_stack().cloned() else {
+            return;
+        };
+        if !self.collapsed.remove(&stack) {
+            self.collapsed.insert(stack);
+        }
+        self.rows = self.group_rows();
+    }
+
+    /// The rows of the matches, under the headings of their stacks, stacks in name order then
+    /// the functions of no stack, while grouped.
+    fn group_rows(&self) -> Vec<FunctionRow> {
+        if !self.grouped {
+            return (0..self.matches.len()).map(FunctionRow::Function).collect();
+        }
+        let functions = self.functions.as_deref().unwrap_or_default();
+
+        let mut stacks: BTreeMap<Option<String>, Vec<usize>> = BTreeMap::new();
+        for (i, function_match) in self.matches.iter().enumerate() {
+            let stack = self
+                .stack_name(&functions[function_match.index])
+                .map(String::from);
+            stacks.entry(stack).or_default().push(i);
+        }
+        // Functions of no stack are listed last, rather than first.
+        let no_stack = stacks.remove(&None).map(|matches| (None, matches));
+
+        let mut rows = Vec::new();
+        for (name, matches) in stacks.into_iter().chain(no_stack) {
+            let collapsed = self.collapsed.contains(&name);
+            rows.push(FunctionRow::Stack {
+                name,
+                count: matches.len(),
+            });
+            if !collapsed {
+                rows.extend(matches.into_iter().map(FunctionRow::Function));
+            }
+        }
+        rows
+    }
+
+    /// Add a newly loaded page of functions, keeping them sorted and the same function selected.
+    fn extend(&mut self, page: Vec<aws::lambda::Function>) {
+        if self.cached {
+            if let Some(loaded) = &mut self.loaded {
+                loaded.extend(page);
+            }
+            return;
+        }
+
+        let selected = self.selected().cloned();
+
+        let functions = self.functions.get_or_insert_default();
+        functions.extend(page);
+        functions.sort();
+
+        self.update_matches(selected);
+    }
+
+    /// Count a region as done loading pages, finishing once every region is done.
+    fn finish_loading_region(&mut self) {
+        self.loading_regions = self.loading_regions.saturating_sub(1);
+        if self.loading_regions == 0 {
+            self.finish_loading();
+        }
+    }
+
+    fn finish_loading(&mut self) {
+        self.loading = false;
+        self.loaded_at = Some(Instant::now());
+
+        if self.cached
+            && let Some(mut functions) = self.loaded.take()
+        {
+            functions.sort();
+            self.cached = false;
+            self.replace(functions);
+        }
+        // The function selected last run is no longer listed.
+        self.restore = None;
+    }
+
+    /// Replace the functions with a refreshed list, keeping the same function selected.
+    ///
+    /// Their configurations and metrics are loaded again too, as they may have changed.
+    fn replace(&mut self, functions: Vec<aws::lambda::Function>) {
+        let selected = self.selected().cloned();
+        self.functions = Some(functions);
+        self.configurations.clear();
+        self.metrics.clear();
+        self.update_matches(selected);
+    }
+
+    /// The runtimes of the listed functions, sorted.
+    fn runtimes(&self) -> Vec<String> {
+        self.summary_values(|summary| summary.runtime.iter().cloned().collect())
+    }
+
+    /// The architectures of the listed functions, sorted.
+    fn architectures(&self) -> Vec<String> {
+        self.summary_values(|summary| summary.architectures.clone())
+    }
+
+    fn summary_values(
+        &self,
+        values: impl Fn(&aws::lambda::FunctionSummary) -> Vec<String>,
+    ) -> Vec<String> {
+        let values: BTreeSet<String> = self
+            .functions
+            .iter()
+            .flatten()
+            .filter_map(|function| self.summaries.get(function))
+            .flat_map(values)
+            .collect();
+        values.into_iter().collect()
+    }
+
+    /// Narrow the listed functions to the next of their runtimes, or to any runtime after the
+    /// last of them.
+    fn cycle_runtime_filter(&mut self) {
+        self.runtime_filter = next_filter(&self.runtimes(), self.runtime_filter.as_ref());
+        let selected = self.selected().cloned();
+        self.update_matches(selected);
+    }
+
+    /// Narrow the listed functions to the next of their architectures, or to any architecture
+    /// after the last of them.
+    fn cycle_architecture_filter(&mut self) {
+        self.architecture_filter =
+            next_filter(&self.architectures(), self.architecture_filter.as_ref());
+        let selected = self.selected().cloned();
+        self.update_matches(selected);
+    }
+
+    /// List the functions in the next order.
+    fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        let selected = self.selected().cloned();
+        self.update_matches(selected);
+    }
+
+    /// Narrow the listed functions to those matching the edited query.
+    fn handle_query_key(&mut self, key: KeyEvent) {
+        let selected = self.selected().cloned();
+        self.query.handle_key(key);
+        self.update_matches(selected);
+    }
+
+    /// Stop narrowing the listed functions.
+    fn clear_query(&mut self) {
+        let selected = self.selected().cloned();
+        self.query = TextInput::default();
+        self.finding = false;
+        self.update_matches(selected);
+    }
+
+    /// Match the functions against the query, keeping `selected` selected while it matches.
+    ///
+    /// The best match is selected instead, once `selected` no longer matches.
+    fn update_matches(&mut self, selected: Option<aws::lambda::Function>) {
+        let functions = self.functions.as_deref().unwrap_or_default();
+        let query = self.query.value.trim();
+
+        self.matches = if query.is_empty() {
+            let mut matches: Vec<FunctionMatch> = (0..functions.len())
+                .map(|index| FunctionMatch {
+                    index,
+                    positions: Vec::new(),
+                })
+                .collect();
+            // Stable, so functions sorted alike, or without summaries, stay in name order.
+            let summary = |function_match: &FunctionMatch| {
+                self.summaries.get(&functions[function_match.index])
+            };
+            match self.sort {
+                FunctionSort::Name => {}
+                FunctionSort::LastModified => matches.sort_by(|a, b| {
+                    let last_modified = |function_match| {
+                        summary(function_match).and_then(|summary| summary.last_modified.as_ref())
+                    };
+                    last_modified(b).cmp(&last_modified(a))
+                }),
+                FunctionSort::CodeSize => matches.sort_by_key(|function_match| {
+                    std::cmp::Reverse(summary(function_match).map(|summary| summary.code_size))
+                }),
+            }
+            matches
+        } else {
+            let matcher = SkimMatcherV2::default().smart_case();
+            let mut scored: Vec<_> = functions
+                .iter()
+                .enumerate()
+                .filter_map(|(index, function)| {
+                    let (score, positions) = matcher.fuzzy_indices(&function.name, query)?;
+                    Some((score, FunctionMatch { index, positions }))
+                })
+                .collect();
+            // Stable, so equally good matches stay in name order.
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            scored
+                .into_iter()
+                .map(|(_, function_match)| function_match)
+                .collect()
+        };
+
+        if self.runtime_filter.is_some() || self.architecture_filter.is_some() {
+            self.matches.retain(|function_match| {
+                self.summaries
+                    .get(&functions[function_match.index])
+                    .is_some_and(|summary| {
+                        self.runtime_filter
+                            .as_ref()
+                            .is_none_or(|runtime| summary.runtime.as_ref() == Some(runtime))
+                            && self
+                                .architecture_filter
+                                .as_ref()
+                                .is_none_or(|architecture| {
+                                    summary.architectures.contains(architecture)
+                                })
+                    })
+            });
+        }
+
+        let tag_filter = self.tag_filter.trim();
+        if !tag_filter.is_empty() {
+            self.matches.retain(|function_match| {
+                matches!(
+                    self.tags.get(&functions[function_match.index]),
+                    Some(FunctionDetails::Loaded(tags)) if aws::lambda::tags_match(tags, tag_filter)
+                )
+            });
+        }
+
+        // Stable, so favorites are listed first in the same order as the others.
+        self.matches.sort_by_key(|function_match| {
+            !self.favorites.contains(&functions[function_match.index])
+        });
+        self.rows = self.group_rows();
+
+        let position = |function: &aws::lambda::Function| {
+            self.rows.iter().position(|row| {
+                matches!(row, FunctionRow::Function(i)
+                    if &functions[self.matches[*i].index] == function)
+            })
+        };
+        let selected = selected.and_then(|selected| position(&selected));
+        match selected {
+            Some(i) => self.state.select(Some(i)),
+            None if self.state.selected().is_some() || !query.is_empty() => {
+                self.state.select((!self.rows.is_empty()).then_some(0))
+            }
+            None => {}
+        }
+
+        if let Some((function, offset)) = &self.restore
+            && let Some(i) = position(function)
+        {
+            self.state.select(Some(i));
+            *self.state.offset_mut() = *offset;
+            self.restore = None;
+        }
+    }
+}
+
+/// The value after `current` of `values`, or the first if there is no current value, or none
+/// after the last.
+fn next_filter(values: &[String], current: Option<&String>) -> Option<String> {
+    match current.and_then(|current| values.iter().position(|value| value == current)) {
+        Some(i) => values.get(i + 1).cloned(),
+        None => values.first().cloned(),
+    }
+}
+
+#[derive(Debug, Default)]
+struct LogGroupList {
+    log_groups: Option<Vec<aws::cloudwatch_logs::LogGroup>>,
+    loading: bool,
+    state: ListState,
+    // Whether the log groups storing the most bytes are listed first, rather than by name.
+    by_size: bool,
+    // Names of the log groups marked to search together.
+    marked: HashSet<String>,
+    // Whether the log groups are listed as a tree of the prefixes of their names, split on `/`,
+    // and the prefixes expanded to list what they hold, such as `/aws/lambda/`.
+    tree: bool,
+    expanded: HashSet<String>,
+    // The rows listed, the log groups under the prefixes holding them while listed as a tree.
+    rows: Vec<LogGroupRow>,
+}
+
+/// A row of the listed log groups.
+#[derive(Debug)]
+enum LogGroupRow {
+    /// A prefix of the names of log groups, how deeply it is nested, where it starts after the
+    /// prefix holding it, and how many log groups it holds.
+    Prefix {
+        prefix: String,
+        depth: usize,
+        name_start: usize,
+        count: usize,
+    },
+    /// A log group, by its index in `LogGroupList::log_groups`, how deeply it is nested, and
+    /// where its name starts after the prefix holding it.
+    LogGroup {
+        index: usize,
+        depth: usize,
+        name_start: usize,
+    },
+}
+
+impl LogGroupList {
+    fn selected(&self) -> Option<&aws::cloudwatch_logs::LogGroup> {
+        let LogGroupRow::LogGroup { index, .. } = self.rows.get(self.state.selected()?)? else {
+            return None;
+        };
+        self.log_groups.as_ref()?.get(*index)
+    }
+
+    /// List the log groups by name, or by size, keeping the selected log group selected.
+    fn sort(&mut self) {
+        let selected = self.selected().map(|log_group| log_group.name.clone());
+        let Some(log_groups) = &mut self.log_groups else {
+            return;
+        };
+
+        if self.by_size {
+            log_groups.sort_by(|a, b| b.stored_bytes.cmp(&a.stored_bytes).then(a.cmp(b)));
+        } else {
+            log_groups.sort();
+        }
+        self.update_rows(selected);
+    }
+
+    /// List the log groups as a tree of prefixes, or as a flat list again.
+    fn toggle_tree(&mut self) {
+        self.tree = !self.tree;
+        let selected = self.selected().map(|log_group| log_group.name.clone());
+        self.update_rows(selected);
+    }
+
+    /// Expand the selected prefix to list what it holds, or collapse it again.
+    fn toggle_prefix(&mut self) {
+        let Some(LogGroupRow::Prefix { prefix, .. }) =
+            self.state.selected().and_then(|i| self.rows.get(i))
+        else {
+            return;
+        };
+        if !self.expanded.remove(prefix) {
+            self.expanded.insert(prefix.clone());
+        }
+        self.update_rows(None);
+    }
+
+    /// Whether a prefix, rather than a log group, is selected.
+    fn prefix_selected(&self) -> bool {
+        matches!(
+            self.state.selected().and_then(|i| self.rows.get(i)),
+            Some(LogGroupRow::Prefix { .. })
+        )
+    }
+
+    /// List the rows of the log groups again, selecting the log group `selected` if it is
+    /// listed.
+    fn update_rows(&mut self, selected: Option<String>) {
+        let log_groups = self.log_groups.as_deref().unwrap_or_default();
+        self.rows = if self.tree {
+            let mut rows = Vec::new();
+            let indices: Vec<usize> = (0..log_groups.len()).collect();
+            self.tree_rows(&indices, 0, 0, &mut rows);
+            rows
+        } else {
+            (0..log_groups.len())
+                .map(|index| LogGroupRow::LogGroup {
+                    index,
+                    depth: 0,
+                    name_start: 0,
+                })
+                .collect()
+        };
+
+        if let Some(selected) = selected {
+            self.state.select(self.rows.iter().position(|row| {
+                matches!(row, LogGroupRow::LogGroup { index, .. }
+                    if log_groups[*index].name == selected)
+            }));
+        }
+    }
+
+    /// Add the rows of the log groups `indices`, whose names all start with a prefix
+    /// `prefix_len` long, to `rows`, grouped by the next segment of their names, in the order
+    /// they are listed.
+    ///
+    /// Prefixes holding a single log group list it instead.
+    fn tree_rows(
+        &self,
+        indices: &[usize],
+        prefix_len: usize,
+        depth: usize,
+        rows: &mut Vec<LogGroupRow>,
+    ) {
+        let log_groups = self.log_groups.as_deref().unwrap_or_default();
+
+        // The log groups each prefix holds, or those with no further prefix on their own.
+        let mut groups: Vec<(Option<&str>, Vec<usize>)> = Vec::new();
+        // Where in `groups` each prefix is.
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for &index in indices {
+            let name = &log_groups[index].name;
+            // A leading `/` is part of the first segment, rather than a prefix of its own.
+            let prefix = name[prefix_len..]
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '/')
+                .map(|(i, _)| &name[..prefix_len + i + 1]);
+            match prefix.and_then(|prefix| positions.get(prefix)) {
+                Some(&position) => groups[position].1.push(index),
+                None => {
+                    if let Some(prefix) = prefix {
+                        positions.insert(prefix, groups.len());
+                    }
+                    groups.push((prefix, vec![index]));
+                }
+            }
+        }
+
+        for (prefix, group) in groups {
+            match prefix {
+                Some(prefix) if group.len() > 1 => {
+                    rows.push(LogGroupRow::Prefix {
+                        prefix: prefix.to_string(),
+                        depth,
+                        name_start: prefix_len,
+                        count: group.len(),
+                    });
+                    if self.expanded.contains(prefix) {
+                        self.tree_rows(&group, prefix.len(), depth + 1, rows);
+                    }
+                }
+                _ => rows.extend(group.into_iter().map(|index| LogGroupRow::LogGroup {
+                    index,
+                    depth,
+                    name_start: prefix_len,
+                })),
+            }
+        }
+    }
+}
+
+/// ECS services, and where their containers log to, loaded once a service is selected.
+#[derive(Debug, Default)]
+struct ServiceList {
+    services: Option<Vec<aws::ecs::Service>>,
+    loading: bool,
+    state: ListState,
+    logs: HashMap<aws::ecs::Service, FunctionDetails<aws::ecs::ServiceLogs>>,
+}
+
+#[derive(Debug, Default)]
+struct StateMachineList {
+    state_machines: Option<Vec<aws::step_functions::StateMachine>>,
+    loading: bool,
+    state: ListState,
+}
+
+/// Stages of API Gateway REST APIs, and whether their access or execution logs are viewed.
+#[derive(Debug, Default)]
+struct StageList {
+    stages: Option<Vec<aws::api_gateway::Stage>>,
+    loading: bool,
+    state: ListState,
+    execution_logs: bool,
+}
+
+/// RDS databases, and which of the logs they export is viewed.
+#[derive(Debug, Default)]
+struct DatabaseList {
+    databases: Option<Vec<aws::rds::Database>>,
+    loading: bool,
+    state: ListState,
+    // The log viewed of databases that export it, others view the first log they export.
+    log: Option<String>,
+}
+
+impl DatabaseList {
+    fn selected(&self) -> Option<&aws::rds::Database> {
+        self.databases.as_ref()?.get(self.state.selected()?)
+    }
+
+    /// The log viewed of the selected database, if it exports any.
+    fn selected_log(&self) -> Option<&str> {
+        let log_exports = &self.selected()?.log_exports;
+        self.log
+            .as_deref()
+            .filter(|log| log_exports.iter().any(|export| export == log))
+            .or(log_exports.first().map(String::as_str))
+    }
+
+    /// View the next log the selected database exports, after the one viewed.
+    fn switch_log(&mut self) {
+        let Some(database) = self.selected() else {
+            return;
+        };
+        let log_exports = &database.log_exports;
+        let next = match self.selected_log() {
+            Some(log) => log_exports
+                .iter()
+                .position(|export| export == log)
+                .map_or(0, |i| (i + 1) % log_exports.len()),
+            None => 0,
+        };
+        self.log = log_exports.get(next).cloned();
+    }
+}
+
+/// CodeBuild projects.
+#[derive(Debug, Default)]
+struct ProjectList {
+    projects: Option<Vec<aws::codebuild::Project>>,
+    loading: bool,
+    state: ListState,
+}
+
+impl ProjectList {
+    fn selected(&self) -> Option<&aws::codebuild::Project> {
+        self.projects.as_ref()?.get(self.state.selected()?)
+    }
+}
+
+/// What is listed in the top pane.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ListMode {
+    #[default]
+    Functions,
+    LogGroups,
+    Services,
+    StateMachines,
+    Stages,
+    Databases,
+    Projects,
+}
+
+/// The order the functions are listed in, while not finding them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum FunctionSort {
+    #[default]
+    Name,
+    /// Most recently deployed first.
+    LastModified,
+    /// Largest deployment package first.
+    CodeSize,
+}
+
+impl FunctionSort {
+    fn next(self) -> Self {
+        match self {
+            Self::Name => Self::LastModified,
+            Self::LastModified => Self::CodeSize,
+            Self::CodeSize => Self::Name,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::LastModified => "last modified",
+            Self::CodeSize => "code size",
+        }
+    }
+}
+
+/// Which of the log streams of a log group are listed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LogStreamScope {
+    /// Log streams written by a function version.
+    Version(String),
+    /// Log streams named with a prefix, such as those of a container of an ECS service.
+    Prefix(String),
+}
+
+impl LogStreamScope {
+    fn includes(&self, log_stream_name: &str) -> bool {
+        match self {
+            Self::Version(version) => {
+                aws::lambda::log_stream_version(log_stream_name) == Some(version.as_str())
+            }
+            Self::Prefix(prefix) => log_stream_name.starts_with(prefix.as_str()),
+        }
+    }
+
+    fn label(&self) -> &str {
+        match self {
+            Self::Version(label) | Self::Prefix(label) => label,
+        }
+    }
+}
+
+/// Log streams of a single log group, most recently active first.
+///
+/// Only log streams in `scope` are listed, when set.
+#[derive(Debug, Default)]
+struct LogStreamList {
+    log_group_name: String,
+    scope: Option<LogStreamScope>,
+    log_streams: Vec<aws::cloudwatch_logs::LogStream>,
+    // Names of the log streams marked to view together.
+    marked: HashSet<String>,
+    loading: bool,
+    state: ListState,
+}
+
+/// Log events of a single log group, viewed in place of the function info.
+///
+/// Only log events of `log_stream_names` are viewed, when any are, merged in the order they
+/// were logged, and only those matching the regex filter, and not of a hidden level, are shown.
+#[derive(Debug, Default)]
+struct LogView {
+    log_group_name: String,
+    log_stream_names: Vec<String>,
+    // Log groups tailed, or searched, together with `log_group_name`, their events merged with
+    // its own.
+    merged_log_group_names: Vec<String>,
+    // The filter pattern the log groups were searched together with, in place of the filter
+    // pattern of `log_group_name`.
+    search_pattern: Option<String>,
+    // The time range and filter pattern the events were loaded with, in place of those of the
+    // tab, such as of a finished build, or of the logs around one invocation.
+    filter: Option<aws::cloudwatch_logs::EventFilter>,
+    events: Vec<aws::cloudwatch_logs::LogEvent>,
+    loading: bool,
+    loading_older: bool,
+    // Whether no log events older than `events` remain in the time range.
+    loaded_oldest: bool,
+    // Indexes of the shown `events`, which the rows of `state` are.
+    shown: Vec<usize>,
+    state: ListState,
+    live_tail: Option<LiveTailTask>,
+    // Whether Live Tail resumes once the expired credentials it ended with are loaded again.
+    resuming_live_tail: bool,
+    // When each batch of events streamed by Live Tail arrived, by the position of its first
+    // event, until they are no longer new.
+    arrivals: Vec<(usize, Instant)>,
+    // Whether following new events was paused, and how many were shown since it was, or since
+    // scrolling up from the last event.
+    follow_paused: bool,
+    unseen_events: usize,
+    // The messages of `events` with their JSON pretty-printed, once pretty-printing is shown.
+    pretty_messages: Vec<Option<String>>,
+    // The levels of `events`, once shown.
+    levels: Vec<Option<LogLevel>>,
+    // Compact summaries of `events` with a known format, such as `REPORT` lines, once shown.
+    summaries: Vec<Option<String>>,
+    // The lines logged by Kubernetes containers, from the Fluent Bit records of `events`, once
+    // shown.
+    kubernetes_logs: Vec<Option<aws::eks::KubernetesLog>>,
+    // The JSON fields of `events` as rows of a table, while the table is shown.
+    table_rows: Vec<Option<String>>,
+    // Text searched for in the messages of `events`, to highlight and move between matches.
+    search: Option<String>,
+    // Only events with messages matching the regex are shown, without loading them again.
+    regex_filter: Option<Regex>,
+    // Events of these levels are hidden, while events without a level are always shown.
+    hidden_levels: Vec<LogLevel>,
+    // Whether only the events of cold starts are shown.
+    cold_starts_only: bool,
+    // The events grouped by the invocation that logged them, once grouped.
+    invocations: Option<InvocationList>,
+    // Only the events of the invocations with this request ID, such as of retries, are shown,
+    // when set.
+    invocation: Option<String>,
+    // The VPC flow log records of `events`, once tabulated.
+    flow_logs: Option<FlowLogTable>,
+    // How wide messages were wrapped when last drawn, right of the timestamps, if they were.
+    wrap_width: Option<usize>,
+    // How many characters of messages are scrolled past horizontally, when not wrapped.
+    column_offset: usize,
+    // The volume of the shown events as last drawn, to go to the events of a clicked bucket.
+    volume: Option<Volume>,
+}
+
+impl LogView {
+    fn is_viewing(&self, log_group_name: &str, log_stream_names: &[String]) -> bool {
+        self.log_group_name == log_group_name && self.log_stream_names == log_stream_names
+    }
+
+    /// The messages of the events with their JSON pretty-printed, pretty-printing any new events.
+    fn pretty_messages(&mut self) -> &[Option<String>] {
+        let start = self.pretty_messages.len();
+        self.pretty_messages.extend(
+            self.events[start..]
+                .iter()
+                .map(|event| log_format::pretty_json(&event.message)),
+        );
+        &self.pretty_messages
+    }
+
+    /// The levels of the events, detecting the levels of any new events.
+    fn levels(&mut self) -> &[Option<LogLevel>] {
+        let start = self.levels.len();
+        self.levels.extend(
+            self.events[start..]
+                .iter()
+                .map(|event| LogLevel::detect(&event.message)),
+        );
+        &self.levels
+    }
+
+    /// The summaries of the events with a known format, summarizing any new events.
+    fn summaries(&mut self) -> &[Option<String>] {
+        let start = self.summaries.len();
+        self.summaries.extend(
+            self.events[start..]
+                .iter()
+                .map(|event| log_format::summary(&event.message)),
+        );
+        &self.summaries
+    }
+
+    /// The lines logged by Kubernetes containers, parsing the records of any new events.
+    fn kubernetes_logs(&mut self) -> &[Option<aws::eks::KubernetesLog>] {
+        let start = self.kubernetes_logs.len();
+        self.kubernetes_logs.extend(
+            self.events[start..]
+                .iter()
+                .map(|event| aws::eks::KubernetesLog::parse(&event.message)),
+        );
+        &self.kubernetes_logs
+    }
+
+    /// The messages of the events as rows of the table of the JSON `columns`, tabulating any new
+    /// events.
+    fn table_rows(&mut self, columns: &[ColumnConfig]) -> &[Option<String>] {
+        let start = self.table_rows.len();
+        self.table_rows.extend(
+            self.events[start..]
+                .iter()
+                .map(|event| log_format::json_columns(&event.message, columns)),
+        );
+        &self.table_rows
+    }
+
+    /// Whether the events of several functions, or log streams, are merged.
+    fn is_merged(&self) -> bool {
+        !self.merged_log_group_names.is_empty() || self.log_stream_names.len() > 1
+    }
+
+    /// The function, or else log stream, the `i`th event came from, when merged with those of
+    /// others.
+    fn source(&self, i: usize) -> Option<&str> {
+        let event = &self.events[i];
+        if self.merged_log_group_names.is_empty() {
+            return event.log_stream_name.as_deref();
+        }
+        let log_group_name = event.log_group_name.as_deref()?;
+        Some(
+            log_group_name
+                .strip_prefix(aws::cloudwatch_logs::LAMBDA_LOG_GROUP_PREFIX)
+                .unwrap_or(log_group_name),
+        )
+    }
+
+    /// The message of the `i`th event pretty-printed, if it has JSON and that is shown.
+    fn pretty_message(&self, i: usize, pretty_json: bool) -> Option<&str> {
+        if !pretty_json {
+            return None;
+        }
+        self.pretty_messages.get(i)?.as_deref()
+    }
+
+    /// The line logged by a Kubernetes container of the `i`th event, unless its message is
+    /// shown pretty-printed or summarized instead.
+    fn kubernetes_log(&self, i: usize, pretty_json: bool) -> Option<&aws::eks::KubernetesLog> {
+        if self.table_row(i).is_some()
+            || self.pretty_message(i, pretty_json).is_some()
+            || self.summaries.get(i)?.is_some()
+        {
+            return None;
+        }
+        self.kubernetes_logs.get(i)?.as_ref()
+    }
+
+    /// The row of the table of the `i`th event, if the table is shown and it has any of the
+    /// JSON fields of its columns.
+    fn table_row(&self, i: usize) -> Option<&str> {
+        self.table_rows.get(i)?.as_deref()
+    }
+
+    /// The lines of the message of the `i`th event as shown, before wrapping them.
+    fn message_lines(&self, i: usize, pretty_json: bool) -> Vec<&str> {
+        if let Some(table_row) = self.table_row(i) {
+            return vec![table_row];
+        }
+        if let Some(pretty_message) = self.pretty_message(i, pretty_json) {
+            return pretty_message.lines().collect();
+        }
+        if let Some(Some(summary)) = self.summaries.get(i) {
+            return vec![summary.as_str()];
+        }
+        match self.kubernetes_log(i, pretty_json) {
+            Some(kubernetes_log) => vec![kubernetes_log.log.as_str()],
+            None => vec![self.events[i].message.trim_end()],
+        }
+    }
+
+    /// How wide the rows of the message of the `i`th event are when wrapped, right of any
+    /// Kubernetes columns.
+    fn message_width(&self, i: usize, pretty_json: bool, wrap_width: usize) -> usize {
+        match self.kubernetes_log(i, pretty_json) {
+            Some(_) => wrap_width.saturating_sub(log_format::KUBERNETES_COLUMNS_WIDTH),
+            None => wrap_width,
+        }
+    }
+
+    /// Scroll the messages right, or else left, by a step, no further right than the longest
+    /// line of the shown messages.
+    fn scroll_columns(&mut self, right: bool, pretty_json: bool) {
+        if !right {
+            self.column_offset = self.column_offset.saturating_sub(COLUMN_SCROLL_STEP);
+            return;
+        }
+
+        let longest = self
+            .shown
+            .iter()
+            .flat_map(|&i| self.message_lines(i, pretty_json))
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        self.column_offset = (self.column_offset + COLUMN_SCROLL_STEP)
+            .min(longest.saturating_sub(1) / COLUMN_SCROLL_STEP * COLUMN_SCROLL_STEP);
+    }
+
+    /// How many rows the `i`th event takes, as pretty-printed JSON, and wrapped messages, take
+    /// several.
+    fn event_height(&self, i: usize, pretty_json: bool) -> usize {
+        let lines = self.message_lines(i, pretty_json);
+        match self.wrap_width {
+            Some(wrap_width) => {
+                let width = self.message_width(i, pretty_json, wrap_width);
+                lines
+                    .into_iter()
+                    .map(|line| log_format::wrap(line, width).len())
+                    .sum()
+            }
+            None => lines.len(),
+        }
+    }
+
+    /// Whether the event is shown, rather than hidden by the regex filter, its level, or not
+    /// being of a cold start when only those are shown.
+    fn shows(&self, event: &aws::cloudwatch_logs::LogEvent) -> bool {
+        if self.cold_starts_only && !log_format::is_cold_start(&event.message) {
+            return false;
+        }
+        let shows_level = self.hidden_levels.is_empty()
+            || LogLevel::detect(&event.message)
+                .is_none_or(|level| !self.hidden_levels.contains(&level));
+
+        shows_level
+            && self
+                .regex_filter
+                .as_ref()
+                .is_none_or(|regex_filter| regex_filter.is_match(&event.message))
+    }
+
+    /// Show the events of `level` if they are hidden, or else hide them.
+    fn toggle_level(&mut self, level: LogLevel) {
+        if let Some(i) = self
+            .hidden_levels
+            .iter()
+            .position(|&hidden| hidden == level)
+        {
+            self.hidden_levels.remove(i);
+        } else {
+            self.hidden_levels.push(level);
+        }
+        self.update_shown();
+    }
+
+    /// The selected event, if any.
+    fn selected_log_event(&self) -> Option<&aws::cloudwatch_logs::LogEvent> {
+        self.selected_event().map(|i| &self.events[i])
+    }
+
+    /// How many events are hidden by the regex filter, their level, or not being of a cold start.
+    fn hidden(&self) -> usize {
+        self.events.len() - self.shown.len()
+    }
+
+    /// The index of the selected event in `events`, if any.
+    fn selected_event(&self) -> Option<usize> {
+        let last = self.shown.len().checked_sub(1)?;
+        // The selection is only clamped to the rows once rendered.
+        let row = self.state.selected()?.min(last);
+        Some(self.shown[row])
+    }
+
+    /// Find the shown events again, such as after changing the regex filter.
+    ///
+    /// Keeps the selected event selected if it is still shown, or else the next shown event.
+    fn update_shown(&mut self) {
+        let selected_event = self.selected_event();
+        let invocation_events = self.invocation.as_ref().map(|request_id| {
+            let mut events: Vec<usize> = self
+                .invocations
+                .iter()
+                .flat_map(|invocations| &invocations.invocations)
+                .filter(|invocation| &invocation.request_id == request_id)
+                .flat_map(|invocation| invocation.events.iter().copied())
+                .collect();
+            events.sort_unstable();
+            events
+        });
+        self.shown = match invocation_events {
+            Some(events) => events
+                .into_iter()
+                .filter(|&i| self.shows(&self.events[i]))
+                .collect(),
+            None => (0..self.events.len())
+                .filter(|&i| self.shows(&self.events[i]))
+                .collect(),
+        };
+
+        let row = match selected_event {
+            Some(selected_event) => Some(
+                self.shown
+                    .partition_point(|&i| i < selected_event)
+                    .min(self.shown.len().saturating_sub(1)),
+            ),
+            None => self.shown.len().checked_sub(1),
+        };
+        self.state = ListState::default().with_selected(row);
+    }
+
+    /// The time range the events were loaded from, if not the time range of the tab.
+    fn time_range(&self) -> Option<TimeRange> {
+        let filter = self.filter.as_ref()?;
+        Some(TimeRange::Between {
+            start: filter.start_time?,
+            end: filter.end_time?,
+        })
+    }
+
+    /// Whether the invocations are shown, rather than the events of one or all of them.
+    fn shows_invocations(&self) -> bool {
+        self.invocations.is_some() && self.invocation.is_none()
+    }
+
+    /// Group the events by the invocation that logged them again, such as after loading more
+    /// events, keeping the same invocation selected.
+    fn group_invocations(&mut self) {
+        let selected = self
+            .invocations
+            .as_ref()
+            .and_then(InvocationList::selected)
+            .map(|invocation| invocation.request_id.clone());
+        let invocations = aws::lambda::group_invocations(&self.events);
+        let row = selected
+            .and_then(|request_id| {
+                invocations
+                    .iter()
+                    .position(|invocation| invocation.request_id == request_id)
+            })
+            .or(invocations.len().checked_sub(1));
+
+        self.invocations = Some(InvocationList {
+            invocations,
+            state: ListState::default().with_selected(row),
+        });
+        if self.invocation.is_some() {
+            self.update_shown();
+        }
+    }
+
+    /// Parse the VPC flow log records of the events again, such as after loading more events,
+    /// keeping the same sort and filter.
+    fn tabulate_flow_logs(&mut self) {
+        self.flow_logs = Some(FlowLogTable::new(&self.events, self.flow_logs.take()));
+    }
+
+    /// Show only the events of the selected invocation, starting from its first event.
+    fn open_invocation(&mut self) {
+        let Some(invocation) = self.invocations.as_ref().and_then(InvocationList::selected) else {
+            return;
+        };
+        self.invocation = Some(invocation.request_id.clone());
+        self.update_shown();
+        self.state.select_first();
+    }
+
+    /// Show the events of every invocation again.
+    fn close_invocation(&mut self) {
+        self.invocation = None;
+        self.update_shown();
+    }
+
+    /// Select the first shown event at or after `timestamp`, or the last shown event if none are.
+    fn select_time(&mut self, timestamp: i64) {
+        if self.shown.is_empty() {
+            return;
+        }
+        let row = self
+            .shown
+            .iter()
+            .position(|&i| self.events[i].timestamp >= timestamp)
+            .unwrap_or(self.shown.len() - 1);
+        self.state.select(Some(row));
+    }
+
+    /// Select the first shown event of the bucket `i` of the volume as last drawn.
+    fn select_volume_bucket(&mut self, i: usize) {
+        if let Some(volume) = &self.volume {
+            let timestamp = volume.bucket_start(i);
+            self.select_time(timestamp);
+        }
+    }
+
+    /// Select the next shown event after the selected event with a message matching the
+    /// search, or the previous event if not `forward`, wrapping around the events.
+    ///
+    /// Returns whether a matching event was found.
+    fn select_match(&mut self, forward: bool) -> bool {
+        let Some(search) = &self.search else {
+            return false;
+        };
+        let len = self.shown.len();
+        let selected = self
+            .state
+            .selected()
+            .map_or(if forward { len } else { 0 }, |row| row.min(len));
+
+        let found = (1..=len)
+            .map(|step| {
+                if forward {
+                    (selected + step) % len
+                } else {
+                    (selected + len - step % len) % len
+                }
+            })
+            .find(|&row| {
+                !log_format::find_matches(&self.events[self.shown[row]].message, search).is_empty()
+            });
+        if found.is_some() {
+            self.state.select(found);
+        }
+        found.is_some()
+    }
+
+    /// Prepend older log events, keeping the selected event selected and in place.
+    fn prepend(&mut self, events: Vec<aws::cloudwatch_logs::LogEvent>) {
+        let count = events.len();
+        if count == 0 {
+            self.loaded_oldest = true;
+            return;
+        }
+
+        let shown: Vec<usize> = (0..count).filter(|&i| self.shows(&events[i])).collect();
+        let shown_count = shown.len();
+        self.shown = shown
+            .into_iter()
+            .chain(self.shown.iter().map(|i| i + count))
+            .collect();
+        self.events.splice(0..0, events);
+        for (start, _) in &mut self.arrivals {
+            *start += count;
+        }
+        // The cached messages and levels are by position, so are found again once shown.
+        self.pretty_messages.clear();
+        self.levels.clear();
+        self.summaries.clear();
+        self.kubernetes_logs.clear();
+        self.table_rows.clear();
+
+        self.state
+            .select(self.state.selected().map(|row| row + shown_count));
+        *self.state.offset_mut() += shown_count;
+
+        // The grouped events are by position too, and older events may start invocations.
+        if self.invocations.is_some() {
+            self.group_invocations();
+        }
+        if self.flow_logs.is_some() {
+            self.tabulate_flow_logs();
+        }
+    }
+
+    /// Add newly streamed log events, following them if the last event was selected.
+    ///
+    /// Events of several log streams may arrive out of the order they were logged in, so are
+    /// merged among the events logged after them, keeping the selected event selected.
+    fn append(&mut self, mut events: Vec<aws::cloudwatch_logs::LogEvent>) {
+        let following = self.following();
+        if events.is_empty() {
+            return;
+        }
+
+        events.sort_by_key(|event| event.timestamp);
+        let start = self
+            .events
+            .partition_point(|event| event.timestamp <= events[0].timestamp);
+        let selected = self
+            .state
+            .selected()
+            .and_then(|row| self.shown.get(row).copied());
+
+        // Merge the events with those logged after them, finding where each of those moved to.
+        let later = self.events.split_off(start);
+        let mut moved = Vec::with_capacity(later.len());
+        let mut events = events.into_iter().peekable();
+        for event in later {
+            while let Some(new_event) = events.next_if(|new| new.timestamp < event.timestamp) {
+                self.events.push(new_event);
+            }
+            moved.push(self.events.len());
+            self.events.push(event);
+        }
+        self.events.extend(events);
+
+        // The cached messages and levels of the merged events are found again once shown, as
+        // are the marks of when they arrived.
+        self.pretty_messages.truncate(start);
+        self.levels.truncate(start);
+        self.summaries.truncate(start);
+        self.kubernetes_logs.truncate(start);
+        self.table_rows.truncate(start);
+        self.arrivals.retain(|&(arrival, _)| arrival < start);
+        self.arrivals.push((start, Instant::now()));
+
+        let kept = self.shown.partition_point(|&i| i < start);
+        let earlier_shown = self.shown.len();
+        self.shown.truncate(kept);
+        let shown: Vec<usize> = (start..self.events.len())
+            .filter(|&i| self.shows(&self.events[i]))
+            .collect();
+        if !following {
+            self.unseen_events += kept + shown.len() - earlier_shown;
+        }
+        self.shown.extend(shown);
+
+        if self.invocations.is_some() {
+            self.group_invocations();
+        }
+        if self.flow_logs.is_some() {
+            self.tabulate_flow_logs();
+        }
+        if following {
+            self.unseen_events = 0;
+            self.state.select(self.shown.len().checked_sub(1));
+        } else if let Some(selected) = selected {
+            let selected = selected
+                .checked_sub(start)
+                .map_or(selected, |later| moved[later]);
+            if let Ok(row) = self.shown.binary_search(&selected) {
+                self.state.select(Some(row));
+            }
+        }
+    }
+
+    /// Whether the `i`th event was streamed less than `duration` ago.
+    fn arrived_within(&self, i: usize, duration: Duration) -> bool {
+        let batch = self.arrivals.partition_point(|&(start, _)| start <= i);
+        batch
+            .checked_sub(1)
+            .is_some_and(|batch| self.arrivals[batch].1.elapsed() < duration)
+    }
+
+    /// Whether new events are selected as they are appended, unless following was paused, or
+    /// an earlier event is selected.
+    fn following(&self) -> bool {
+        !self.follow_paused
+            && self
+                .state
+                .selected()
+                .is_none_or(|row| row + 1 >= self.shown.len())
+    }
+
+    /// Pause following new events, or else go to the last event and follow them again.
+    fn toggle_follow(&mut self) {
+        if self.following() {
+            self.follow_paused = true;
+            return;
+        }
+
+        self.follow_paused = false;
+        self.unseen_events = 0;
+        self.state.select(self.shown.len().checked_sub(1));
+    }
+}
+
+/// The invocations that logged the events of the log viewer, most recently started last.
+#[derive(Debug, Default)]
+struct InvocationList {
+    invocations: Vec<aws::lambda::InvocationLog>,
+    state: ListState,
+}
+
+impl InvocationList {
+    fn selected(&self) -> Option<&aws::lambda::InvocationLog> {
+        self.invocations.get(self.state.selected()?)
+    }
+}
+
+/// The VPC flow log records of the events of the log viewer, as a table sortable and
+/// filterable by column.
+#[derive(Debug, Default)]
+struct FlowLogTable {
+    // The parsed records, with the positions of the events they were parsed from.
+    records: Vec<(usize, aws::vpc::FlowLog)>,
+    // Positions in `records` of the shown records, in the sorted order.
+    shown: Vec<usize>,
+    // The column the records are sorted by, and whether descending, else in time order.
+    sort: Option<(aws::vpc::FlowField, bool)>,
+    // The filter as typed, to edit it again, and as parsed.
+    filter: Option<(String, aws::vpc::FlowFilter)>,
+    state: TableState,
+}
+
+impl FlowLogTable {
+    /// Parse the flow log records of `events`, keeping the sort and filter of `previous`.
+    fn new(events: &[aws::cloudwatch_logs::LogEvent], previous: Option<FlowLogTable>) -> Self {
+        let records = events
+            .iter()
+            .enumerate()
+            .filter_map(|(i, event)| Some((i, aws::vpc::FlowLog::parse(&event.message)?)))
+            .collect();
+        let (sort, filter) = previous.map_or((None, None), |table| (table.sort, table.filter));
+
+        let mut table = Self {
+            records,
+            sort,
+            filter,
+            ..Default::default()
+        };
+        table.update_shown();
+        table
+    }
+
+    /// Find the shown records again, such as after sorting them, selecting the most recent.
+    fn update_shown(&mut self) {
+        self.shown = (0..self.records.len())
+            .filter(|&i| {
+                self.filter
+                    .as_ref()
+                    .is_none_or(|(_, filter)| filter.matches(&self.records[i].1))
+            })
+            .collect();
+        if let Some((field, descending)) = self.sort {
+            // Sorting is stable, so records with equal values stay in time order.
+            let records = &self.records;
+            self.shown
+                .sort_by(|&a, &b| records[a].1.compare(&records[b].1, field));
+            if descending {
+                self.shown.reverse();
+            }
+        }
+
+        let row = match self.sort {
+            Some(_) => (!self.shown.is_empty()).then_some(0),
+            None => self.shown.len().checked_sub(1),
+        };
+        self.state = TableState::default().with_selected(row);
+    }
+
+    /// Sort by the next column, or in time order after the last column.
+    fn cycle_sort(&mut self) {
+        let fields = aws::vpc::FlowField::ALL;
+        self.sort = match self.sort {
+            None => Some((fields[0], false)),
+            Some((field, _)) => fields
+                .iter()
+                .position(|&f| f == field)
+                .and_then(|i| fields.get(i + 1))
+                .map(|&field| (field, false)),
+        };
+        self.update_shown();
+    }
+
+    /// Sort by the same column in the other direction.
+    fn reverse_sort(&mut self) {
+        if let Some((_, descending)) = &mut self.sort {
+            *descending = !*descending;
+            self.update_shown();
+        }
+    }
+}
+
+/// Where an ended Live Tail session left off, to load the log events it missed when resumed.
+#[derive(Debug)]
+struct TailGap {
+    // The time of the last log event received, and the messages of those at that time.
+    start_time: i64,
+    seen: Vec<String>,
+}
+
+/// A Live Tail session running in the background, feeding new log events to the log viewer.
+///
+/// The session is stopped when the task is dropped.
+#[derive(Debug)]
+struct LiveTailTask {
+    task: JoinHandle<()>,
+}
+
+impl LiveTailTask {
+    /// Tail the log group `log_group_name`, together with any `merged_log_group_names`.
+    fn spawn(
+        events: &EventHandler,
+        logs_client: &aws::cloudwatch_logs::Client,
+        log_group_name: String,
+        merged_log_group_names: Vec<String>,
+        filter: aws::cloudwatch_logs::EventFilter,
+        gap: Option<TailGap>,
+    ) -> Self {
+        let sender = events.sender();
+        let logs_client = logs_client.clone();
+        let task = tokio::spawn(async move {
+            let log_stream_names = filter.log_stream_names.clone();
+            // Whether the app is still receiving the events.
+            let send = |events| {
+                sender
+                    .send(AppEvent::LiveTailEvents {
+                        log_group_name: log_group_name.clone(),
+                        log_stream_names: log_stream_names.clone(),
+                        events,
+                    })
+                    .is_ok()
+            };
+
+            let log_group_names: Vec<String> = std::iter::once(log_group_name.clone())
+                .chain(merged_log_group_names)
+                .collect();
+            let mut tailed_log_group_names = Vec::new();
+            let mut log_group_arns = Vec::new();
+            for name in &log_group_names {
+                match logs_client.get_log_group(name).await {
+                    Ok(log_group) => {
+                        if let Some(arn) = log_group.and_then(|log_group| log_group.arn) {
+                            tailed_log_group_names.push(name.clone());
+                            log_group_arns.push(arn);
+                        }
+                    }
+                    Err(error) => {
+                        send(Err(error));
+                        return;
+                    }
+                }
+            }
+            // Log groups are only created once a function logs, so there may be nothing to tail.
+            if log_group_arns.is_empty() {
+                let _ = sender.send(AppEvent::Error(format!(
+                    "Log group {} does not exist yet, nothing to tail",
+                    log_group_names.join(", ")
+                )));
+                return;
+            }
+
+            // Live Tail only streams new log events, so those missed since are loaded first.
+            if let Some(gap) = gap {
+                let gap_filter = aws::cloudwatch_logs::EventFilter {
+                    start_time: Some(gap.start_time),
+                    ..filter.clone()
+                };
+                let mut missed = Vec::new();
+                for name in &tailed_log_group_names {
+                    match logs_client
+                        .get_log_events(name, &gap_filter, MAX_LOG_EVENTS)
+                        .await
+                    {
+                        Ok(events) => missed.extend(events),
+                        Err(error) => {
+                            send(Err(error));
+                            return;
+                        }
+                    }
+                }
+                missed.retain(|event| {
+                    event.timestamp != gap.start_time || !gap.seen.contains(&event.message)
+                });
+                if !send(Ok(missed)) {
+                    return;
+                }
+            }
+
+            let mut live_tail = match logs_client.start_live_tail(&log_group_arns, &filter).await {
+                Ok(live_tail) => live_tail,
+                Err(error) => {
+                    send(Err(error));
+                    return;
+                }
+            };
+            loop {
+                let events = match live_tail.next_events().await {
+                    Ok(Some(events)) => Ok(events),
+                    Ok(None) => break,
+                    Err(error) => Err(error),
+                };
+                let failed = events.is_err();
+                if !send(events) || failed {
+                    break;
+                }
+            }
+        });
+
+        Self { task }
+    }
+}
+
+impl Drop for LiveTailTask {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Signing in again with `aws sso login`, once the SSO session of the profile expired.
+///
+/// The AWS CLI prints the URL, and code, to authorize the device with while it waits, so its
+/// output is kept to show. Signing in is cancelled when dropped.
+#[derive(Debug)]
+struct SsoLogin {
+    profile: Option<String>,
+    output: Vec<String>,
+    error: Option<String>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl SsoLogin {
+    fn new(profile: Option<String>) -> Self {
+        Self {
+            profile,
+            output: Vec::new(),
+            error: None,
+            task: None,
+        }
+    }
+
+    /// The command that signs in, to run in another terminal instead.
+    fn command(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("aws sso login --profile {profile}"),
+            None => "aws sso login".to_string(),
+        }
+    }
+
+    fn running(&self) -> bool {
+        self.task.is_some()
+    }
+
+    /// Run `aws sso login` in the background, sending its output as it prints it.
+    fn start(&mut self, events: &EventHandler) {
+        let mut command = Command::new("aws");
+        command.args(["sso", "login"]);
+        if let Some(profile) = &self.profile {
+            command.args(["--profile", profile]);
+        }
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        self.output.clear();
+        self.error = None;
+        let sender = events.sender();
+        self.task = Some(tokio::spawn(async move {
+            let mut child = match command.spawn() {
+                Ok(child) => child,
+                Err(error) => {
+                    let _ = sender.send(AppEvent::SsoLoginFinished(Err(format!(
+                        "Failed to run aws sso login: {error}"
+                    ))));
+                    return;
+                }
+            };
+
+            let (Some(stdout), Some(stderr)) = (child.stdout.take(), child.stderr.take()) else {
+                return;
+            };
+            let mut stdout = BufReader::new(stdout).lines();
+            let mut stderr = BufReader::new(stderr).lines();
+            let (mut stdout_closed, mut stderr_closed) = (false, false);
+            while !(stdout_closed && stderr_closed) {
+                let line = tokio::select! {
+                    line = stdout.next_line(), if !stdout_closed => {
+                        line.ok().flatten().or_else(|| {
+                            stdout_closed = true;
+                            None
+                        })
+                    }
+                    line = stderr.next_line(), if !stderr_closed => {
+                        line.ok().flatten().or_else(|| {
+                            stderr_closed = true;
+                            None
+                        })
+                    }
+                };
+                if let Some(line) = line.filter(|line| !line.trim().is_empty())
+                    && sender.send(AppEvent::SsoLoginOutput(line)).is_err()
+                {
+                    return;
+                }
+            }
+
+            let finished = match child.wait().await {
+                Ok(status) if status.success() => Ok(()),
+                Ok(status) => Err(format!("aws sso login failed, {status}")),
+                Err(error) => Err(format!("Failed to run aws sso login: {error}")),
+            };
+            let _ = sender.send(AppEvent::SsoLoginFinished(finished));
+        }));
+    }
+}
+
+impl Drop for SsoLogin {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.abort();
+        }
+    }
+}
+
+/// A Logs Insights query of a single log group, and its results.
+#[derive(Debug, Default)]
+struct InsightsView {
+    log_group_name: String,
+    query: TextInput,
+    // Whether key presses edit the query, rather than browse the results.
+    editing: bool,
+    running: bool,
+    results: Option<aws::cloudwatch_logs::QueryResults>,
+    // Positions in the results of the shown rows, in the sorted order.
+    shown: Vec<usize>,
+    // The column the rows are sorted by, and whether descending, else in the order returned.
+    sort: Option<(String, bool)>,
+    state: TableState,
+}
+
+impl InsightsView {
+    /// Show the `results` of the query, sorted by the same column if they still have it.
+    fn show_results(&mut self, results: aws::cloudwatch_logs::QueryResults) {
+        if self
+            .sort
+            .as_ref()
+            .is_some_and(|(column, _)| !results.columns.contains(column))
+        {
+            self.sort = None;
+        }
+        self.results = Some(results);
+        self.update_shown();
+    }
+
+    /// Sort the shown rows again, such as after sorting by another column, selecting the first.
+    fn update_shown(&mut self) {
+        let Some(results) = &self.results else {
+            return;
+        };
+        self.shown = (0..results.rows.len()).collect();
+        if let Some((column, descending)) = &self.sort
+            && let Some(column) = results.columns.iter().position(|name| name == column)
+        {
+            // Numbers, such as from `stats`, are compared as numbers rather than as text.
+            let value = |row: usize| results.rows[row].values[column].trim();
+            self.shown.sort_by(
+                |&a, &b| match (value(a).parse::<f64>(), value(b).parse::<f64>()) {
+                    (Ok(a), Ok(b)) => a.total_cmp(&b),
+                    _ => value(a).cmp(value(b)),
+                },
+            );
+            if *descending {
+                self.shown.reverse();
+            }
+        }
+        self.state = TableState::default().with_selected((!self.shown.is_empty()).then_some(0));
+    }
+
+    /// Sort by the next column, or in the order returned after the last column.
+    fn cycle_sort(&mut self) {
+        let Some(results) = &self.results else {
+            return;
+        };
+        self.sort = match &self.sort {
+            None => results.columns.first(),
+            Some((column, _)) => results
+                .columns
+                .iter()
+                .position(|name| name == column)
+                .and_then(|i| results.columns.get(i + 1)),
+        }
+        .map(|column| (column.clone(), false));
+        self.update_shown();
+    }
+
+    /// Sort by the same column in the other direction.
+    fn reverse_sort(&mut self) {
+        if let Some((_, descending)) = &mut self.sort {
+            *descending = !*descending;
+            self.update_shown();
+        }
+    }
+
+    fn selected_row(&self) -> Option<&aws::cloudwatch_logs::QueryRow> {
+        let row = *self.shown.get(self.state.selected()?)?;
+        self.results.as_ref()?.rows.get(row)
+    }
+}
+
+/// Clients for one of the AWS regions that functions are listed from.
+#[derive(Debug)]
+struct RegionalClients {
+    region: Option<String>,
+    lambda_client: aws::lambda::Client,
+    logs_client: aws::cloudwatch_logs::Client,
+    metrics_client: aws::cloudwatch_metrics::Client,
+    xray_client: aws::xray::Client,
+    ecs_client: aws::ecs::Client,
+    sfn_client: aws::step_functions::Client,
+    api_gateway_client: aws::api_gateway::Client,
+    rds_client: aws::rds::Client,
+    codebuild_client: aws::codebuild::Client,
+    cloudtrail_client: aws::cloudtrail::Client,
+    sqs_client: aws::sqs::Client,
+}
+
+impl RegionalClients {
+    fn new(sdk_config: &SdkConfig) -> Self {
+        Self {
+            region: sdk_config.region().map(ToString::to_string),
+            lambda_client: aws::lambda::Client::new(sdk_config),
+            logs_client: aws::cloudwatch_logs::Client::new(sdk_config),
+            metrics_client: aws::cloudwatch_metrics::Client::new(sdk_config),
+            xray_client: aws::xray::Client::new(sdk_config),
+            ecs_client: aws::ecs::Client::new(sdk_config),
+            sfn_client: aws::step_functions::Client::new(sdk_config),
+            api_gateway_client: aws::api_gateway::Client::new(sdk_config),
+            rds_client: aws::rds::Client::new(sdk_config),
+            codebuild_client: aws::codebuild::Client::new(sdk_config),
+            cloudtrail_client: aws::cloudtrail::Client::new(sdk_config),
+            sqs_client: aws::sqs::Client::new(sdk_config),
+        }
+    }
+
+    /// Clients for each of `regions`, or for the region of `sdk_config` unless there are several.
+    fn for_regions(sdk_config: &SdkConfig, regions: &[String]) -> Vec<Self> {
+        if regions.len() <= 1 {
+            return vec![Self::new(sdk_config)];
+        }
+
+        regions
+            .iter()
+            .map(|region| Self::new(&aws::config::with_region(sdk_config, region)))
+            .collect()
+    }
+}
+
+/// Tailed log events that matched an alert pattern, shown in a banner until acknowledged.
+#[derive(Debug, Default)]
+struct Alert {
+    count: usize,
+    // The first line of the message of the last of them.
+    message: String,
+}
+
+/// A failed call to AWS, shown over the app until retried or dismissed.
+#[derive(Debug)]
+struct ErrorScreen {
+    error: AwsError,
+    retry: Retry,
+    /// Signing in again, if the error is that the SSO session expired.
+    sso_login: Option<SsoLogin>,
+}
+
+/// What to do again when retrying after an [`ErrorScreen`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Retry {
+    LoadFunctions,
+    RefreshFunctions,
+    LoadLogGroups,
+    LoadServices,
+    LoadStateMachines,
+    LoadStages,
+    LoadDatabases,
+    LoadProjects,
+    LoadLogStreams,
+    LoadFunctionVersions,
+    LoadLogEvents,
+    SearchLogGroups,
+    LoadOlderLogEvents,
+    LiveTail,
+    RunQuery,
+}
+
+/// A popup to pick one of a list of items, such as an AWS profile.
+#[derive(Debug, Default)]
+struct Picker {
+    kind: PickerKind,
+    items: Vec<String>,
+    state: ListState,
+}
+
+/// What is being picked, and so what is done with the picked item.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum PickerKind {
+    #[default]
+    Profile,
+    Region,
+    TimeRange,
+    Version,
+    Recent,
+    Query,
+    Retention,
+}
+
+/// A download of the log events of a log group to a file, running in the background.
+#[derive(Debug)]
+struct Download {
+    id: u64,
+    log_group_name: String,
+    count: usize,
+    // How far through the time range the download is, from 0 to 1.
+    progress: f64,
+}
+
+/// A popup asking to confirm a change to AWS resources, made only once confirmed.
+#[derive(Debug)]
+struct Confirmation {
+    question: String,
+    change: Change,
+}
+
+/// A change to AWS resources, made in the background.
+#[derive(Debug)]
+enum Change {
+    Retention {
+        log_group_name: String,
+        retention_in_days: Option<i32>,
+    },
+}
+
+/// A form to input the start and end of a time range.
+#[derive(Debug, Default)]
+struct TimeRangeForm {
+    start: TextInput,
+    end: TextInput,
+    // Whether the end is being edited, rather than the start.
+    editing_end: bool,
+}
+
+impl TimeRangeForm {
+    fn editing(&mut self) -> &mut TextInput {
+        if self.editing_end {
+            &mut self.end
+        } else {
+            &mut self.start
+        }
+    }
+}
+
+/// The JSON payload to invoke a Lambda function with, being edited.
+#[derive(Debug)]
+struct InvokeInput {
+    function: aws::lambda::Function,
+    payload: TextInput,
+    // Whether to only check that the function can be invoked, without running it.
+    dry_run: bool,
+}
+
+/// The command palette, fuzzy finding the actions of the mode it was opened from.
+#[derive(Debug)]
+struct Palette {
+    mode: Mode,
+    query: TextInput,
+    // The actions matching the query, best match first.
+    matches: Vec<CommandMatch>,
+    state: ListState,
+}
+
+/// An action matching the command palette query.
+#[derive(Debug)]
+struct CommandMatch {
+    action: Action,
+    // Indices of the characters of the action description matching the query.
+    positions: Vec<usize>,
+}
+
+impl Palette {
+    fn new(mode: Mode) -> Self {
+        let mut palette = Self {
+            mode,
+            query: TextInput::default(),
+            matches: Vec::new(),
+            state: ListState::default(),
+        };
+        palette.update_matches();
+        palette
+    }
+
+    fn selected(&self) -> Option<Action> {
+        let i = self.state.selected()?;
+        Some(self.matches.get(i)?.action)
+    }
+
+    /// Narrow the actions to those matching the edited query.
+    fn handle_query_key(&mut self, key: KeyEvent) {
+        self.query.handle_key(key);
+        self.update_matches();
+    }
+
+    /// Match the actions against the query, selecting the best match.
+    fn update_matches(&mut self) {
+        let matcher = SkimMatcherV2::default().smart_case();
+        let query = self.query.value.trim();
+
+        let mut scored: Vec<_> = self
+            .mode
+            .commands()
+            .into_iter()
+            .filter_map(|action| {
+                if query.is_empty() {
+                    return Some((
+                        0,
+                        CommandMatch {
+                            action,
+                            positions: Vec::new(),
+                        },
+                    ));
+                }
+                let (score, positions) = matcher.fuzzy_indices(action.description(), query)?;
+                Some((score, CommandMatch { action, positions }))
+            })
+            .collect();
+        // Stable, so equally good matches stay in keybinding order.
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        self.matches = scored
+            .into_iter()
+            .map(|(_, command_match)| command_match)
+            .collect();
+
+        self.state.select((!self.matches.is_empty()).then_some(0));
+    }
+}
+
+/// A popup listing the environment variables of a function, with their values masked until
+/// revealed.
+#[derive(Debug)]
+struct EnvironmentView {
+    function: aws::lambda::Function,
+    revealed: bool,
+    // Lines scrolled past.
+    scroll: usize,
+}
+
+/// A popup of the whole of a log event, with its message pretty-printed if it has JSON.
+#[derive(Debug)]
+struct EventView {
+    event: aws::cloudwatch_logs::LogEvent,
+    // The JSON of the message, explored as a tree, if it has any.
+    tree: Option<JsonTree>,
+    // Lines scrolled past.
+    scroll: usize,
+}
+
+impl EventView {
+    fn new(event: aws::cloudwatch_logs::LogEvent) -> Self {
+        Self {
+            tree: log_format::parse_json(&event.message).map(|(_, value)| JsonTree::new(value)),
+            event,
+            scroll: 0,
+        }
+    }
+}
+
+/// A popup of the timeline of an X-Ray trace, loaded in the background.
+#[derive(Debug)]
+struct TraceView {
+    trace_id: String,
+    // The trace once loaded, or `None` if X-Ray has no such trace.
+    trace: Option<Result<Option<aws::xray::Trace>, AwsError>>,
+    // Lines scrolled past.
+    scroll: usize,
+}
+
+/// A popup of the recent executions of a state machine, loaded in the background, or of the
+/// event history of one of them once opened.
+#[derive(Debug)]
+struct ExecutionView {
+    state_machine: aws::step_functions::StateMachine,
+    // The executions once loaded, most recent first.
+    executions: Option<Result<Vec<aws::step_functions::Execution>, AwsError>>,
+    state: ListState,
+    history: Option<ExecutionHistory>,
+}
+
+impl ExecutionView {
+    fn selected(&self) -> Option<&aws::step_functions::Execution> {
+        let executions = self.executions.as_ref()?.as_ref().ok()?;
+        executions.get(self.state.selected()?)
+    }
+}
+
+/// A popup of the event source mappings of a function, loaded in the background.
+#[derive(Debug)]
+struct EventSourceView {
+    function: aws::lambda::Function,
+    mappings: Option<Result<Vec<aws::lambda::EventSourceMapping>, AwsError>>,
+    state: ListState,
+}
+
+/// A popup of the failed events of a function, peeked at in its dead-letter queue, or
+/// on-failure destination, in the background.
+#[derive(Debug)]
+struct FailedEventView {
+    function: aws::lambda::Function,
+    queue_arn: String,
+    // The messages once peeked at, oldest first.
+    messages: Option<Result<Vec<aws::sqs::Message>, AwsError>>,
+    state: ListState,
+}
+
+impl FailedEventView {
+    fn selected(&self) -> Option<&aws::sqs::Message> {
+        let messages = self.messages.as_ref()?.as_ref().ok()?;
+        messages.get(self.state.selected()?)
+    }
+}
+
+/// A popup of the recent builds of a CodeBuild project, loaded in the background.
+#[derive(Debug)]
+struct BuildView {
+    project: aws::codebuild::Project,
+    // The builds once loaded, most recent first.
+    builds: Option<Result<Vec<aws::codebuild::Build>, AwsError>>,
+    state: ListState,
+}
+
+impl BuildView {
+    fn selected(&self) -> Option<&aws::codebuild::Build> {
+        let builds = self.builds.as_ref()?.as_ref().ok()?;
+        builds.get(self.state.selected()?)
+    }
+}
+
+/// The event history of an execution, loaded in the background.
+#[derive(Debug)]
+struct ExecutionHistory {
+    execution: aws::step_functions::Execution,
+    events: Option<Result<Vec<aws::step_functions::HistoryEvent>, AwsError>>,
+    // Whether to view the logs of the functions the execution invoked once the history is loaded.
+    open_logs: bool,
+    // Lines scrolled past.
+    scroll: usize,
+}
+
+/// A popup of the CloudTrail events of a resource over the time range of the tab, loaded in
+/// the background, to see who changed it and when.
+#[derive(Debug)]
+struct CloudTrailView {
+    // The client of the region of the resource, to look events up again once filtered.
+    cloudtrail_client: aws::cloudtrail::Client,
+    // Events referencing the resource, and named like the event name, are listed, unless empty.
+    resource_name: String,
+    event_name: String,
+    start_time: i64,
+    end_time: i64,
+    // The events once loaded, most recent first.
+    events: Option<Result<Vec<aws::cloudtrail::TrailEvent>, AwsError>>,
+    state: ListState,
+    // The filter being edited, if any.
+    editing: Option<(TrailFilter, TextInput)>,
+}
+
+/// A filter of the events of a [`CloudTrailView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TrailFilter {
+    ResourceName,
+    EventName,
+}
+
+/// The help overlay, listing the keybindings of every mode.
+#[derive(Debug)]
+struct HelpView {
+    // The mode the help was opened from, listed first.
+    mode: Mode,
+    // Lines scrolled past.
+    scroll: usize,
+}
+
+/// How the main area is split between the list and the pane under it.
+#[derive(Debug)]
+struct Split {
+    // Share of the main area taken by the list, in percent.
+    list_percent: u16,
+    // Whether the list is collapsed, leaving the whole main area to the pane under it.
+    collapsed: bool,
+    // Whether the border between the panes is being dragged with the mouse.
+    dragging: bool,
+}
+
+impl Default for Split {
+    fn default() -> Self {
+        Self {
+            list_percent: DEFAULT_LIST_PERCENT,
+            collapsed: false,
+            dragging: false,
+        }
+    }
+}
+
+/// The columns shown left of log messages, each of which can be hidden to make room for them.
+#[derive(Debug)]
+struct Gutters {
+    line_numbers: bool,
+    timestamps: bool,
+    stream_names: bool,
+}
+
+impl Default for Gutters {
+    fn default() -> Self {
+        Self {
+            line_numbers: false,
+            timestamps: true,
+            stream_names: false,
+        }
+    }
+}
+
+impl Split {
+    fn resize(&mut self, list_percent: u16) {
+        self.list_percent = list_percent.clamp(MIN_LIST_PERCENT, MAX_LIST_PERCENT);
+        self.collapsed = false;
+    }
+}
+
+/// Log views opened in a single tab, each tab with its own logs, queries, and filters.
+#[derive(Debug)]
+struct Tab {
+    // The client, and region, the tab's logs were opened in.
+    logs_client: aws::cloudwatch_logs::Client,
+    region: Option<String>,
+    log_stream_list: Option<LogStreamList>,
+    log_view: Option<LogView>,
+    insights_view: Option<InsightsView>,
+    focus: Focus,
+    // Filter patterns applied to each log group, by log group name.
+    filter_patterns: HashMap<String, String>,
+    // The time range log events are loaded, and queried, from.
+    time_range: TimeRange,
+}
+
+impl Tab {
+    fn new(logs_client: aws::cloudwatch_logs::Client, region: Option<String>) -> Self {
+        Self {
+            logs_client,
+            region,
+            log_stream_list: None,
+            log_view: None,
+            insights_view: None,
+            focus: Focus::default(),
+            filter_patterns: HashMap::new(),
+            time_range: TimeRange::default(),
+        }
+    }
+
+    /// The name of the tab, after the last part of the name of the log group it views.
+    fn title(&self) -> &str {
+        let log_group_name = match (&self.insights_view, &self.log_view, &self.log_stream_list) {
+            (Some(insights_view), _, _) => &insights_view.log_group_name,
+            (_, Some(log_view), _) => &log_view.log_group_name,
+            (_, _, Some(log_stream_list)) => &log_stream_list.log_group_name,
+            _ => return "New tab",
+        };
+        log_group_name.rsplit('/').next().unwrap_or(log_group_name)
+    }
+}
+
+/// The pane that receives key presses.
+#[derive(Debug, Default, PartialEq, Eq)]
+enum Focus {
+    #[default]
+    List,
+    LogStreams,
+    Logs,
+    Invocations,
+    FlowLogs,
+    Insights,
+}
+
+#[derive(Debug)]
+pub struct App {
+    events: EventHandler,
+    // Clients of every region functions are listed from, usually just one.
+    clients: Vec<RegionalClients>,
+    // The client of the region of the viewed logs.
+    logs_client: aws::cloudwatch_logs::Client,
+    // The AWS profile and comma-separated regions the clients were created with, when not inferred.
+    profile: Option<String>,
+    region: Option<String>,
+    // The IAM role assumed with the credentials of the profile, if any.
+    role_arn: Option<String>,
+    // Whether the credentials are being loaded again, having expired, and when they last were.
+    refreshing_credentials: bool,
+    credentials_refreshed: Option<Instant>,
+    // The region of the viewed logs, whether inferred or not.
+    active_region: Option<String>,
+    // The account, and user or role, that the credentials are of, once loaded.
+    caller_identity: Option<aws::sts::CallerIdentity>,
+    // Matches the account ID, profile, or ARN of production accounts, to warn of them.
+    production_pattern: Option<Regex>,
+    // Match the messages of tailed log events to alert of, and those matched until acknowledged.
+    alert_patterns: Vec<AlertPattern>,
+    alert: Option<Alert>,
+    // Whether the terminal is focused, as far as it tells, to notify of alerts on the desktop
+    // while it isn't.
+    focused: bool,
+    function_list: FunctionList,
+    log_group_list: LogGroupList,
+    service_list: ServiceList,
+    state_machine_list: StateMachineList,
+    stage_list: StageList,
+    database_list: DatabaseList,
+    project_list: ProjectList,
+    list_mode: ListMode,
+    // Log views of every open tab, and which of them is shown.
+    tabs: Vec<Tab>,
+    tab: usize,
+    time_range_form: Option<TimeRangeForm>,
+    // The Logs Insights query being built, rather than edited as text.
+    query_builder: Option<QueryBuilder>,
+    // The filter pattern being edited, for the log group in `filter_log_group_name`.
+    filter_input: Option<TextInput>,
+    filter_log_group_name: String,
+    // The search of the loaded log events being edited.
+    search_input: Option<TextInput>,
+    // The filter pattern to search the log groups in `group_search_log_group_names` with
+    // together, being edited.
+    group_search_input: Option<TextInput>,
+    group_search_log_group_names: Vec<String>,
+    // The regex filter of the loaded log events being edited.
+    regex_filter_input: Option<TextInput>,
+    // The filter of the VPC flow log records being edited.
+    flow_filter_input: Option<TextInput>,
+    // The path of the file to export the shown log events to, being edited.
+    export_input: Option<TextInput>,
+    // The S3 bucket to export the log group in `s3_export_log_group_name` to, being edited.
+    s3_export_input: Option<TextInput>,
+    s3_export_log_group_name: String,
+    // The S3 bucket, and key prefix, last exported to, to export to again.
+    s3_destination: Option<String>,
+    // The path of the file to download the log group in `download_log_group_name` to, being
+    // edited.
+    download_input: Option<TextInput>,
+    download_log_group_name: String,
+    downloads: Vec<Download>,
+    // The ID of the next download, to tell downloads of the same log group apart.
+    next_download_id: u64,
+    // The tags to filter the functions by, being edited.
+    tag_filter_input: Option<TextInput>,
+    // The name to save the Logs Insights query as, being edited.
+    query_name_input: Option<TextInput>,
+    invoke_input: Option<InvokeInput>,
+    // The last payload each function was invoked with, to invoke it with again.
+    invoke_payloads: HashMap<aws::lambda::Function, String>,
+    // The most recent failure of the last action, shown until the next key press.
+    error: Option<String>,
+    // The outcome of the last action, such as copying a log event, shown until the next key press.
+    notice: Option<String>,
+    // What became of background tasks, shown until they are dismissed after a while.
+    toasts: Toasts,
+    // The most recent failure to call AWS, shown until retried or dismissed.
+    error_screen: Option<ErrorScreen>,
+    picker: Option<Picker>,
+    confirmation: Option<Confirmation>,
+    help: Option<HelpView>,
+    environment: Option<EnvironmentView>,
+    trace: Option<TraceView>,
+    event_view: Option<EventView>,
+    executions: Option<ExecutionView>,
+    event_sources: Option<EventSourceView>,
+    failed_events: Option<FailedEventView>,
+    builds: Option<BuildView>,
+    cloudtrail: Option<CloudTrailView>,
+    // The log groups viewed most recently, most recent first.
+    recent_log_groups: Vec<String>,
+    // Filter patterns and Logs Insights queries input before.
+    history: History,
+    // Logs Insights queries named in the config, and saved in the app, sorted by name.
+    configured_queries: Vec<SavedQuery>,
+    saved_queries: Vec<SavedQuery>,
+    // Logs Insights queries saved in CloudWatch Logs, as last loaded.
+    query_definitions: Vec<aws::cloudwatch_logs::QueryDefinition>,
+    // Whether queries saved in the app are saved to CloudWatch Logs too.
+    sync_queries: bool,
+    // The function whose versions are being loaded, or picked from.
+    versions_function: Option<aws::lambda::Function>,
+    palette: Option<Palette>,
+    theme: Theme,
+    // Ticks elapsed, to animate the loading spinner.
+    ticks: usize,
+    // How often to refresh the functions, if at all.
+    refresh_interval: Option<Duration>,
+    // Whether the mouse is captured, rather than left to the terminal.
+    mouse: bool,
+    // The item selected in the list, and when it was selected.
+    selection: (ListMode, Option<usize>),
+    selection_changed: Instant,
+    // Where the list and the pane under it were last drawn, to find the pane under the mouse.
+    list_area: Rect,
+    item_area: Rect,
+    // Where the volume of log events was last drawn, if at all, to find the bucket clicked.
+    volume_area: Rect,
+    split: Split,
+    // Whether the volume of log events over time is shown above them.
+    show_volume: bool,
+    // Whether JSON log messages are pretty-printed.
+    pretty_json: bool,
+    // Whether long log messages are wrapped over several rows, rather than cut off.
+    wrap_lines: bool,
+    // The columns of the table of JSON log messages, and whether it is shown.
+    columns: Vec<ColumnConfig>,
+    show_table: bool,
+    // How long log events streamed by Live Tail are marked as new for.
+    new_event_highlight: Duration,
+    gutters: Gutters,
+    timestamp_format: TimestampFormat,
+    should_exit: bool,
+}
+
+impl App {
+    /// Create the app, loading the Lambda functions in the background.
+    pub fn new(
+        sdk_config: &SdkConfig,
+        config: &Config,
+        theme: Theme,
+        production_pattern: Option<Regex>,
+        alert_patterns: Vec<AlertPattern>,
+    ) -> Self {
+        debug_assert!(
+            Mode::ALL.iter().all(|mode| mode.duplicate_key().is_none()),
+            "A key is bound twice in the same mode"
+        );
+        let regions = config
+            .region
+            .as_deref()
+            .map(aws::config::parse_regions)
+            .unwrap_or_default();
+        let mut app = Self {
+            events: EventHandler::new(TICK_RATE),
+            clients: RegionalClients::for_regions(sdk_config, &regions),
+            logs_client: aws::cloudwatch_logs::Client::new(sdk_config),
+            profile: config.profile.clone(),
+            region: config.region.clone(),
+            role_arn: config.role_arn.clone(),
+            refreshing_credentials: false,
+            credentials_refreshed: None,
+            active_region: sdk_config.region().map(ToString::to_string),
+            caller_identity: None,
+            production_pattern,
+            alert_patterns,
+            alert: None,
+            focused: true,
+            function_list: FunctionList::default(),
+            log_group_list: LogGroupList::default(),
+            service_list: ServiceList::default(),
+            state_machine_list: StateMachineList::default(),
+            stage_list: StageList::default(),
+            database_list: DatabaseList::default(),
+            project_list: ProjectList::default(),
+            list_mode: ListMode::default(),
+            tabs: vec![Tab::new(
+                aws::cloudwatch_logs::Client::new(sdk_config),
+                sdk_config.region().map(ToString::to_string),
+            )],
+            tab: 0,
+            time_range_form: None,
+            query_builder: None,
+            filter_input: None,
+            filter_log_group_name: String::new(),
+            search_input: None,
+            group_search_input: None,
+            group_search_log_group_names: Vec::new(),
+            regex_filter_input: None,
+            flow_filter_input: None,
+            export_input: None,
+            s3_export_input: None,
+            s3_export_log_group_name: String::new(),
+            s3_destination: config.export_bucket.clone(),
+            download_input: None,
+            download_log_group_name: String::new(),
+            downloads: Vec::new(),
+            next_download_id: 0,
+            tag_filter_input: None,
+            query_name_input: None,
+            invoke_input: None,
+            invoke_payloads: HashMap::new(),
+            error: None,
+            notice: None,
+            toasts: Toasts::default(),
+            error_screen: None,
+            picker: None,
+            confirmation: None,
+            help: None,
+            environment: None,
+            trace: None,
+            event_view: None,
+            executions: None,
+            event_sources: None,
+            failed_events: None,
+            builds: None,
+            cloudtrail: None,
+            recent_log_groups: Vec::new(),
+            history: History::load(),
+            configured_queries: config.configured_queries(),
+            saved_queries: Vec::new(),
+            query_definitions: Vec::new(),
+            sync_queries: config.sync_queries(),
+            versions_function: None,
+            palette: None,
+            theme,
+            ticks: 0,
+            refresh_interval: config.refresh_interval(),
+            mouse: config.mouse(),
+            selection: (ListMode::default(), None),
+            selection_changed: Instant::now(),
+            list_area: Rect::default(),
+            item_area: Rect::default(),
+            volume_area: Rect::default(),
+            show_volume: true,
+            split: Split::default(),
+            pretty_json: false,
+            wrap_lines: false,
+            new_event_highlight: config.new_event_highlight(),
+            columns: config.columns.clone(),
+            show_table: false,
+            gutters: Gutters::default(),
+            timestamp_format: config.timestamp_format(),
+            should_exit: false,
+        };
+        app.restore_session();
+        app.load_functions();
+        app.load_caller_identity(sdk_config);
+        app
+    }
+
+    pub async fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        if self.mouse {
+            self.capture_mouse(true);
+        }
+        // Terminals that don't report focus are taken to stay focused.
+        let _ = execute!(io::stdout(), EnableFocusChange);
+        while !self.should_exit {
+            terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
+            let Some(event) = self.events.next().await else {
+                break;
+            };
+            self.handle_event(event);
+            self.load_function_activity();
+            if self.selection_settled() {
+                self.load_function_configuration();
+                self.load_function_tags();
+                self.load_function_metrics();
+                self.load_service_logs();
+            }
+        }
+        if self.mouse {
+            self.capture_mouse(false);
+        }
+        let _ = execute!(io::stdout(), DisableFocusChange);
+        self.save_session();
+        // Failing to save the history only loses what was input before.
+        let _ = self.history.save();
+        Ok(())
+    }
+
+    /// Whether the selection of the list has stayed the same for long enough to load the details
+    /// of the selected item, rather than those of every item scrolled past.
+    fn selection_settled(&mut self) -> bool {
+        let selection = (self.list_mode, self.list_state_mut().selected());
+        if selection != self.selection {
+            self.selection = selection;
+            self.selection_changed = Instant::now();
+        }
+
+        self.selection_changed.elapsed() >= SELECTION_DEBOUNCE
+    }
+
+    /// Restore where the user left off with the profile and region, when the app last quit.
+    fn restore_session(&mut self) {
+        let session = Session::load(self.profile.as_deref(), self.region.as_deref());
+
+        self.function_list.restore = session.function_name.map(|name| {
+            let function = aws::lambda::Function {
+                name,
+                region: session.function_region,
+            };
+            (function, session.list_offset)
+        });
+        self.function_list.favorites = session
+            .favorites
+            .into_iter()
+            .map(|favorite| aws::lambda::Function {
+                name: favorite.name,
+                region: favorite.region,
+            })
+            .collect();
+        self.recent_log_groups = session.recent_log_groups;
+        self.saved_queries = session.saved_queries;
+        let tab = self.tab_mut();
+        tab.time_range = session.time_range;
+        tab.filter_patterns = session.filter_patterns;
+    }
+
+    /// Star the selected function as a favorite, listing it first, or unstar it.
+    fn toggle_favorite(&mut self) {
+        if self.list_mode != ListMode::Functions {
+            return;
+        }
+        let Some(function) = self.function_list.selected().cloned() else {
+            return;
+        };
+
+        let favorites = &mut self.function_list.favorites;
+        self.notice = if favorites.remove(&function) {
+            Some(format!("Unstarred {}", function.name))
+        } else {
+            favorites.insert(function.clone());
+            Some(format!("Starred {}", function.name))
+        };
+        self.function_list.update_matches(Some(function));
+    }
+
+    /// Mark the selected function to tail with the other marked functions, or unmark it, then
+    /// select the next function.
+    fn mark_function(&mut self) {
+        if self.list_mode != ListMode::Functions {
+            return;
+        }
+        let Some(function) = self.function_list.selected().cloned() else {
+            return;
+        };
+
+        let marked = &mut self.function_list.marked;
+        if !marked.remove(&function) {
+            if marked.len() >= MAX_TAILED_LOG_GROUPS {
+                self.notice = Some(format!(
+                    "At most {MAX_TAILED_LOG_GROUPS} functions can be tailed together"
+                ));
+                return;
+            }
+            marked.insert(function);
+        }
+        self.function_list.state.select_next();
+    }
+
+    /// Mark the selected log group to search with the other marked log groups, or unmark it,
+    /// then select the next log group.
+    fn mark_log_group(&mut self) {
+        let Some(log_group) = self.selected_log_group() else {
+            return;
+        };
+        let name = log_group.name.clone();
+
+        let marked = &mut self.log_group_list.marked;
+        if !marked.remove(&name) {
+            if marked.len() >= MAX_SEARCHED_LOG_GROUPS {
+                self.notice = Some(format!(
+                    "At most {MAX_SEARCHED_LOG_GROUPS} log groups can be searched together"
+                ));
+                return;
+            }
+            marked.insert(name);
+        }
+        self.log_group_list.state.select_next();
+    }
+
+    /// Tail the logs of the marked functions together, their log events merged in the order
+    /// they were logged.
+    fn tail_marked_functions(&mut self) {
+        let mut functions: Vec<aws::lambda::Function> =
+            self.function_list.marked.iter().cloned().collect();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+        let Some(first) = functions.first() else {
+            return;
+        };
+        // A Live Tail session only tails the log groups of a single region.
+        let region = first.region.clone();
+        if functions.iter().any(|function| function.region != region) {
+            self.error =
+                Some("Only functions in the same region can be tailed together".to_string());
+            return;
+        }
+
+        self.use_logs_region(region.as_deref());
+        let mut log_group_names = functions
+            .iter()
+            .map(|function| aws::cloudwatch_logs::lambda_log_group_name(&function.name));
+        let Some(log_group_name) = log_group_names.next() else {
+            return;
+        };
+        self.tab_mut().log_view = Some(LogView {
+            log_group_name,
+            merged_log_group_names: log_group_names.collect(),
+            // Older log events are only loaded of a single log group.
+            loaded_oldest: true,
+            ..Default::default()
+        });
+        self.tab_mut().focus = Focus::Logs;
+        self.toggle_live_tail();
+    }
+
+    /// Save where the user left off with the profile and region, to restore on the next run.
+    fn save_session(&self) {
+        let function = self.function_list.selected().or(self
+            .function_list
+            .restore
+            .as_ref()
+            .map(|(function, _)| function));
+        let tab = self.tab();
+        let session = Session {
+            function_name: function.map(|function| function.name.clone()),
+            function_region: function.and_then(|function| function.region.clone()),
+            list_offset: self.function_list.state.offset(),
+            time_range: tab.time_range,
+            filter_patterns: tab.filter_patterns.clone(),
+            recent_log_groups: self.recent_log_groups.clone(),
+            saved_queries: self.saved_queries.clone(),
+            favorites: self
+                .function_list
+                .favorites
+                .iter()
+                .map(|function| FavoriteFunction {
+                    name: function.name.clone(),
+                    region: function.region.clone(),
+                })
+                .collect(),
+        };
+
+        // Failing to save the session only loses where the user left off.
+        let _ = session.save(self.profile.as_deref(), self.region.as_deref());
+    }
+
+    fn handle_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::Key(key) => self.handle_key(key),
+            AppEvent::Mouse(mouse) => self.handle_mouse(mouse),
+            AppEvent::Resize => {}
+            AppEvent::Focus(focused) => self.focused = focused,
+            AppEvent::Tick => self.tick(),
+            AppEvent::FunctionsPageLoaded(page) => match page {
+                Ok(page) => {
+                    // The region is finished with after its last page, listing the pages in
+                    // place of any cached functions once all regions are.
+                    let last = page.next_marker.is_none();
+                    self.function_list.summaries.extend(page.summaries);
+                    self.function_list.extend(page.functions);
+                    if last {
+                        self.function_list.finish_loading_region();
+                        if !self.function_list.loading {
+                            self.cache_functions();
+                        }
+                    }
+                }
+                Err(error) => {
+                    // The cached functions are kept rather than listing some of them.
+                    self.function_list.loaded = None;
+                    self.function_list.finish_loading_region();
+                    self.show_error(error, Retry::LoadFunctions);
+                }
+            },
+            AppEvent::SdkConfigLoaded {
+                profile,
+                region,
+                sdk_config,
+                credentials_only,
+            } => {
+                if credentials_only && profile == self.profile && region == self.region {
+                    self.refresh_clients(&sdk_config);
+                } else if profile == self.profile && region == self.region {
+                    let regions = region
+                        .as_deref()
+                        .map(aws::config::parse_regions)
+                        .unwrap_or_default();
+                    self.active_region = sdk_config.region().map(ToString::to_string);
+                    self.clients = RegionalClients::for_regions(&sdk_config, &regions);
+                    self.logs_client = aws::cloudwatch_logs::Client::new(&sdk_config);
+                    self.tabs = vec![Tab::new(
+                        self.logs_client.clone(),
+                        self.active_region.clone(),
+                    )];
+                    self.tab = 0;
+                    self.restore_session();
+                    self.load_functions();
+                    self.load_caller_identity(&sdk_config);
+                }
+            }
+            AppEvent::CallerIdentityLoaded {
+                profile,
+                region,
+                identity,
+            } => {
+                if profile != self.profile || region != self.region {
+                    return;
+                }
+                match identity {
+                    Ok(identity) => {
+                        self.caller_identity = Some(identity);
+                        if self.is_production() {
+                            self.toasts.push(
+                                Severity::Warning,
+                                "This looks like a production account, take care",
+                            );
+                        }
+                    }
+                    Err(error) => self.toasts.push(
+                        Severity::Warning,
+                        format!("Failed to tell which account this is: {error}"),
+                    ),
+                }
+            }
+            AppEvent::FunctionConfigurationLoaded {
+                function,
+                configuration,
+            } => {
+                let details = match *configuration {
+                    Ok(configuration) => FunctionDetails::Loaded(configuration),
+                    Err(error) => FunctionDetails::Failed(error),
+                };
+                self.function_list.configurations.insert(function, details);
+            }
+            AppEvent::FunctionActivityLoaded {
+                function,
+                last_event_time,
+            } => {
+                let details = match last_event_time {
+                    Ok(last_event_time) => FunctionDetails::Loaded(last_event_time),
+                    Err(error) => FunctionDetails::Failed(error),
+                };
+                self.function_list.last_activity.insert(function, details);
+            }
+            AppEvent::FunctionTagsLoaded { function, tags } => {
+                let details = match tags {
+                    Ok(tags) => FunctionDetails::Loaded(tags),
+                    Err(error) => FunctionDetails::Failed(error),
+                };
+                self.function_list.tags.insert(function, details);
+                if !self.function_list.tag_filter.is_empty() || self.function_list.grouped {
+                    let selected = self.function_list.selected().cloned();
+                    self.function_list.update_matches(selected);
+                }
+            }
+            AppEvent::FunctionMetricsLoaded {
+                function,
+                time_range,
+                metrics,
+            } => {
+                let details = match metrics {
+                    Ok(metrics) => FunctionDetails::Loaded(metrics),
+                    Err(error) => FunctionDetails::Failed(error),
+                };
+                self.function_list
+                    .metrics
+                    .insert((function, time_range), details);
+            }
+            AppEvent::FunctionVersionsLoaded { function, versions } => {
+                if self.versions_function.as_ref() != Some(&function) {
+                    return;
+                }
+                match versions {
+                    Ok(versions) => {
+                        let items = versions
+                            .into_iter()
+                            .map(|function_version| {
+                                // The version comes first, as it is what is picked.
+                                let mut item = function_version.version;
+                                if !function_version.aliases.is_empty() {
+                                    item.push_str(&format!(
+                                        "  ({})",
+                                        function_version.aliases.join(", ")
+                                    ));
+                                }
+                                item
+                            })
+                            .collect();
+                        self.open_picker(PickerKind::Version, items, "");
+                    }
+                    Err(error) => self.show_error(error, Retry::LoadFunctionVersions),
+                }
+            }
+            AppEvent::FunctionInvoked {
+                function,
+                dry_run,
+                invocation,
+            } => match invocation {
+                Ok(_) if dry_run => {
+                    self.notice =
+                        Some(format!("{} can be invoked with the payload", function.name));
+                }
+                Ok(invocation) => self.show_invocation(function, invocation),
+                Err(error) => self.toasts.push(Severity::Error, error.to_string()),
+            },
+            AppEvent::TraceLoaded { trace_id, trace } => {
+                if let Some(trace_view) = &mut self.trace
+                    && trace_view.trace_id == trace_id
+                {
+                    trace_view.trace = Some(trace);
+                }
+            }
+            AppEvent::FunctionsRefreshed(functions) => {
+                self.function_list.finish_loading();
+                match functions {
+                    Ok(page) => {
+                        self.function_list.summaries = page.summaries;
+                        self.function_list.replace(page.functions);
+                        self.cache_functions();
+                    }
+                    Err(error) => self.show_error(error, Retry::RefreshFunctions),
+                }
+            }
+            AppEvent::LogGroupsLoaded(log_groups) => {
+                self.log_group_list.loading = false;
+                match log_groups {
+                    Ok(log_groups) => {
+                        self.log_group_list.log_groups = Some(log_groups);
+                        self.log_group_list.sort();
+                    }
+                    Err(error) => self.show_error(error, Retry::LoadLogGroups),
+                }
+            }
+            AppEvent::ServicesLoaded(services) => {
+                self.service_list.loading = false;
+                match services {
+                    Ok(services) => self.service_list.services = Some(services),
+                    Err(error) => self.show_error(error, Retry::LoadServices),
+                }
+            }
+            AppEvent::ServiceLogsLoaded {
+                service,
+                service_logs,
+            } => {
+                let details = match service_logs {
+                    Ok(service_logs) => FunctionDetails::Loaded(service_logs),
+                    Err(error) => FunctionDetails::Failed(error),
+                };
+                self.service_list.logs.insert(service, details);
+            }
+            AppEvent::StateMachinesLoaded(state_machines) => {
+                self.state_machine_list.loading = false;
+                match state_machines {
+                    Ok(state_machines) => {
+                        self.state_machine_list.state_machines = Some(state_machines);
+                    }
+                    Err(error) => self.show_error(error, Retry::LoadStateMachines),
+                }
+            }
+            AppEvent::StagesLoaded(stages) => {
+                self.stage_list.loading = false;
+                match stages {
+                    Ok(stages) => self.stage_list.stages = Some(stages),
+                    Err(error) => self.show_error(error, Retry::LoadStages),
+                }
+            }
+            AppEvent::DatabasesLoaded(databases) => {
+                self.database_list.loading = false;
+                match databases {
+                    Ok(databases) => self.database_list.databases = Some(databases),
+                    Err(error) => self.show_error(error, Retry::LoadDatabases),
+                }
+            }
+            AppEvent::ProjectsLoaded(projects) => {
+                self.project_list.loading = false;
+                match projects {
+                    Ok(projects) => self.project_list.projects = Some(projects),
+                    Err(error) => self.show_error(error, Retry::LoadProjects),
+                }
+            }
+            AppEvent::EventSourceMappingsLoaded { function, mappings } => {
+                if let Some(event_source_view) = &mut self.event_sources
+                    && event_source_view.function == function
+                {
+                    if let Ok(mappings) = &mappings {
+                        event_source_view
+                            .state
+                            .select((!mappings.is_empty()).then_some(0));
+                    }
+                    event_source_view.mappings = Some(mappings);
+                }
+            }
+            AppEvent::FailedEventsLoaded {
+                queue_arn,
+                messages,
+            } => {
+                if let Some(failed_event_view) = &mut self.failed_events
+                    && failed_event_view.queue_arn == queue_arn
+                {
+                    if let Ok(messages) = &messages {
+                        failed_event_view
+                            .state
+                            .select((!messages.is_empty()).then_some(0));
+                    }
+                    failed_event_view.messages = Some(messages);
+                }
+            }
+            AppEvent::BuildsLoaded { project, builds } => {
+                if let Some(build_view) = &mut self.builds
+                    && build_view.project == project
+                {
+                    if let Ok(builds) = &builds {
+                        build_view.state.select((!builds.is_empty()).then_some(0));
+                    }
+                    build_view.builds = Some(builds);
+                }
+            }
+            AppEvent::ExecutionsLoaded {
+                state_machine,
+                executions,
+            } => {
+                if let Some(execution_view) = &mut self.executions
+                    && execution_view.state_machine == state_machine
+                {
+                    if let Ok(executions) = &executions {
+                        execution_view
+                            .state
+                            .select((!executions.is_empty()).then_some(0));
+                    }
+                    execution_view.executions = Some(executions);
+                }
+            }
+            AppEvent::ExecutionHistoryLoaded {
+                execution_arn,
+                history,
+            } => {
+                let Some(execution_history) = self
+                    .executions
+                    .as_mut()
+                    .and_then(|execution_view| execution_view.history.as_mut())
+                    .filter(|execution_history| execution_history.execution.arn == execution_arn)
+                else {
+                    return;
+                };
+                let open_logs = execution_history.open_logs && history.is_ok();
+                execution_history.events = Some(history);
+                if open_logs {
+                    self.open_execution_logs();
+                }
+            }
+            AppEvent::CloudTrailEventsLoaded {
+                resource_name,
+                event_name,
+                events,
+            } => {
+                if let Some(cloudtrail) = &mut self.cloudtrail
+                    && cloudtrail.resource_name == resource_name
+                    && cloudtrail.event_name == event_name
+                {
+                    cloudtrail.state.select(
+                        events
+                            .as_ref()
+                            .is_ok_and(|events| !events.is_empty())
+                            .then_some(0),
+                    );
+                    cloudtrail.events = Some(events);
+                }
+            }
+            AppEvent::LogStreamsLoaded {
+                log_group_name,
+                log_streams,
+            } => {
+                let Some(tab) = self.tabs.iter().position(|tab| {
+                    tab.log_stream_list.as_ref().is_some_and(|log_stream_list| {
+                        log_stream_list.loading && log_stream_list.log_group_name == log_group_name
+                    })
+                }) else {
+                    return;
+                };
+                let Some(log_stream_list) = &mut self.tabs[tab].log_stream_list else {
+                    return;
+                };
+
+                log_stream_list.loading = false;
+                match log_streams {
+                    Ok(mut log_streams) => {
+                        if let Some(scope) = &log_stream_list.scope {
+                            log_streams.retain(|log_stream| scope.includes(&log_stream.name));
+                        }
+                        log_stream_list
+                            .state
+                            .select((!log_streams.is_empty()).then_some(0));
+                        log_stream_list.log_streams = log_streams;
+                    }
+                    Err(error) => self.show_tab_error(tab, error, Retry::LoadLogStreams),
+                }
+            }
+            AppEvent::LogEventsLoaded {
+                log_group_name,
+                log_stream_names,
+                events,
+            } => {
+                let Some(tab) = self.tabs.iter().position(|tab| {
+                    tab.log_view.as_ref().is_some_and(|log_view| {
+                        log_view.loading && log_view.is_viewing(&log_group_name, &log_stream_names)
+                    })
+                }) else {
+                    return;
+                };
+                let Some(log_view) = &mut self.tabs[tab].log_view else {
+                    return;
+                };
+
+                log_view.loading = false;
+                match events {
+                    Ok(events) => {
+                        log_view.events = events;
+                        if log_view.invocations.is_some() {
+                            log_view.group_invocations();
+                        }
+                        // Start at the most recent event, like a terminal's scrollback.
+                        log_view.update_shown();
+                        // VPC flow logs are easier to read as a table than as raw records.
+                        log_view.tabulate_flow_logs();
+                        if log_view
+                            .flow_logs
+                            .as_ref()
+                            .is_some_and(|flow_logs| flow_logs.records.is_empty())
+                        {
+                            log_view.flow_logs = None;
+                        } else if self.tabs[tab].focus == Focus::Logs {
+                            self.tabs[tab].focus = Focus::FlowLogs;
+                        }
+                    }
+                    Err(error) => {
+                        let retry = if log_view.merged_log_group_names.is_empty() {
+                            Retry::LoadLogEvents
+                        } else {
+                            Retry::SearchLogGroups
+                        };
+                        self.show_tab_error(tab, error, retry);
+                    }
+                }
+            }
+            AppEvent::OlderLogEventsLoaded {
+                log_group_name,
+                log_stream_names,
+                events,
+            } => {
+                let Some(tab) = self.tabs.iter().position(|tab| {
+                    tab.log_view.as_ref().is_some_and(|log_view| {
+                        log_view.loading_older
+                            && log_view.is_viewing(&log_group_name, &log_stream_names)
+                    })
+                }) else {
+                    return;
+                };
+                let Some(log_view) = &mut self.tabs[tab].log_view else {
+                    return;
+                };
+
+                log_view.loading_older = false;
+                match events {
+                    Ok(events) => log_view.prepend(events),
+                    Err(error) => self.show_tab_error(tab, error, Retry::LoadOlderLogEvents),
+                }
+            }
+            AppEvent::LiveTailEvents {
+                log_group_name,
+                log_stream_names,
+                events,
+            } => {
+                let Some(tab) = self.tabs.iter().position(|tab| {
+                    tab.log_view.as_ref().is_some_and(|log_view| {
+                        log_view.live_tail.is_some()
+                            && log_view.is_viewing(&log_group_name, &log_stream_names)
+                    })
+                }) else {
+                    return;
+                };
+                if let Ok(events) = &events {
+                    self.alert(events);
+                }
+                let Some(log_view) = &mut self.tabs[tab].log_view else {
+                    return;
+                };
+
+                match events {
+                    Ok(events) => log_view.append(events),
+                    Err(error) => {
+                        // The session has ended, so stop showing it as live.
+                        log_view.live_tail = None;
+                        // Long sessions outlive temporary credentials, so are resumed with new
+                        // ones, unless the credentials aren't being renewed.
+                        if error.is_credentials_expired()
+                            && self.credentials_refreshed.is_none_or(|refreshed| {
+                                refreshed.elapsed() >= MIN_CREDENTIALS_LIFETIME
+                            })
+                        {
+                            log_view.resuming_live_tail = true;
+                            self.refresh_credentials();
+                        } else {
+                            self.show_tab_error(tab, error, Retry::LiveTail);
+                        }
+                    }
+                }
+            }
+            AppEvent::QueryDefinitionsLoaded(result) => match result {
+                Ok(query_definitions) => {
+                    self.query_definitions = query_definitions;
+                    let items = self
+                        .query_choices()
+                        .into_iter()
+                        .map(|(label, _)| label)
+                        .collect();
+                    if let Some(picker) = &mut self.picker
+                        && picker.kind == PickerKind::Query
+                    {
+                        picker.items = items;
+                    }
+                }
+                Err(error) => self.toasts.push(Severity::Warning, error.to_string()),
+            },
+            AppEvent::QueryResultsLoaded {
+                log_group_name,
+                results,
+            } => {
+                let Some(tab) = self.tabs.iter().position(|tab| {
+                    tab.insights_view.as_ref().is_some_and(|insights_view| {
+                        insights_view.running && insights_view.log_group_name == log_group_name
+                    })
+                }) else {
+                    return;
+                };
+                let Some(insights_view) = &mut self.tabs[tab].insights_view else {
+                    return;
+                };
+
+                insights_view.running = false;
+                match results {
+                    Ok(results) => insights_view.show_results(results),
+                    Err(error) => self.show_tab_error(tab, error, Retry::RunQuery),
+                }
+            }
+            AppEvent::RetentionSet {
+                log_group_name,
+                retention_in_days,
+                result,
+            } => match result {
+                Ok(()) => {
+                    if let Some(log_group) = self
+                        .log_group_list
+                        .log_groups
+                        .iter_mut()
+                        .flatten()
+                        .find(|log_group| log_group.name == log_group_name)
+                    {
+                        log_group.retention_in_days = retention_in_days;
+                    }
+                    self.toasts.push(
+                        Severity::Info,
+                        format!(
+                            "Set the retention of {log_group_name} to {}",
+                            aws::cloudwatch_logs::retention_label(retention_in_days).to_lowercase()
+                        ),
+                    );
+                }
+                Err(error) => self.toasts.push(Severity::Error, error.to_string()),
+            },
+            AppEvent::DownloadProgress {
+                id,
+                count,
+                progress,
+            } => {
+                if let Some(download) = self.downloads.iter_mut().find(|download| download.id == id)
+                {
+                    download.count = count;
+                    download.progress = progress;
+                }
+            }
+            AppEvent::DownloadFinished { id, outcome } => {
+                self.downloads.retain(|download| download.id != id);
+                match outcome {
+                    Ok(notice) => self.toasts.push(Severity::Info, notice),
+                    Err(error) => self.toasts.push(Severity::Error, error),
+                }
+            }
+            AppEvent::LogRecordLoaded(event) => {
+                self.notice = None;
+                match event {
+                    Ok(Some(event)) => self.event_view = Some(EventView::new(event)),
+                    Ok(None) => self.error = Some("The log event no longer exists".to_string()),
+                    Err(error) => {
+                        self.error = Some(format!("Failed to load the log event: {error}"))
+                    }
+                }
+            }
+            AppEvent::SsoLoginOutput(line) => {
+                if let Some(sso_login) = self
+                    .error_screen
+                    .as_mut()
+                    .and_then(|error_screen| error_screen.sso_login.as_mut())
+                {
+                    sso_login.output.push(line);
+                }
+            }
+            AppEvent::SsoLoginFinished(finished) => {
+                let Some(sso_login) = self
+                    .error_screen
+                    .as_mut()
+                    .and_then(|error_screen| error_screen.sso_login.as_mut())
+                else {
+                    return;
+                };
+
+                sso_login.task = None;
+                match finished {
+                    Ok(()) => {
+                        self.notice = Some("Signed in with AWS SSO, retrying...".to_string());
+                        self.retry();
+                    }
+                    Err(error) => sso_login.error = Some(error),
+                }
+            }
+            AppEvent::Notice(notice) => self.toasts.push(Severity::Info, notice),
+            AppEvent::Error(error) => self.toasts.push(Severity::Error, error),
+        }
+    }
+
+    /// Animate, and refresh the functions once the refresh interval has elapsed.
+    fn tick(&mut self) {
+        self.ticks = self.ticks.wrapping_add(1);
+        self.toasts.expire();
+        // Batches of events arrive in order, so the first to no longer be new are the oldest.
+        let new_event_highlight = self.new_event_highlight;
+        for log_view in self.tabs.iter_mut().filter_map(|tab| tab.log_view.as_mut()) {
+            log_view
+                .arrivals
+                .retain(|(_, arrived)| arrived.elapsed() < new_event_highlight);
+        }
+
+        // Don't keep refreshing while a failure is being shown.
+        if let Some(refresh_interval) = self.refresh_interval
+            && self.error_screen.is_none()
+            && self
+                .function_list
+                .loaded_at
+                .is_some_and(|loaded_at| loaded_at.elapsed() >= refresh_interval)
+        {
+            self.refresh_functions();
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        self.error = None;
+        self.notice = None;
+
+        let mode = self.mode();
+        match mode.action(key) {
+            Some(action) => self.perform(action),
+            None if mode.edits_text() => self.edit_input(key),
+            None => {}
+        }
+    }
+
+    /// Scroll the pane under the mouse, or click to focus a pane and select one of its rows.
+    ///
+    /// Popups, and the Logs Insights view, are scrolled wherever the mouse is.
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        let mode = self.mode();
+        let position = Position::new(mouse.column, mouse.row);
+        let pane = match mode {
+            Mode::List | Mode::LogStreams | Mode::Logs | Mode::Invocations | Mode::FlowLogs => {
+                let log_view = self.tab().log_view.as_ref();
+                if self.list_area.contains(position) {
+                    Mode::List
+                } else if self.item_area.contains(position)
+                    && log_view.is_some_and(LogView::shows_invocations)
+                {
+                    Mode::Invocations
+                } else if self.item_area.contains(position)
+                    && log_view.is_some_and(|log_view| log_view.flow_logs.is_some())
+                {
+                    Mode::FlowLogs
+                } else if self.item_area.contains(position) && log_view.is_some() {
+                    Mode::Logs
+                } else if self.item_area.contains(position) && self.tab().log_stream_list.is_some()
+                {
+                    Mode::LogStreams
+                } else {
+                    return;
+                }
+            }
+            _ => mode,
+        };
+
+        match mouse.kind {
+            MouseEventKind::Drag(MouseButton::Left) if self.split.dragging => {
+                self.drag_split(position);
+            }
+            MouseEventKind::Up(MouseButton::Left) => self.split.dragging = false,
+            MouseEventKind::ScrollDown => self.move_selection(pane, Action::Next),
+            MouseEventKind::ScrollUp => self.move_selection(pane, Action::Previous),
+            MouseEventKind::Down(MouseButton::Left) => self.click(pane, position),
+            _ => {}
+        }
+    }
+
+    /// Focus the clicked pane, selecting the clicked row unless its title was clicked.
+    ///
+    /// Clicking the volume of log events selects the first event of the clicked bucket.
+    fn click(&mut self, pane: Mode, position: Position) {
+        let (area, focus) = match pane {
+            Mode::List => (self.list_area, Focus::List),
+            Mode::LogStreams => (self.item_area, Focus::LogStreams),
+            Mode::Logs => (self.item_area, Focus::Logs),
+            Mode::Invocations => (self.item_area, Focus::Invocations),
+            Mode::FlowLogs => (self.item_area, Focus::FlowLogs),
+            _ => return,
+        };
+        self.tab_mut().focus = focus;
+        // The title of the pane under the list is the border to drag to resize the list.
+        if area == self.item_area && position.y == area.y && !self.split.collapsed {
+            self.split.dragging = true;
+        }
+
+        let mut top = area.y;
+        if pane == Mode::Logs && !self.volume_area.is_empty() {
+            let volume_area = self.volume_area;
+            if volume_area.contains(position) {
+                let i = usize::from(position.x - volume_area.x);
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.select_volume_bucket(i);
+                }
+                return;
+            }
+            top = volume_area.bottom();
+        }
+
+        // The title takes the first row of every pane, and the filter chips the row under the
+        // title of the functions.
+        let header_rows = if pane == Mode::List && self.shows_filter_chips() {
+            2
+        } else {
+            1
+        };
+        let Some(row) = position.y.saturating_sub(top).checked_sub(header_rows) else {
+            return;
+        };
+        let row = usize::from(row);
+        if pane == Mode::FlowLogs {
+            if let Some(flow_logs) = self
+                .tab_mut()
+                .log_view
+                .as_mut()
+                .and_then(|log_view| log_view.flow_logs.as_mut())
+            {
+                // The header of the columns takes the row under the title.
+                let clicked = row.checked_sub(1).and_then(|row| {
+                    let len = flow_logs.shown.len();
+                    item_at_row(flow_logs.state.offset(), row, std::iter::repeat_n(1, len))
+                });
+                if clicked.is_some() {
+                    flow_logs.state.select(clicked);
+                }
+            }
+            return;
+        }
+        let pretty_json = self.pretty_json;
+        let (state, clicked) = match pane {
+            Mode::List => {
+                let len = match self.list_mode {
+                    ListMode::Functions => self.function_list.rows.len(),
+                    ListMode::LogGroups => self.log_group_list.rows.len(),
+                    ListMode::Services => self.service_list.services.as_ref().map_or(0, Vec::len),
+                    ListMode::StateMachines => self
+                        .state_machine_list
+                        .state_machines
+                        .as_ref()
+                        .map_or(0, Vec::len),
+                    ListMode::Stages => self.stage_list.stages.as_ref().map_or(0, Vec::len),
+                    ListMode::Databases => {
+                        self.database_list.databases.as_ref().map_or(0, Vec::len)
+                    }
+                    ListMode::Projects => self.project_list.projects.as_ref().map_or(0, Vec::len),
+                };
+                let state = self.list_state_mut();
+                let clicked = item_at_row(state.offset(), row, std::iter::repeat_n(1, len));
+                (state, clicked)
+            }
+            Mode::LogStreams => match &mut self.tab_mut().log_stream_list {
+                Some(log_stream_list) => {
+                    let len = log_stream_list.log_streams.len();
+                    let state = &mut log_stream_list.state;
+                    let clicked = item_at_row(state.offset(), row, std::iter::repeat_n(1, len));
+                    (state, clicked)
+                }
+                None => return,
+            },
+            Mode::Logs => match &mut self.tab_mut().log_view {
+                Some(log_view) => {
+                    let heights = log_view
+                        .shown
+                        .iter()
+                        .map(|&i| log_view.event_height(i, pretty_json));
+                    let clicked = item_at_row(log_view.state.offset(), row, heights);
+                    (&mut log_view.state, clicked)
+                }
+                None => return,
+            },
+            Mode::Invocations => match self
+                .tab_mut()
+                .log_view
+                .as_mut()
+                .and_then(|log_view| log_view.invocations.as_mut())
+            {
+                Some(invocations) => {
+                    let len = invocations.invocations.len();
+                    let state = &mut invocations.state;
+                    let clicked = item_at_row(state.offset(), row, std::iter::repeat_n(1, len));
+                    (state, clicked)
+                }
+                None => return,
+            },
+            _ => return,
+        };
+
+        if clicked.is_some() {
+            state.select(clicked);
+        }
+    }
+
+    /// Resize the list to end where the border between the panes was dragged to.
+    fn drag_split(&mut self, position: Position) {
+        let top = self.list_area.y;
+        let height = self.list_area.height + self.item_area.height;
+        if height == 0 {
+            return;
+        }
+
+        let list_height = position.y.saturating_sub(top).min(height);
+        self.split
+            .resize((u32::from(list_height) * 100 / u32::from(height)) as u16);
+    }
+
+    /// Capture the mouse, or leave it to the terminal to select text with.
+    fn capture_mouse(&mut self, capture: bool) {
+        let result = if capture {
+            execute!(io::stdout(), EnableMouseCapture)
+        } else {
+            execute!(io::stdout(), DisableMouseCapture)
+        };
+
+        match result {
+            Ok(()) => self.mouse = capture,
+            Err(error) => self.error = Some(format!("Failed to capture the mouse: {error}")),
+        }
+    }
+
+    /// What is receiving key presses, from the topmost popup down to the focused pane.
+    fn mode(&self) -> Mode {
+        if self.help.is_some() {
+            Mode::Help
+        } else if self.error_screen.is_some() {
+            Mode::ErrorScreen
+        } else if self.picker.is_some() {
+            Mode::Picker
+        } else if self.confirmation.is_some() {
+            Mode::Confirmation
+        } else if self.palette.is_some() {
+            Mode::Palette
+        } else if self.environment.is_some() {
+            Mode::Environment
+        } else if self.trace.is_some() {
+            Mode::Trace
+        } else if self.event_view.is_some() {
+            Mode::Event
+        } else if self.executions.is_some() {
+            Mode::Executions
+        } else if self.event_sources.is_some() {
+            Mode::EventSources
+        } else if self.failed_events.is_some() {
+            Mode::FailedEvents
+        } else if self.builds.is_some() {
+            Mode::Builds
+        } else if let Some(cloudtrail) = &self.cloudtrail {
+            if cloudtrail.editing.is_some() {
+                Mode::Input
+            } else {
+                Mode::CloudTrail
+            }
+        } else if self.time_range_form.is_some()
+            || self.query_builder.is_some()
+            || self.function_list.finding
+            || self.filter_input.is_some()
+            || self.search_input.is_some()
+            || self.group_search_input.is_some()
+            || self.regex_filter_input.is_some()
+            || self.flow_filter_input.is_some()
+            || self.export_input.is_some()
+            || self.s3_export_input.is_some()
+            || self.download_input.is_some()
+            || self.tag_filter_input.is_some()
+            || self.query_name_input.is_some()
+            || self.invoke_input.is_some()
+            || self
+                .tab()
+                .insights_view
+                .as_ref()
+                .is_some_and(|insights_view| insights_view.editing)
+        {
+            Mode::Input
+        } else {
+            match self.tab().focus {
+                Focus::List => Mode::List,
+                Focus::LogStreams => Mode::LogStreams,
+                Focus::Logs => Mode::Logs,
+                Focus::Invocations => Mode::Invocations,
+                Focus::FlowLogs => Mode::FlowLogs,
+                Focus::Insights => Mode::Insights,
+            }
+        }
+    }
+
+    /// Edit the text being input with a key press that isn't bound to an action.
+    fn edit_input(&mut self, key: KeyEvent) {
+        if let Some(palette) = &mut self.palette {
+            palette.handle_query_key(key);
+        } else if let Some((_, input)) = self
+            .cloudtrail
+            .as_mut()
+            .and_then(|cloudtrail| cloudtrail.editing.as_mut())
+        {
+            input.handle_key(key);
+        } else if let Some(time_range_form) = &mut self.time_range_form {
+            time_range_form.editing().handle_key(key);
+        } else if let Some(query_builder) = &mut self.query_builder {
+            query_builder.editing().handle_key(key);
+        } else if self.function_list.finding {
+            self.function_list.handle_query_key(key);
+        } else if let Some(filter_input) = &mut self.filter_input {
+            filter_input.handle_key(key);
+        } else if let Some(search_input) = &mut self.search_input {
+            search_input.handle_key(key);
+        } else if let Some(group_search_input) = &mut self.group_search_input {
+            group_search_input.handle_key(key);
+        } else if let Some(regex_filter_input) = &mut self.regex_filter_input {
+            regex_filter_input.handle_key(key);
+        } else if let Some(flow_filter_input) = &mut self.flow_filter_input {
+            flow_filter_input.handle_key(key);
+        } else if let Some(export_input) = &mut self.export_input {
+            export_input.handle_key(key);
+        } else if let Some(s3_export_input) = &mut self.s3_export_input {
+            s3_export_input.handle_key(key);
+        } else if let Some(download_input) = &mut self.download_input {
+            download_input.handle_key(key);
+        } else if let Some(tag_filter_input) = &mut self.tag_filter_input {
+            tag_filter_input.handle_key(key);
+        } else if let Some(query_name_input) = &mut self.query_name_input {
+            query_name_input.handle_key(key);
+        } else if let Some(invoke_input) = &mut self.invoke_input {
+            invoke_input.payload.handle_key(key);
+        } else if let Some(insights_view) = &mut self.tab_mut().insights_view {
+            insights_view.query.handle_key(key);
+        }
+    }
+
+    /// Do `action`, as it applies to what is receiving key presses.
+    fn perform(&mut self, action: Action) {
+        let mode = self.mode();
+        match action {
+            Action::Quit => self.should_exit = true,
+            Action::Back => self.back(mode),
+            Action::Confirm => self.confirm(mode),
+            Action::Help => self.help = Some(HelpView { mode, scroll: 0 }),
+            Action::CommandPalette => self.palette = Some(Palette::new(mode)),
+            Action::Next
+            | Action::Previous
+            | Action::First
+            | Action::Last
+            | Action::PageDown
+            | Action::PageUp => self.move_selection(mode, action),
+            Action::SelectNone => self.select_none(),
+            Action::OpenLogStreams => self.open_log_streams(),
+            Action::Mark => match mode {
+                Mode::LogStreams => self.mark_log_stream(),
+                _ if self.list_mode == ListMode::LogGroups => self.mark_log_group(),
+                _ => self.mark_function(),
+            },
+            Action::OpenLogs => match mode {
+                Mode::LogStreams => {
+                    if let Some((log_group_name, log_stream_names)) = self.marked_log_streams() {
+                        self.load_logs(log_group_name, log_stream_names);
+                    }
+                }
+                // The functions of a stack are collapsed to its heading, or expanded again.
+                Mode::List
+                    if self.list_mode == ListMode::Functions
+                        && self.function_list.selected_stack().is_some() =>
+                {
+                    self.function_list.toggle_stack();
+                }
+                Mode::List
+                    if self.list_mode == ListMode::LogGroups
+                        && self.log_group_list.prefix_selected() =>
+                {
+                    self.log_group_list.toggle_prefix();
+                }
+                _ => self.open_logs(),
+            },
+            Action::LiveTail => match mode {
+                Mode::LogStreams => {
+                    if let Some((log_group_name, log_stream_names)) = self.marked_log_streams() {
+                        self.start_live_tail(log_group_name, log_stream_names);
+                    }
+                }
+                Mode::Logs => self.toggle_live_tail(),
+                Mode::List
+                    if self.list_mode == ListMode::Functions
+                        && !self.function_list.marked.is_empty() =>
+                {
+                    self.tail_marked_functions();
+                }
+                _ => {
+                    if let Some(log_group_name) = self.select_log_group() {
+                        self.start_live_tail(log_group_name, Vec::new());
+                    }
+                }
+            },
+            Action::Filter => self.open_filter_input(),
+            Action::Find => {
+                if self.list_mode == ListMode::Functions {
+                    self.function_list.finding = true;
+                }
+            }
+            Action::GroupList => match self.list_mode {
+                ListMode::Functions => {
+                    self.function_list.toggle_grouped();
+                    self.load_function_tags();
+                }
+                ListMode::LogGroups => self.log_group_list.toggle_tree(),
+                _ => {}
+            },
+            Action::SortFunctions => match self.list_mode {
+                ListMode::Functions => self.function_list.cycle_sort(),
+                ListMode::LogGroups => {
+                    self.log_group_list.by_size = !self.log_group_list.by_size;
+                    self.log_group_list.sort();
+                }
+                _ => {}
+            },
+            Action::FilterRuntime => {
+                if self.list_mode == ListMode::Functions {
+                    self.function_list.cycle_runtime_filter();
+                }
+            }
+            Action::FilterArchitecture => {
+                if self.list_mode == ListMode::Functions {
+                    self.function_list.cycle_architecture_filter();
+                }
+            }
+            Action::FilterTags => {
+                if self.list_mode == ListMode::Functions {
+                    self.tag_filter_input = Some(TextInput::new(&self.function_list.tag_filter));
+                }
+            }
+            Action::Insights => self.open_insights(),
+            Action::EditQuery => {
+                if let Some(insights_view) = &mut self.tab_mut().insights_view {
+                    insights_view.editing = true;
+                }
+            }
+            Action::BuildQuery => {
+                if let Some(insights_view) = &mut self.tab_mut().insights_view {
+                    insights_view.editing = false;
+                    self.query_builder = Some(QueryBuilder::default());
+                }
+            }
+            Action::EditBuiltQuery => {
+                if self.query_builder.is_some() {
+                    self.edit_built_query();
+                }
+            }
+            Action::SaveQuery => {
+                if self.tab().insights_view.is_some() {
+                    self.query_name_input = Some(TextInput::new(""));
+                }
+            }
+            Action::PickQuery => self.open_query_picker(),
+            Action::Refresh => {
+                if self.list_mode == ListMode::Functions {
+                    self.refresh_functions();
+                }
+            }
+            Action::ToggleListMode => self.toggle_list_mode(),
+            Action::SwitchProfile => self.open_profile_picker(),
+            Action::SwitchRegion => self.open_region_picker(),
+            Action::PickTimeRange => self.open_time_range_picker(),
+            Action::PickRecent => self.open_recent_picker(),
+            Action::ToggleMouse => self.capture_mouse(!self.mouse),
+            Action::GrowList => self
+                .split
+                .resize(self.split.list_percent.saturating_add(LIST_PERCENT_STEP)),
+            Action::ShrinkList => self
+                .split
+                .resize(self.split.list_percent.saturating_sub(LIST_PERCENT_STEP)),
+            Action::CollapseList => self.split.collapsed = !self.split.collapsed,
+            Action::TogglePrettyJson => self.pretty_json = !self.pretty_json,
+            Action::ToggleTable => {
+                if self.columns.is_empty() {
+                    self.notice = Some(
+                        "No columns to show, add [[columns]] with the paths of JSON fields to the config"
+                            .to_string(),
+                    );
+                } else {
+                    self.show_table = !self.show_table;
+                }
+            }
+            Action::ToggleWrap => self.wrap_lines = !self.wrap_lines,
+            Action::AcknowledgeAlert => self.alert = None,
+            Action::ToggleLineNumbers => {
+                self.gutters.line_numbers = !self.gutters.line_numbers;
+            }
+            Action::ToggleTimestamps => self.gutters.timestamps = !self.gutters.timestamps,
+            Action::ToggleStreamNames => {
+                self.gutters.stream_names = !self.gutters.stream_names;
+            }
+            Action::ScrollLeft | Action::ScrollRight => {
+                let pretty_json = self.pretty_json;
+                if self.wrap_lines {
+                    self.notice =
+                        Some("Wrapped log messages don't scroll, w to cut them off".to_string());
+                } else if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.scroll_columns(action == Action::ScrollRight, pretty_json);
+                }
+            }
+            Action::ToggleVolume => self.show_volume = !self.show_volume,
+            Action::ToggleFollow => {
+                if let Some(log_view) = &mut self.tab_mut().log_view
+                    && log_view.live_tail.is_some()
+                {
+                    log_view.toggle_follow();
+                }
+            }
+            Action::JumpToPeak => {
+                if let Some(log_view) = &mut self.tab_mut().log_view
+                    && let Some(peak) = log_view.volume.as_ref().and_then(Volume::peak)
+                {
+                    log_view.select_volume_bucket(peak);
+                }
+            }
+            Action::Search => self.open_search_input(),
+            Action::SearchNext => self.search(true),
+            Action::SearchPrevious => self.search(false),
+            Action::FilterRegex => self.open_regex_filter_input(),
+            Action::Export => self.open_export_input(),
+            Action::ExportToS3 => self.open_s3_export_input(),
+            Action::Download => self.open_download_input(),
+            Action::SearchLogGroups => self.open_group_search_input(),
+            Action::OpenConsole => self.open_console(mode),
+            Action::BrowseVersions => self.load_function_versions(),
+            Action::Invoke => self.open_invoke_input(false),
+            Action::DryRunInvoke => self.open_invoke_input(true),
+            Action::SetRetention => self.open_retention_picker(),
+            Action::ShowEnvironment => {
+                if let Some(function) = self.selected_function().cloned() {
+                    self.environment = Some(EnvironmentView {
+                        function,
+                        revealed: false,
+                        scroll: 0,
+                    });
+                }
+            }
+            Action::RevealValues => {
+                if let Some(environment) = &mut self.environment {
+                    environment.revealed = !environment.revealed;
+                }
+            }
+            Action::CopyMessage => self.copy_log_event(false),
+            Action::CopyLogEvent => self.copy_log_event(true),
+            Action::CopyCurl => self.copy_curl_command(),
+            Action::GroupInvocations => {
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.group_invocations();
+                    log_view.close_invocation();
+                    self.tab_mut().focus = Focus::Invocations;
+                }
+            }
+            Action::ShowFlowLogs => {
+                let tab = self.tab_mut();
+                if let Some(log_view) = &mut tab.log_view {
+                    if log_view.flow_logs.take().is_some() {
+                        tab.focus = Focus::Logs;
+                    } else {
+                        log_view.tabulate_flow_logs();
+                        tab.focus = Focus::FlowLogs;
+                    }
+                }
+            }
+            Action::SortByColumn | Action::ReverseSort if mode == Mode::Insights => {
+                if let Some(insights_view) = &mut self.tab_mut().insights_view {
+                    if action == Action::SortByColumn {
+                        insights_view.cycle_sort();
+                    } else {
+                        insights_view.reverse_sort();
+                    }
+                }
+            }
+            Action::SortByColumn | Action::ReverseSort => {
+                if let Some(flow_logs) = self
+                    .tab_mut()
+                    .log_view
+                    .as_mut()
+                    .and_then(|log_view| log_view.flow_logs.as_mut())
+                {
+                    if action == Action::SortByColumn {
+                        flow_logs.cycle_sort();
+                    } else {
+                        flow_logs.reverse_sort();
+                    }
+                }
+            }
+            Action::FilterFlowLogs => self.open_flow_filter_input(),
+            Action::OpenInvocation => {
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.open_invocation();
+                    self.tab_mut().focus = Focus::Logs;
+                }
+            }
+            Action::ShowTrace => self.show_trace(mode),
+            Action::ShowEvent if mode == Mode::Insights => self.show_log_record(),
+            Action::ShowEvent if mode == Mode::FailedEvents => self.show_failed_event(),
+            Action::ShowEvent => {
+                if let Some(event) = self
+                    .tab()
+                    .log_view
+                    .as_ref()
+                    .and_then(LogView::selected_log_event)
+                {
+                    self.event_view = Some(EventView::new(event.clone()));
+                }
+            }
+            Action::ToggleJson | Action::ExpandJson | Action::CollapseJson => {
+                if let Some(tree) = self
+                    .event_view
+                    .as_mut()
+                    .and_then(|event_view| event_view.tree.as_mut())
+                {
+                    match action {
+                        Action::ToggleJson => tree.toggle(),
+                        Action::ExpandJson => tree.expand(),
+                        _ => tree.collapse(),
+                    }
+                }
+            }
+            Action::ShowCloudTrail => self.show_cloudtrail(),
+            Action::FilterTrailResource => self.edit_trail_filter(TrailFilter::ResourceName),
+            Action::FilterTrailEvents => self.edit_trail_filter(TrailFilter::EventName),
+            Action::BrowseExecutions => self.browse_executions(),
+            Action::BrowseEventSources => self.browse_event_sources(),
+            Action::BrowseFailedEvents => self.browse_failed_events(),
+            Action::OpenFailedEventLogs => self.open_failed_event_logs(),
+            Action::BrowseBuilds => self.browse_builds(),
+            Action::ToggleFavorite => self.toggle_favorite(),
+            Action::OpenBuild => self.open_build(),
+            Action::SwitchLogs => match self.list_mode {
+                ListMode::Stages => {
+                    self.stage_list.execution_logs = !self.stage_list.execution_logs
+                }
+                ListMode::Databases => self.database_list.switch_log(),
+                _ => {}
+            },
+            Action::OpenExecution => self.open_execution(false),
+            Action::OpenExecutionLogs => {
+                let history_loaded = self
+                    .executions
+                    .as_ref()
+                    .and_then(|execution_view| execution_view.history.as_ref())
+                    .is_some_and(|execution_history| execution_history.events.is_some());
+                if history_loaded {
+                    self.open_execution_logs();
+                } else {
+                    self.open_execution(true);
+                }
+            }
+            Action::ToggleColdStarts => {
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.cold_starts_only = !log_view.cold_starts_only;
+                    log_view.update_shown();
+                }
+            }
+            Action::ToggleLevel(level) => {
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.toggle_level(level);
+                }
+            }
+            Action::ToggleTimeZone => self.timestamp_format.toggle_time_zone(),
+            Action::ToggleRelativeTime => self.timestamp_format.toggle_style(),
+            Action::NewTab => self.open_tab(),
+            Action::CloseTab => self.close_tab(),
+            Action::NextTab => self.tab = (self.tab + 1) % self.tabs.len(),
+            Action::PreviousTab => self.tab = (self.tab + self.tabs.len() - 1) % self.tabs.len(),
+            Action::Retry => self.retry(),
+            Action::SsoLogin => self.sso_login(),
+        }
+    }
+
+    /// Close whatever is receiving key presses, quitting once there is nothing left to close.
+    fn back(&mut self, mode: Mode) {
+        match mode {
+            Mode::Help => self.help = None,
+            Mode::ErrorScreen => self.error_screen = None,
+            Mode::Picker => self.picker = None,
+            Mode::Confirmation => self.confirmation = None,
+            Mode::Palette => self.palette = None,
+            Mode::Environment => self.environment = None,
+            Mode::Trace => self.trace = None,
+            Mode::Event => self.event_view = None,
+            Mode::Executions => {
+                if let Some(execution_view) = &mut self.executions
+                    && execution_view.history.is_some()
+                {
+                    execution_view.history = None;
+                } else {
+                    self.executions = None;
+                }
+            }
+            Mode::EventSources => self.event_sources = None,
+            Mode::FailedEvents => self.failed_events = None,
+            Mode::Builds => self.builds = None,
+            Mode::CloudTrail => self.cloudtrail = None,
+            Mode::Input => {
+                if let Some(cloudtrail) = &mut self.cloudtrail
+                    && cloudtrail.editing.is_some()
+                {
+                    cloudtrail.editing = None;
+                } else if self.time_range_form.is_some() {
+                    self.time_range_form = None;
+                } else if self.query_builder.is_some() {
+                    self.query_builder = None;
+                } else if self.function_list.finding {
+                    self.function_list.clear_query();
+                } else if self.filter_input.is_some() {
+                    self.filter_input = None;
+                } else if self.search_input.is_some() {
+                    self.search_input = None;
+                } else if self.group_search_input.is_some() {
+                    self.group_search_input = None;
+                } else if self.regex_filter_input.is_some() {
+                    self.regex_filter_input = None;
+                } else if self.flow_filter_input.is_some() {
+                    self.flow_filter_input = None;
+                } else if self.export_input.is_some() {
+                    self.export_input = None;
+                } else if self.s3_export_input.is_some() {
+                    self.s3_export_input = None;
+                } else if self.download_input.is_some() {
+                    self.download_input = None;
+                } else if self.tag_filter_input.is_some() {
+                    self.tag_filter_input = None;
+                } else if self.query_name_input.is_some() {
+                    self.query_name_input = None;
+                } else if self.invoke_input.is_some() {
+                    self.invoke_input = None;
+                } else if let Some(insights_view) = &mut self.tab_mut().insights_view {
+                    if insights_view.results.is_some() {
+                        insights_view.editing = false;
+                    } else {
+                        self.close_insights();
+                    }
+                }
+            }
+            Mode::List => {
+                if self.list_mode == ListMode::Functions
+                    && !self.function_list.query.value.is_empty()
+                {
+                    self.function_list.clear_query();
+                } else {
+                    self.should_exit = true;
+                }
+            }
+            Mode::LogStreams => self.close_log_streams(),
+            Mode::Logs => match &mut self.tab_mut().log_view {
+                Some(log_view) if log_view.invocation.is_some() => {
+                    log_view.close_invocation();
+                    self.tab_mut().focus = Focus::Invocations;
+                }
+                _ => self.close_logs(),
+            },
+            Mode::Invocations => {
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.invocations = None;
+                }
+                self.tab_mut().focus = Focus::Logs;
+            }
+            Mode::FlowLogs => {
+                if let Some(log_view) = &mut self.tab_mut().log_view {
+                    log_view.flow_logs = None;
+                }
+                self.tab_mut().focus = Focus::Logs;
+            }
+            Mode::Insights => self.close_insights(),
+        }
+    }
+
+    /// Accept the text being input, or the picked item.
+    fn confirm(&mut self, mode: Mode) {
+        match mode {
+            Mode::Picker => self.pick(),
+            Mode::Confirmation => self.make_change(),
+            Mode::Palette => {
+                // Run the action as if its key was pressed where the palette was opened.
+                if let Some(action) = self.palette.take().and_then(|palette| palette.selected()) {
+                    self.perform(action);
+                }
+            }
+            Mode::Input => {
+                if self
+                    .cloudtrail
+                    .as_ref()
+                    .is_some_and(|cloudtrail| cloudtrail.editing.is_some())
+                {
+                    self.apply_trail_filter();
+                } else if self.time_range_form.is_some() {
+                    self.apply_time_range_form();
+                } else if self.query_builder.is_some() {
+                    self.edit_built_query();
+                    self.run_insights_query();
+                } else if self.function_list.finding {
+                    self.function_list.finding = false;
+                } else if self.filter_input.is_some() {
+                    self.apply_filter();
+                } else if self.search_input.is_some() {
+                    self.apply_search();
+                } else if let Some(group_search_input) = self.group_search_input.take() {
+                    let pattern = group_search_input.value.trim();
+                    let log_group_names = std::mem::take(&mut self.group_search_log_group_names);
+                    self.search_log_groups(
+                        log_group_names,
+                        (!pattern.is_empty()).then(|| pattern.to_string()),
+                    );
+                } else if self.regex_filter_input.is_some() {
+                    self.apply_regex_filter();
+                } else if self.flow_filter_input.is_some() {
+                    self.apply_flow_filter();
+                } else if self.export_input.is_some() {
+                    self.export_log_events();
+                } else if self.s3_export_input.is_some() {
+                    self.export_to_s3();
+                } else if self.download_input.is_some() {
+                    self.download_log_events();
+                } else if self.tag_filter_input.is_some() {
+                    self.apply_tag_filter();
+                } else if self.query_name_input.is_some() {
+                    self.save_query();
+                } else if self.invoke_input.is_some() {
+                    self.invoke_function();
+                } else if self.tab().insights_view.is_some() {
+                    self.run_insights_query();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Move the selection of whatever is receiving key presses.
+    ///
+    /// Moving up from the oldest loaded log event loads older log events.
+    fn move_selection(&mut self, mode: Mode, action: Action) {
+        fn move_list(state: &mut ListState, action: Action, page: u16) {
+            match action {
+                Action::Next => state.select_next(),
+                Action::Previous => state.select_previous(),
+                Action::First => state.select_first(),
+                Action::Last => state.select_last(),
+                Action::PageDown => state.scroll_down_by(page),
+                Action::PageUp => state.scroll_up_by(page),
+                _ => {}
+            }
+        }
+
+        // Popups without a selection are scrolled instead, clamped once rendered.
+        fn scroll(scroll: usize, action: Action, page: u16) -> usize {
+            match action {
+                Action::Next => scroll.saturating_add(1),
+                Action::Previous => scroll.saturating_sub(1),
+                Action::First => 0,
+                Action::Last => usize::MAX,
+                Action::PageDown => scroll.saturating_add(usize::from(page)),
+                Action::PageUp => scroll.saturating_sub(usize::from(page)),
+                _ => scroll,
+            }
+        }
+
+        // Half of the pane is paged, less its title.
+        let area = match mode {
+            Mode::List => self.list_area,
+            _ => self.item_area,
+        };
+        let page = (area.height.saturating_sub(1) / 2).max(1);
+
+        match mode {
+            Mode::List => move_list(self.list_state_mut(), action, page),
+            // Only the listed functions can be moved through while finding them.
+            Mode::Input if self.function_list.finding => {
+                move_list(&mut self.function_list.state, action, page);
+            }
+            Mode::Input if self.query_builder.is_some() => {
+                if let Some(query_builder) = &mut self.query_builder {
+                    query_builder.edit_next(action == Action::Next);
+                }
+            }
+            Mode::Input if self.time_range_form.is_some() => {
+                if let Some(time_range_form) = &mut self.time_range_form {
+                    time_range_form.editing_end = action == Action::Next;
+                }
+            }
+            // Up and Down recall the filter patterns, or queries, input before.
+            Mode::Input => {
+                let older = action == Action::Previous;
+                if let Some(filter_input) = &mut self.filter_input {
+                    filter_input.recall(&self.history.filter_patterns, older);
+                } else if let Some(group_search_input) = &mut self.group_search_input {
+                    group_search_input.recall(&self.history.filter_patterns, older);
+                } else if let Some(insights_view) = &mut self.tabs[self.tab].insights_view
+                    && insights_view.editing
+                {
+                    insights_view.query.recall(&self.history.queries, older);
+                }
+            }
+            Mode::LogStreams => {
+                if let Some(log_stream_list) = &mut self.tab_mut().log_stream_list {
+                    move_list(&mut log_stream_list.state, action, page);
+                }
+            }
+            Mode::Logs => {
+                let Some(log_view) = &mut self.tab_mut().log_view else {
+                    return;
+                };
+                let at_oldest = log_view.state.selected() == Some(0);
+                move_list(&mut log_view.state, action, page);
+                if at_oldest && matches!(action, Action::Previous | Action::PageUp | Action::First)
+                {
+                    self.load_older_logs();
+                }
+            }
+            Mode::Invocations => {
+                if let Some(invocations) = self
+                    .tab_mut()
+                    .log_view
+                    .as_mut()
+                    .and_then(|log_view| log_view.invocations.as_mut())
+                {
+                    move_list(&mut invocations.state, action, page);
+                }
+            }
+            Mode::FlowLogs => {
+                if let Some(flow_logs) = self
+                    .tab_mut()
+                    .log_view
+                    .as_mut()
+                    .and_then(|log_view| log_view.flow_logs.as_mut())
+                {
+                    let state = &mut flow_logs.state;
+                    match action {
+                        Action::Next => state.select_next(),
+                        Action::Previous => state.select_previous(),
+                        Action::First => state.select_first(),
+                        Action::Last => state.select_last(),
+                        Action::PageDown => state.scroll_down_by(page),
+                        Action::PageUp => state.scroll_up_by(page),
+                        _ => {}
+                    }
+                }
+            }
+            Mode::Insights => {
+                if let Some(insights_view) = &mut self.tab_mut().insights_view {
+                    let state = &mut insights_view.state;
+                    match action {
+                        Action::Next => state.select_next(),
+                        Action::Previous => state.select_previous(),
+                        Action::First => state.select_first(),
+                        Action::Last => state.select_last(),
+                        Action::PageDown => state.scroll_down_by(page),
+                        Action::PageUp => state.scroll_up_by(page),
+                        _ => {}
+                    }
+                }
+            }
+            Mode::Picker => {
+                if let Some(picker) = &mut self.picker {
+                    move_list(&mut picker.state, action, page);
+                }
+            }
+            Mode::Palette => {
+                if let Some(palette) = &mut self.palette {
+                    move_list(&mut palette.state, action, page);
+                }
+            }
+            Mode::ErrorScreen | Mode::Confirmation => {}
+            Mode::Environment => {
+                if let Some(environment) = &mut self.environment {
+                    environment.scroll = scroll(environment.scroll, action, page);
+                }
+            }
+            Mode::Trace => {
+                if let Some(trace) = &mut self.trace {
+                    trace.scroll = scroll(trace.scroll, action, page);
+                }
+            }
+            // The JSON of a message is moved through, rather than scrolled.
+            Mode::Event => {
+                if let Some(event_view) = &mut self.event_view {
+                    match &mut event_view.tree {
+                        Some(tree) => tree.select(scroll(tree.selected, action, page)),
+                        None => event_view.scroll = scroll(event_view.scroll, action, page),
+                    }
+                }
+            }
+            Mode::Executions => {
+                if let Some(execution_view) = &mut self.executions {
+                    match &mut execution_view.history {
+                        Some(execution_history) => {
+                            execution_history.scroll =
+                                scroll(execution_history.scroll, action, page);
+                        }
+                        None => move_list(&mut execution_view.state, action, page),
+                    }
+                }
+            }
+            Mode::EventSources => {
+                if let Some(event_source_view) = &mut self.event_sources {
+                    move_list(&mut event_source_view.state, action, page);
+                }
+            }
+            Mode::FailedEvents => {
+                if let Some(failed_event_view) = &mut self.failed_events {
+                    move_list(&mut failed_event_view.state, action, page);
+                }
+            }
+            Mode::Builds => {
+                if let Some(build_view) = &mut self.builds {
+                    move_list(&mut build_view.state, action, page);
+                }
+            }
+            Mode::CloudTrail => {
+                if let Some(cloudtrail) = &mut self.cloudtrail {
+                    move_list(&mut cloudtrail.state, action, page);
+                }
+            }
+            Mode::Help => {
+                if let Some(help) = &mut self.help {
+                    help.scroll = scroll(help.scroll, action, page);
+                }
+            }
+        }
+    }
+
+    /// Show the error screen for `error`, offering to `retry`.
+    ///
+    /// Access being denied is only told of in a toast, as retrying won't help until the IAM
+    /// policy is changed, and the rest of the app can still be used meanwhile.
+    fn show_error(&mut self, error: AwsError, retry: Retry) {
+        if error.is_access_denied() {
+            self.toasts.push(Severity::Error, error.to_string());
+            return;
+        }
+
+        let sso_login = error
+            .is_sso_session_expired()
+            .then(|| SsoLogin::new(self.profile.clone()));
+        self.error_screen = Some(ErrorScreen {
+            error,
+            retry,
+            sso_login,
+        });
+    }
+
+    /// Sign in again with `aws sso login`, retrying once signed in, if the SSO session expired.
+    fn sso_login(&mut self) {
+        if let Some(sso_login) = self
+            .error_screen
+            .as_mut()
+            .and_then(|error_screen| error_screen.sso_login.as_mut())
+            && !sso_login.running()
+        {
+            sso_login.start(&self.events);
+        }
+    }
+
+    /// Show the error screen for `error` of the tab `tab`, switching to it so it is retried there.
+    fn show_tab_error(&mut self, tab: usize, error: AwsError, retry: Retry) {
+        if !error.is_access_denied() {
+            self.tab = tab;
+        }
+        self.show_error(error, retry);
+    }
+
+    /// Dismiss the error screen and try again what failed, if it is still being viewed.
+    fn retry(&mut self) {
+        let Some(error_screen) = self.error_screen.take() else {
+            return;
+        };
+
+        match error_screen.retry {
+            Retry::LoadFunctions => self.load_functions(),
+            Retry::RefreshFunctions => self.refresh_functions(),
+            Retry::LoadLogGroups => self.load_log_groups(),
+            Retry::LoadServices => self.load_services(),
+            Retry::LoadStateMachines => self.load_state_machines(),
+            Retry::LoadStages => self.load_stages(),
+            Retry::LoadDatabases => self.load_databases(),
+            Retry::LoadProjects => self.load_projects(),
+            Retry::LoadLogStreams => {
+                if let Some(log_stream_list) = &self.tab().log_stream_list {
+                    self.load_log_streams(
+                        log_stream_list.log_group_name.clone(),
+                        log_stream_list.scope.clone(),
+                    );
+                }
+            }
+            Retry::LoadFunctionVersions => self.load_function_versions(),
+            Retry::LoadLogEvents => {
+                if let Some(log_view) = &self.tab().log_view {
+                    match (&log_view.filter, &log_view.invocation) {
+                        (Some(filter), Some(request_id)) => self.load_invocation_logs(
+                            log_view.log_group_name.clone(),
+                            request_id.clone(),
+                            filter.clone(),
+                        ),
+                        (Some(filter), None) => self.load_filtered_logs(
+                            log_view.log_group_name.clone(),
+                            log_view.log_stream_names.clone(),
+                            filter.clone(),
+                        ),
+                        _ => self.load_logs(
+                            log_view.log_group_name.clone(),
+                            log_view.log_stream_names.clone(),
+                        ),
+                    }
+                }
+            }
+            Retry::SearchLogGroups => {
+                if let Some(log_view) = &self.tab().log_view {
+                    let log_group_names = [log_view.log_group_name.clone()]
+                        .into_iter()
+                        .chain(log_view.merged_log_group_names.iter().cloned())
+                        .collect();
+                    self.search_log_groups(log_group_names, log_view.search_pattern.clone());
+                }
+            }
+            Retry::LoadOlderLogEvents => self.load_older_logs(),
+            Retry::LiveTail => {
+                if self
+                    .tab()
+                    .log_view
+                    .as_ref()
+                    .is_some_and(|log_view| log_view.live_tail.is_none())
+                {
+                    self.toggle_live_tail();
+                }
+            }
+            Retry::RunQuery => {
+                if self.tab().insights_view.is_some() {
+                    self.run_insights_query();
+                }
+            }
+        }
+    }
+
+    /// List the AWS profiles to switch to, with the current profile selected.
+    fn open_profile_picker(&mut self) {
+        let current = self
+            .profile
+            .clone()
+            .unwrap_or_else(|| "default".to_string());
+        self.open_picker(PickerKind::Profile, aws::config::list_profiles(), &current);
+    }
+
+    /// List the AWS regions to switch to, with the current region selected.
+    fn open_region_picker(&mut self) {
+        let regions = aws::config::REGIONS
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let current = self.active_region.clone().unwrap_or_default();
+        self.open_picker(PickerKind::Region, regions, &current);
+    }
+
+    /// List the time ranges to load log events from, with the tab's time range selected.
+    fn open_time_range_picker(&mut self) {
+        let time_ranges = time_range::PRESETS
+            .iter()
+            .map(|&(_, duration)| TimeRange::Last(duration).label())
+            .chain([CUSTOM_TIME_RANGE.to_string()])
+            .collect();
+        let current = self.tab().time_range.label();
+        self.open_picker(PickerKind::TimeRange, time_ranges, &current);
+    }
+
+    /// List the recently viewed log groups to switch to, with the one viewed before the last
+    /// selected, to switch back and forth between two.
+    fn open_recent_picker(&mut self) {
+        let current = self.recent_log_groups.get(1).cloned().unwrap_or_default();
+        self.open_picker(PickerKind::Recent, self.recent_log_groups.clone(), &current);
+    }
+
+    /// List how long the selected log group can keep its log events for, with how long it does
+    /// selected.
+    fn open_retention_picker(&mut self) {
+        if self.list_mode != ListMode::LogGroups {
+            self.notice = Some("Switch to the log groups with v to set retention".to_string());
+            return;
+        }
+        let Some(log_group) = self.selected_log_group() else {
+            return;
+        };
+
+        let current = aws::cloudwatch_logs::retention_label(log_group.retention_in_days);
+        let retentions = aws::cloudwatch_logs::RETENTION_DAYS
+            .iter()
+            .map(|&days| Some(days))
+            .chain([None])
+            .map(aws::cloudwatch_logs::retention_label)
+            .collect();
+        self.open_picker(PickerKind::Retention, retentions, &current);
+    }
+
+    /// Ask to confirm setting the retention of the selected log group to the picked `label`.
+    fn confirm_retention(&mut self, label: &str) {
+        let Some(log_group) = self.selected_log_group() else {
+            return;
+        };
+        let Some(retention_in_days) = aws::cloudwatch_logs::RETENTION_DAYS
+            .iter()
+            .map(|&days| Some(days))
+            .chain([None])
+            .find(|&days| aws::cloudwatch_logs::retention_label(days) == label)
+        else {
+            return;
+        };
+        if retention_in_days == log_group.retention_in_days {
+            return;
+        }
+
+        // Expiring log events sooner deletes those older than the new retention.
+        let warning = match (log_group.retention_in_days, retention_in_days) {
+            (_, None) => "",
+            (Some(before), Some(after)) if after > before => "",
+            _ => ", deleting the log events older than that",
+        };
+        self.confirmation = Some(Confirmation {
+            question: format!(
+                "Change the retention of {} from {} to {}{warning}?",
+                log_group.name,
+                aws::cloudwatch_logs::retention_label(log_group.retention_in_days).to_lowercase(),
+                label.to_lowercase(),
+            ),
+            change: Change::Retention {
+                log_group_name: log_group.name.clone(),
+                retention_in_days,
+            },
+        });
+    }
+
+    /// Make the confirmed change in the background, its outcome shown as a toast.
+    fn make_change(&mut self) {
+        let Some(confirmation) = self.confirmation.take() else {
+            return;
+        };
+
+        match confirmation.change {
+            Change::Retention {
+                log_group_name,
+                retention_in_days,
+            } => {
+                let logs_client = self.logs_client.clone();
+                self.events.spawn(async move {
+                    let result = logs_client
+                        .set_retention(&log_group_name, retention_in_days)
+                        .await;
+                    AppEvent::RetentionSet {
+                        log_group_name,
+                        retention_in_days,
+                        result,
+                    }
+                });
+            }
+        }
+    }
+
+    /// Remember `log_group_name` as the most recently viewed log group.
+    fn remember_recent(&mut self, log_group_name: &str) {
+        self.recent_log_groups
+            .retain(|recent| recent != log_group_name);
+        self.recent_log_groups.insert(0, log_group_name.to_string());
+        self.recent_log_groups.truncate(MAX_RECENT_LOG_GROUPS);
+    }
+
+    fn open_picker(&mut self, kind: PickerKind, items: Vec<String>, current: &str) {
+        let selected = items.iter().position(|item| item == current);
+
+        self.picker = Some(Picker {
+            kind,
+            items,
+            state: ListState::default().with_selected(selected.or(Some(0))),
+        });
+    }
+
+    /// Close the picker, acting on the picked item.
+    fn pick(&mut self) {
+        let Some(picker) = self.picker.take() else {
+            return;
+        };
+        let Some(item) = picker
+            .state
+            .selected()
+            .and_then(|i| picker.items.get(i))
+            .cloned()
+        else {
+            return;
+        };
+
+        match picker.kind {
+            PickerKind::Profile => {
+                self.save_session();
+                self.profile = Some(item);
+            }
+            PickerKind::Region => {
+                self.save_session();
+                self.region = Some(item);
+            }
+            PickerKind::Version => {
+                let Some(function) = self.versions_function.take() else {
+                    return;
+                };
+                let version = item.split_whitespace().next().map(String::from);
+                self.use_logs_region(function.region.as_deref());
+                self.tab_mut().log_view = None;
+                self.load_log_streams(
+                    aws::cloudwatch_logs::lambda_log_group_name(&function.name),
+                    version.map(LogStreamScope::Version),
+                );
+                self.tab_mut().focus = Focus::LogStreams;
+                return;
+            }
+            PickerKind::Recent => {
+                self.load_logs(item, Vec::new());
+                return;
+            }
+            PickerKind::Query => {
+                let query = self
+                    .query_choices()
+                    .into_iter()
+                    .find(|(label, _)| label == &item)
+                    .map(|(_, query)| query);
+                if let Some(query) = query
+                    && let Some(insights_view) = &mut self.tab_mut().insights_view
+                {
+                    insights_view.query = TextInput::new(&query);
+                    self.run_insights_query();
+                }
+                return;
+            }
+            PickerKind::Retention => {
+                self.confirm_retention(&item);
+                return;
+            }
+            PickerKind::TimeRange => {
+                if item == CUSTOM_TIME_RANGE {
+                    self.open_time_range_form();
+                } else if let Some(&(_, duration)) = time_range::PRESETS
+                    .iter()
+                    .find(|&&(_, duration)| TimeRange::Last(duration).label() == item)
+                {
+                    self.set_time_range(TimeRange::Last(duration));
+                }
+                return;
+            }
+        }
+        self.reload_sdk_config();
+    }
+
+    /// Start editing the start and end of a time range, from the tab's time range.
+    fn open_time_range_form(&mut self) {
+        let time_zone = self.timestamp_format.time_zone;
+        let now = SystemTime::now();
+        let (start, end) = self.tab().time_range.bounds(now);
+        let end = end.unwrap_or_else(|| epoch_millis(now));
+
+        self.time_range_form = Some(TimeRangeForm {
+            start: TextInput::new(&time_range::format_time(start, time_zone)),
+            end: TextInput::new(&time_range::format_time(end, time_zone)),
+            editing_end: false,
+        });
+    }
+
+    /// Use the input time range for the tab, unless it isn't a valid time range.
+    fn apply_time_range_form(&mut self) {
+        let Some(time_range_form) = &self.time_range_form else {
+            return;
+        };
+
+        let time_zone = self.timestamp_format.time_zone;
+        let start = time_range::parse_time(&time_range_form.start.value, time_zone);
+        let end = time_range::parse_time(&time_range_form.end.value, time_zone);
+        match (start, end) {
+            (Some(start), Some(end)) if start < end => {
+                self.time_range_form = None;
+                self.set_time_range(TimeRange::Between { start, end });
+            }
+            (Some(_), Some(_)) => self.error = Some("The start must be before the end".to_string()),
+            _ => {
+                self.error =
+                    Some("Times must be like 2024-06-01 13:00, in the shown time zone".to_string());
+            }
+        }
+    }
+
+    /// Use `time_range` for the tab, loading its log events, or running its query, again.
+    fn set_time_range(&mut self, time_range: TimeRange) {
+        self.tab_mut().time_range = time_range;
+
+        let tab = self.tab();
+        if tab
+            .insights_view
+            .as_ref()
+            .is_some_and(|insights_view| insights_view.results.is_some())
+        {
+            self.run_insights_query();
+        } else if let Some(log_view) = &tab.log_view {
+            self.reload_logs(log_view.log_group_name.clone());
+        }
+    }
+
+    /// Load the AWS config of the current profile and region, reloading everything from AWS with it.
+    fn reload_sdk_config(&mut self) {
+        self.reset_views();
+        self.load_sdk_config(false);
+    }
+
+    /// Load the credentials of the AWS profile again, once they expired, keeping everything
+    /// loaded with them.
+    fn refresh_credentials(&mut self) {
+        if self.refreshing_credentials {
+            return;
+        }
+
+        self.refreshing_credentials = true;
+        self.notice = Some("Credentials expired, loading them again...".to_string());
+        self.load_sdk_config(true);
+    }
+
+    /// Load the AWS config of the profile and region in the background, assuming the role if
+    /// any, only replacing the clients when it is for the `credentials_only`.
+    fn load_sdk_config(&mut self, credentials_only: bool) {
+        let profile = self.profile.clone();
+        let region = self.region.clone();
+        let role_arn = self.role_arn.clone();
+        // Other regions are derived from the first, when listing functions from several.
+        let first_region = region
+            .as_deref()
+            .and_then(|region| aws::config::parse_regions(region).into_iter().next());
+        self.events.spawn(async move {
+            let mut sdk_config = aws::config::load_config(profile.clone(), first_region).await;
+            // The role is assumed with the credentials of whichever profile was switched to.
+            if let Some(role_arn) = role_arn {
+                sdk_config = aws::config::assume_role(&sdk_config, &role_arn).await;
+            }
+            AppEvent::SdkConfigLoaded {
+                profile,
+                region,
+                sdk_config: Box::new(sdk_config),
+                credentials_only,
+            }
+        });
+    }
+
+    /// Ring the terminal bell, and count the `events` matching an alert pattern in the banner,
+    /// if any do.
+    ///
+    /// Events matching a pattern to notify of are notified of on the desktop too, while the
+    /// terminal isn't focused.
+    fn alert(&mut self, events: &[aws::cloudwatch_logs::LogEvent]) {
+        let mut matched = 0;
+        let mut last = None;
+        let mut notify = false;
+        for event in events {
+            let mut patterns = self
+                .alert_patterns
+                .iter()
+                .filter(|pattern| pattern.regex.is_match(&event.message))
+                .peekable();
+            if patterns.peek().is_none() {
+                continue;
+            }
+            notify |= patterns.any(|pattern| pattern.notify);
+            matched += 1;
+            last = Some(event);
+        }
+        let Some(last) = last else {
+            return;
+        };
+
+        let message = last.message.lines().next().unwrap_or_default().to_string();
+        if notify && !self.focused {
+            self.notify_desktop(format!(
+                "{matched} tailed log events matched, last: {message}"
+            ));
+        }
+        let alert = self.alert.get_or_insert_default();
+        alert.count += matched;
+        alert.message = message;
+        // The bell is heard, or flagged by tmux, even when the terminal isn't in view.
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(b"\x07").and_then(|()| stdout.flush());
+    }
+
+    /// Show a desktop notification of an alert, in the background as showing it may wait on the
+    /// desktop.
+    fn notify_desktop(&self, body: String) {
+        let sender = self.events.sender();
+        tokio::task::spawn_blocking(move || {
+            let shown = notify_rust::Notification::new()
+                .summary("AWS Logs TUI alert")
+                .body(&body)
+                .show();
+            if let Err(error) = shown {
+                let _ = sender.send(AppEvent::Error(format!(
+                    "Failed to show a desktop notification: {error}"
+                )));
+            }
+        });
+    }
+
+    /// Whether the account being viewed matches the production pattern, by its ID, the profile,
+    /// or the ARN of the user or role.
+    fn is_production(&self) -> bool {
+        let Some(pattern) = &self.production_pattern else {
+            return false;
+        };
+
+        self.profile
+            .as_deref()
+            .is_some_and(|profile| pattern.is_match(profile))
+            || self.caller_identity.as_ref().is_some_and(|identity| {
+                pattern.is_match(&identity.account) || pattern.is_match(&identity.arn)
+            })
+    }
+
+    /// Load who the credentials of `sdk_config` are of in the background, to tell which account
+    /// is being viewed.
+    fn load_caller_identity(&mut self, sdk_config: &SdkConfig) {
+        let sts_client = aws::sts::Client::new(sdk_config);
+        let profile = self.profile.clone();
+        let region = self.region.clone();
+        self.events.spawn(async move {
+            AppEvent::CallerIdentityLoaded {
+                profile,
+                region,
+                identity: sts_client.get_caller_identity().await,
+            }
+        });
+    }
+
+    /// Replace the clients with those of `sdk_config`, with new credentials, and resume the
+    /// Live Tail sessions that ended when the credentials expired.
+    fn refresh_clients(&mut self, sdk_config: &SdkConfig) {
+        self.refreshing_credentials = false;
+        self.credentials_refreshed = Some(Instant::now());
+
+        let regions = self
+            .region
+            .as_deref()
+            .map(aws::config::parse_regions)
+            .unwrap_or_default();
+        self.clients = RegionalClients::for_regions(sdk_config, &regions);
+        self.logs_client = self.active_clients().map_or_else(
+            || aws::cloudwatch_logs::Client::new(sdk_config),
+            |clients| clients.logs_client.clone(),
+        );
+        for tab in &mut self.tabs {
+            tab.logs_client = self
+                .clients
+                .iter()
+                .find(|clients| clients.region == tab.region)
+                .map_or_else(
+                    || self.logs_client.clone(),
+                    |clients| clients.logs_client.clone(),
+                );
+        }
+
+        for tab in 0..self.tabs.len() {
+            self.resume_live_tail(tab);
+        }
+    }
+
+    /// Forget everything loaded from AWS, as it is for another profile or region.
+    ///
+    /// The functions are shown as loading until the new AWS config is loaded.
+    fn reset_views(&mut self) {
+        if let Some(task) = self.function_list.task.take() {
+            task.abort();
+        }
+        for task in &self.function_list.detail_tasks {
+            task.abort();
+        }
+        self.toasts.dismiss_all();
+        self.caller_identity = None;
+        self.function_list = FunctionList {
+            loading: true,
+            ..Default::default()
+        };
+        self.log_group_list = LogGroupList::default();
+        self.service_list = ServiceList::default();
+        self.state_machine_list = StateMachineList::default();
+        self.stage_list = StageList::default();
+        self.database_list = DatabaseList::default();
+        self.project_list = ProjectList::default();
+        self.list_mode = ListMode::default();
+        self.tabs = vec![Tab::new(
+            self.logs_client.clone(),
+            self.active_region.clone(),
+        )];
+        self.tab = 0;
+        self.error_screen = None;
+        self.refreshing_credentials = false;
+    }
+
+    /// Load all functions in the background, listing each page as it arrives.
+    fn load_functions(&mut self) {
+        if let Some(task) = self.function_list.task.take() {
+            task.abort();
+        }
+        self.function_list.functions = None;
+        self.function_list.matches.clear();
+        self.function_list.rows.clear();
+        self.function_list.loading = true;
+        self.function_list.loading_regions = self.clients.len();
+
+        // The functions cached by an earlier run are listed until they all load again.
+        let cached = cache::load_functions(
+            self.profile.as_deref(),
+            self.region.as_deref(),
+            self.role_arn.as_deref(),
+        );
+        self.function_list.cached = cached.is_some();
+        self.function_list.loaded = Some(Vec::new());
+        if let Some(functions) = cached {
+            self.function_list.functions = Some(functions);
+            self.function_list.update_matches(None);
+        }
+
+        let sender = self.events.sender();
+        let lambda_clients = self.lambda_clients();
+        self.function_list.task = Some(tokio::spawn(async move {
+            // Each region is paged through concurrently, listing each page as it arrives.
+            future::join_all(lambda_clients.into_iter().map(|lambda_client| {
+                let sender = sender.clone();
+                async move {
+                    let mut next_marker = None;
+                    loop {
+                        let page = lambda_client.get_functions_page(next_marker).await;
+                        next_marker = page.as_ref().ok().and_then(|page| page.next_marker.clone());
+                        if sender.send(AppEvent::FunctionsPageLoaded(page)).is_err()
+                            || next_marker.is_none()
+                        {
+                            break;
+                        }
+                    }
+                }
+            }))
+            .await;
+        }));
+    }
+
+    /// Cache the listed functions, for the next run to list while they load again.
+    fn cache_functions(&self) {
+        let Some(functions) = &self.function_list.functions else {
+            return;
+        };
+
+        // Failing to cache the functions only makes the next run slower to list them.
+        let _ = cache::save_functions(
+            self.profile.as_deref(),
+            self.region.as_deref(),
+            self.role_arn.as_deref(),
+            functions,
+        );
+    }
+
+    /// Load all functions again in the background, then list them in place of the current ones.
+    ///
+    /// Nothing is done while the functions are still loading.
+    fn refresh_functions(&mut self) {
+        if self.function_list.loading {
+            return;
+        }
+        self.function_list.loading = true;
+
+        let lambda_clients = self.lambda_clients();
+        self.function_list.task = Some(self.events.spawn(async move {
+            let functions = future::try_join_all(
+                lambda_clients
+                    .iter()
+                    .map(|lambda_client| lambda_client.get_all_functions_page()),
+            )
+            .await
+            .map(|pages| {
+                let mut all = aws::lambda::FunctionPage::default();
+                for page in pages {
+                    all.functions.extend(page.functions);
+                    all.summaries.extend(page.summaries);
+                }
+                all.functions.sort();
+                all
+            });
+            AppEvent::FunctionsRefreshed(functions)
+        }));
+    }
+
+    /// Load the configuration of the selected function in the background, unless it has been.
+    fn load_function_configuration(&mut self) {
+        let Some(function) = self.selected_function().cloned() else {
+            return;
+        };
+        if self.function_list.configurations.contains_key(&function) {
+            return;
+        }
+        let Some(lambda_client) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region == function.region)
+            .map(|clients| clients.lambda_client.clone())
+        else {
+            return;
+        };
+
+        self.function_list
+            .configurations
+            .insert(function.clone(), FunctionDetails::Loading);
+        self.events.spawn(async move {
+            let configuration = lambda_client
+                .get_function_configuration(&function.name)
+                .await;
+            AppEvent::FunctionConfigurationLoaded {
+                function,
+                configuration: Box::new(configuration),
+            }
+        });
+    }
+
+    /// Load the tags of the selected function, or of every listed function while they are
+    /// filtered by tag, or grouped by stack, unless they are loaded already.
+    fn load_function_tags(&mut self) {
+        let function_list = &self.function_list;
+        let unloaded =
+            |function: &&aws::lambda::Function| !function_list.tags.contains_key(*function);
+        let functions: Vec<aws::lambda::Function> =
+            if function_list.tag_filter.is_empty() && !function_list.grouped {
+                self.selected_function()
+                    .filter(unloaded)
+                    .cloned()
+                    .into_iter()
+                    .collect()
+            } else {
+                function_list
+                    .functions
+                    .iter()
+                    .flatten()
+                    .filter(unloaded)
+                    .cloned()
+                    .collect()
+            };
+        let loads: Vec<_> = functions
+            .into_iter()
+            .filter_map(|function| {
+                let lambda_client = self
+                    .clients
+                    .iter()
+                    .find(|clients| clients.region == function.region)?
+                    .lambda_client
+                    .clone();
+                Some((lambda_client, function))
+            })
+            .collect();
+        if loads.is_empty() {
+            return;
+        }
+
+        for (_, function) in &loads {
+            self.function_list
+                .tags
+                .insert(function.clone(), FunctionDetails::Loading);
+        }
+        self.spawn_function_loads(loads, |lambda_client, function| async move {
+            let tags = lambda_client.get_function_tags(&function.name).await;
+            AppEvent::FunctionTagsLoaded { function, tags }
+        });
+    }
+
+    /// Load when each listed function last logged in the background, unless loaded already.
+    fn load_function_activity(&mut self) {
+        let function_list = &self.function_list;
+        let loads: Vec<_> = function_list
+            .functions
+            .iter()
+            .flatten()
+            .filter(|function| !function_list.last_activity.contains_key(*function))
+            .filter_map(|function| {
+                let logs_client = self
+                    .clients
+                    .iter()
+                    .find(|clients| clients.region == function.region)?
+                    .logs_client
+                    .clone();
+                Some((logs_client, function.clone()))
+            })
+            .collect();
+        if loads.is_empty() {
+            return;
+        }
+
+        for (_, function) in &loads {
+            self.function_list
+                .last_activity
+                .insert(function.clone(), FunctionDetails::Loading);
+        }
+        self.spawn_function_loads(loads, |logs_client, function| async move {
+            let last_event_time = logs_client
+                .get_last_event_time(&aws::cloudwatch_logs::lambda_log_group_name(&function.name))
+                .await;
+            AppEvent::FunctionActivityLoaded {
+                function,
+                last_event_time,
+            }
+        });
+    }
+
+    /// Load a detail of each function of `loads` with its client in the background, a few at
+    /// a time, sending the event `load` makes of each.
+    fn spawn_function_loads<C, F, Fut>(&mut self, loads: Vec<(C, aws::lambda::Function)>, load: F)
+    where
+        C: Send + 'static,
+        F: Fn(C, aws::lambda::Function) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppEvent> + Send + 'static,
+    {
+        let sender = self.events.sender();
+        self.function_list
+            .detail_tasks
+            .retain(|task| !task.is_finished());
+        self.function_list
+            .detail_tasks
+            .push(tokio::spawn(async move {
+                stream::iter(loads)
+                    .map(|(client, function)| load(client, function))
+                    .buffer_unordered(MAX_CONCURRENT_LOADS)
+                    .for_each(|event| {
+                        let _ = sender.send(event);
+                        future::ready(())
+                    })
+                    .await;
+            }));
+    }
+
+    /// Load the metrics of the selected function over the time range of the tab, unless they
+    /// are loaded already.
+    fn load_function_metrics(&mut self) {
+        let Some(function) = self.selected_function().cloned() else {
+            return;
+        };
+        let time_range = self.tab().time_range;
+        if self
+            .function_list
+            .metrics
+            .contains_key(&(function.clone(), time_range))
+        {
+            return;
+        }
+        let Some(metrics_client) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region == function.region)
+            .map(|clients| clients.metrics_client.clone())
+        else {
+            return;
+        };
+
+        self.function_list
+            .metrics
+            .insert((function.clone(), time_range), FunctionDetails::Loading);
+        let now = SystemTime::now();
+        let (start_time, end_time) = time_range.bounds(now);
+        let end_time = end_time.unwrap_or_else(|| epoch_millis(now));
+        self.events.spawn(async move {
+            let metrics = metrics_client
+                .get_function_metrics(&function.name, start_time, end_time)
+                .await;
+            AppEvent::FunctionMetricsLoaded {
+                function,
+                time_range,
+                metrics,
+            }
+        });
+    }
+
+    /// Load the versions and aliases of the selected function, to pick one to view logs of.
+    fn load_function_versions(&mut self) {
+        let Some(function) = self.selected_function().cloned() else {
+            return;
+        };
+        let Some(lambda_client) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region == function.region)
+            .map(|clients| clients.lambda_client.clone())
+        else {
+            return;
+        };
+
+        self.notice = Some(format!("Loading versions of {}...", function.name));
+        self.versions_function = Some(function.clone());
+        self.events.spawn(async move {
+            let versions = lambda_client.get_function_versions(&function.name).await;
+            AppEvent::FunctionVersionsLoaded { function, versions }
+        });
+    }
+
+    /// Edit the payload to invoke the selected function with, starting from its last payload.
+    fn open_invoke_input(&mut self, dry_run: bool) {
+        let Some(function) = self.selected_function().cloned() else {
+            return;
+        };
+
+        let payload = self
+            .invoke_payloads
+            .get(&function)
+            .map_or(DEFAULT_INVOKE_PAYLOAD, String::as_str);
+        self.invoke_input = Some(InvokeInput {
+            payload: TextInput::new(payload),
+            function,
+            dry_run,
+        });
+    }
+
+    /// Invoke the function with the edited payload, in the background.
+    fn invoke_function(&mut self) {
+        let Some(invoke_input) = self.invoke_input.take() else {
+            return;
+        };
+        let InvokeInput {
+            function,
+            payload,
+            dry_run,
+        } = invoke_input;
+        let payload = payload.value;
+        // Remembered even if invalid, so it can be fixed rather than typed again.
+        self.invoke_payloads
+            .insert(function.clone(), payload.clone());
+
+        if let Err(error) = serde_json::from_str::<serde_json::Value>(&payload) {
+            self.error = Some(format!("Invalid JSON payload, {error}"));
+            return;
+        }
+        let Some(lambda_client) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region == function.region)
+            .map(|clients| clients.lambda_client.clone())
+        else {
+            return;
+        };
+
+        self.notice = Some(format!("Invoking {}...", function.name));
+        self.events.spawn(async move {
+            let invocation = lambda_client
+                .invoke(&function.name, &payload, dry_run)
+                .await;
+            AppEvent::FunctionInvoked {
+                function,
+                dry_run,
+                invocation,
+            }
+        });
+    }
+
+    /// Report how the invocation of `function` went, and tail the logs of its request.
+    fn show_invocation(
+        &mut self,
+        function: aws::lambda::Function,
+        invocation: aws::lambda::Invocation,
+    ) {
+        let response = match &invocation.function_error {
+            Some(function_error) => format!("failed ({function_error})"),
+            None => format!("returned {}", invocation.payload),
+        };
+        self.notice = Some(format!(
+            "Invoked {} ({}), {response}",
+            function.name, invocation.status_code
+        ));
+
+        let Some(request_id) = invocation.request_id else {
+            return;
+        };
+        // The invocation has returned, so it started at most its longest run time ago.
+        let now = Utc::now().timestamp_millis();
+        self.view_invocation_logs(
+            &function,
+            request_id,
+            now - MAX_INVOCATION_TIME.as_millis() as i64,
+            now,
+        );
+        // Lambda may still be sending the last of its logs.
+        self.toggle_live_tail();
+    }
+
+    fn lambda_clients(&self) -> Vec<aws::lambda::Client> {
+        self.clients
+            .iter()
+            .map(|clients| clients.lambda_client.clone())
+            .collect()
+    }
+
+    /// Whether functions are listed from several regions.
+    fn is_multi_region(&self) -> bool {
+        self.clients.len() > 1
+    }
+
+    /// View logs in the region `region`, closing the log views of the tab in the previous region.
+    ///
+    /// Only applies when functions are listed from several regions.
+    fn use_logs_region(&mut self, region: Option<&str>) {
+        if !self.is_multi_region() {
+            return;
+        }
+        let Some(clients) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region.as_deref() == region)
+        else {
+            return;
+        };
+        let logs_client = clients.logs_client.clone();
+        let region = clients.region.clone();
+
+        if region != self.active_region {
+            self.logs_client = logs_client.clone();
+            self.active_region = region.clone();
+            self.log_group_list = LogGroupList::default();
+            self.service_list = ServiceList::default();
+            self.state_machine_list = StateMachineList::default();
+            self.stage_list = StageList::default();
+            self.database_list = DatabaseList::default();
+            self.project_list = ProjectList::default();
+        }
+
+        let tab = self.tab_mut();
+        if tab.region != region {
+            tab.logs_client = logs_client;
+            tab.region = region;
+            tab.log_stream_list = None;
+            tab.log_view = None;
+            tab.insights_view = None;
+        }
+    }
+
+    /// The log group of the selected function or log group, using the region of the function.
+    fn select_log_group(&mut self) -> Option<String> {
+        let log_group_name = self.selected_log_group_name()?;
+        if self.list_mode == ListMode::Functions {
+            let region = self.selected_function()?.region.clone();
+            self.use_logs_region(region.as_deref());
+        }
+        Some(log_group_name)
+    }
+
+    /// Whether chips to narrow the listed functions by runtime and architecture are shown above
+    /// them, once any of their summaries are listed.
+    fn shows_filter_chips(&self) -> bool {
+        self.list_mode == ListMode::Functions && !self.function_list.summaries.is_empty()
+    }
+
+    fn selected_function(&self) -> Option<&aws::lambda::Function> {
+        self.function_list.selected()
+    }
+
+    fn selected_log_group(&self) -> Option<&aws::cloudwatch_logs::LogGroup> {
+        self.log_group_list.selected()
+    }
+
+    /// The log group of the selected function or log group, depending on the list mode.
+    fn selected_log_group_name(&self) -> Option<String> {
+        match self.list_mode {
+            ListMode::Functions => self
+                .selected_function()
+                .map(|function| aws::cloudwatch_logs::lambda_log_group_name(&function.name)),
+            ListMode::LogGroups => self
+                .selected_log_group()
+                .map(|log_group| log_group.name.clone()),
+            ListMode::Services => self
+                .selected_container_logs()
+                .map(|container_logs| container_logs.log_group_name.clone()),
+            ListMode::StateMachines => None,
+            ListMode::Stages => self.selected_stage().and_then(|stage| {
+                if self.stage_list.execution_logs {
+                    Some(stage.execution_log_group_name())
+                } else {
+                    stage.access_log_group_name.clone()
+                }
+            }),
+            ListMode::Databases => self.database_list.selected().and_then(|database| {
+                let log = self.database_list.selected_log()?;
+                Some(database.log_group_name(log))
+            }),
+            ListMode::Projects => self
+                .project_list
+                .selected()
+                .and_then(|project| project.log_group_name.clone()),
+        }
+    }
+
+    fn selected_stage(&self) -> Option<&aws::api_gateway::Stage> {
+        let i = self.stage_list.state.selected()?;
+        self.stage_list.stages.as_ref()?.get(i)
+    }
+
+    fn selected_state_machine(&self) -> Option<&aws::step_functions::StateMachine> {
+        let i = self.state_machine_list.state.selected()?;
+        self.state_machine_list.state_machines.as_ref()?.get(i)
+    }
+
+    fn selected_service(&self) -> Option<&aws::ecs::Service> {
+        let i = self.service_list.state.selected()?;
+        self.service_list.services.as_ref()?.get(i)
+    }
+
+    /// Where the first container of the selected service logs to, once loaded.
+    fn selected_container_logs(&self) -> Option<&aws::ecs::ContainerLogs> {
+        match self.service_list.logs.get(self.selected_service()?)? {
+            FunctionDetails::Loaded(service_logs) => service_logs.containers.first(),
+            _ => None,
+        }
+    }
+
+    /// Switch the top pane between listing functions, all log groups, all ECS services, all
+    /// state machines, all API stages, all RDS databases, and all CodeBuild projects.
+    ///
+    /// Everything but functions is loaded the first time it is listed.
+    fn toggle_list_mode(&mut self) {
+        self.list_mode = match self.list_mode {
+            ListMode::Functions => ListMode::LogGroups,
+            ListMode::LogGroups => ListMode::Services,
+            ListMode::Services => ListMode::StateMachines,
+            ListMode::StateMachines => ListMode::Stages,
+            ListMode::Stages => ListMode::Databases,
+            ListMode::Databases => ListMode::Projects,
+            ListMode::Projects => ListMode::Functions,
+        };
+
+        if self.list_mode == ListMode::LogGroups
+            && self.log_group_list.log_groups.is_none()
+            && !self.log_group_list.loading
+        {
+            self.load_log_groups();
+        }
+        if self.list_mode == ListMode::Services
+            && self.service_list.services.is_none()
+            && !self.service_list.loading
+        {
+            self.load_services();
+        }
+        if self.list_mode == ListMode::StateMachines
+            && self.state_machine_list.state_machines.is_none()
+            && !self.state_machine_list.loading
+        {
+            self.load_state_machines();
+        }
+        if self.list_mode == ListMode::Stages
+            && self.stage_list.stages.is_none()
+            && !self.stage_list.loading
+        {
+            self.load_stages();
+        }
+        if self.list_mode == ListMode::Databases
+            && self.database_list.databases.is_none()
+            && !self.database_list.loading
+        {
+            self.load_databases();
+        }
+        if self.list_mode == ListMode::Projects
+            && self.project_list.projects.is_none()
+            && !self.project_list.loading
+        {
+            self.load_projects();
+        }
+    }
+
+    /// The clients of the region of the viewed logs.
+    fn active_clients(&self) -> Option<&RegionalClients> {
+        self.clients
+            .iter()
+            .find(|clients| clients.region == self.active_region)
+    }
+
+    fn load_services(&mut self) {
+        let Some(ecs_client) = self
+            .active_clients()
+            .map(|clients| clients.ecs_client.clone())
+        else {
+            return;
+        };
+
+        self.service_list.loading = true;
+        self.events
+            .spawn(async move { AppEvent::ServicesLoaded(ecs_client.get_all_services().await) });
+    }
+
+    fn load_state_machines(&mut self) {
+        let Some(sfn_client) = self
+            .active_clients()
+            .map(|clients| clients.sfn_client.clone())
+        else {
+            return;
+        };
+
+        self.state_machine_list.loading = true;
+        self.events.spawn(async move {
+            AppEvent::StateMachinesLoaded(sfn_client.get_all_state_machines().await)
+        });
+    }
+
+    fn load_stages(&mut self) {
+        let Some(api_gateway_client) = self
+            .active_clients()
+            .map(|clients| clients.api_gateway_client.clone())
+        else {
+            return;
+        };
+
+        self.stage_list.loading = true;
+        self.events.spawn(async move {
+            AppEvent::StagesLoaded(api_gateway_client.get_all_stages().await)
+        });
+    }
+
+    fn load_databases(&mut self) {
+        let Some(rds_client) = self
+            .active_clients()
+            .map(|clients| clients.rds_client.clone())
+        else {
+            return;
+        };
+
+        self.database_list.loading = true;
+        self.events
+            .spawn(async move { AppEvent::DatabasesLoaded(rds_client.get_all_databases().await) });
+    }
+
+    fn load_projects(&mut self) {
+        let Some(codebuild_client) = self
+            .active_clients()
+            .map(|clients| clients.codebuild_client.clone())
+        else {
+            return;
+        };
+
+        self.project_list.loading = true;
+        self.events.spawn(async move {
+            AppEvent::ProjectsLoaded(codebuild_client.get_all_projects().await)
+        });
+    }
+
+    /// Show the event source mappings of the selected function in a popup, as a disabled or
+    /// failing mapping explains why a function stopped logging.
+    fn browse_event_sources(&mut self) {
+        if self.list_mode != ListMode::Functions {
+            return;
+        }
+        let Some(function) = self.selected_function().cloned() else {
+            return;
+        };
+        let Some(lambda_client) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region == function.region)
+            .map(|clients| clients.lambda_client.clone())
+        else {
+            return;
+        };
+
+        self.event_sources = Some(EventSourceView {
+            function: function.clone(),
+            mappings: None,
+            state: ListState::default(),
+        });
+        self.events.spawn(async move {
+            let mappings = lambda_client
+                .get_event_source_mappings(&function.name)
+                .await;
+            AppEvent::EventSourceMappingsLoaded { function, mappings }
+        });
+    }
+
+    /// Peek at the failed events of the selected function in a popup, once its configuration
+    /// tells which SQS queue they are sent to.
+    fn browse_failed_events(&mut self) {
+        if self.list_mode != ListMode::Functions {
+            return;
+        }
+        let Some(function) = self.selected_function().cloned() else {
+            return;
+        };
+        let configuration = match self.function_list.configurations.get(&function) {
+            Some(FunctionDetails::Loaded(configuration)) => configuration,
+            Some(FunctionDetails::Failed(error)) => {
+                self.error = Some(format!(
+                    "Failed to load the configuration of {}: {error}",
+                    function.name
+                ));
+                return;
+            }
+            Some(FunctionDetails::Loading) | None => {
+                self.notice = Some(format!(
+                    "The configuration of {} is still loading, try again once it is",
+                    function.name
+                ));
+                self.load_function_configuration();
+                return;
+            }
+        };
+        let Some(queue_arn) = configuration.failure_queue().map(String::from) else {
+            self.error = Some(match &configuration.on_failure_error {
+                Some(error) => format!(
+                    "Failed to load the on-failure destination of {}: {error}",
+                    function.name
+                ),
+                None => format!(
+                    "{} has no dead-letter queue, or on-failure destination, in SQS",
+                    function.name
+                ),
+            });
+            return;
+        };
+        let Some(sqs_client) = self
+            .clients
+            .iter()
+            .find(|clients| clients.region == function.region)
+            .map(|clients| clients.sqs_client.clone())
+        else {
+            return;
+        };
+
+        self.failed_events = Some(FailedEventView {
+            function,
+            queue_arn: queue_arn.clone(),
+            messages: None,
+            state: ListState::default(),
+        });
+        self.events.spawn(async move {
+            let messages = sqs_client
+                .peek_messages(&queue_arn, MAX_FAILED_EVENTS)
+                .await;
+            AppEvent::FailedEventsLoaded {
+                queue_arn,
+                messages,
+            }
+        });
+    }
+
+    /// Show the whole of the selected failed event, with its JSON pretty-printed.
+    fn show_failed_event(&mut self) {
+        let Some(message) = self
+            .failed_events
+            .as_ref()
+            .and_then(FailedEventView::selected)
+        else {
+            return;
+        };
+        let timestamp = message.sent_timestamp.unwrap_or_default();
+        self.event_view = Some(EventView::new(aws::cloudwatch_logs::LogEvent {
+            timestamp,
+            ingestion_time: timestamp,
+            message: message.body.clone(),
+            ..Default::default()
+        }));
+    }
+
+    /// View the logs of the invocation that failed the selected event, up to when it was sent
+    /// to the queue, after any retries.
+    fn open_failed_event_logs(&mut self) {
+        let Some(failed_event_view) = &self.failed_events else {
+            return;
+        };
+        let function = failed_event_view.function.clone();
+        let Some(message) = failed_event_view.selected() else {
+            return;
+        };
+        let Some(request_id) = message.request_id() else {
+            self.error = Some("The failed event has no request ID to find the logs of".to_string());
+            return;
+        };
+        let sent_timestamp = message
+            .sent_timestamp
+            .unwrap_or_else(|| Utc::now().timestamp_millis());
+
+        self.failed_events = None;
+        self.view_invocation_logs(
+            &function,
+            request_id,
+            sent_timestamp - MAX_EVENT_AGE.as_millis() as i64,
+            sent_timestamp,
+        );
+    }
+
+    /// Show the recent builds of the selected CodeBuild project in a popup.
+    fn browse_builds(&mut self) {
+        if self.list_mode != ListMode::Projects {
+            return;
+        }
+        let Some(project) = self.project_list.selected().cloned() else {
+            return;
+        };
+        let Some(codebuild_client) = self
+            .active_clients()
+            .map(|clients| clients.codebuild_client.clone())
+        else {
+            return;
+        };
+
+        self.builds = Some(BuildView {
+            project: project.clone(),
+            builds: None,
+            state: ListState::default(),
+        });
+        self.events.spawn(async move {
+            let builds = codebuild_client
+                .get_recent_builds(&project.name, MAX_BUILDS)
+                .await;
+            AppEvent::BuildsLoaded { project, builds }
+        });
+    }
+
+    /// View the logs of the selected build, tailing them while the build is in progress.
+    fn open_build(&mut self) {
+        let Some(build) = self.builds.as_ref().and_then(BuildView::selected).cloned() else {
+            return;
+        };
+        let (Some(log_group_name), Some(log_stream_name)) =
+            (build.log_group_name.clone(), build.log_stream_name.clone())
+        else {
+            self.error = Some(format!(
+                "The build {} hasn't logged to CloudWatch Logs",
+                build.id
+            ));
+            return;
+        };
+
+        self.builds = None;
+        if build.in_progress() {
+            self.start_live_tail(log_group_name, vec![log_stream_name]);
+        } else {
+            // The whole build is viewed, however long ago it ran.
+            let log_stream_names = vec![log_stream_name];
+            let filter = aws::cloudwatch_logs::EventFilter {
+                start_time: Some(build.start_time),
+                end_time: Some(
+                    build
+                        .end_time
+                        .unwrap_or_else(|| Utc::now().timestamp_millis()),
+                ),
+                ..self.event_filter(&log_group_name, &log_stream_names)
+            };
+            self.load_filtered_logs(log_group_name, log_stream_names, filter);
+        }
+    }
+
+    /// Show the recent executions of the selected state machine in a popup.
+    fn browse_executions(&mut self) {
+        if self.list_mode != ListMode::StateMachines {
+            return;
+        }
+        let Some(state_machine) = self.selected_state_machine().cloned() else {
+            return;
+        };
+        let Some(sfn_client) = self
+            .active_clients()
+            .map(|clients| clients.sfn_client.clone())
+        else {
+            return;
+        };
+
+        self.executions = Some(ExecutionView {
+            state_machine: state_machine.clone(),
+            executions: None,
+            state: ListState::default(),
+            history: None,
+        });
+        self.events.spawn(async move {
+            let executions = sfn_client
+                .get_recent_executions(&state_machine.arn, MAX_EXECUTIONS)
+                .await;
+            AppEvent::ExecutionsLoaded {
+                state_machine,
+                executions,
+            }
+        });
+    }
+
+    /// Show the event history of the selected execution, then view the logs of the functions
+    /// it invoked if `open_logs`.
+    fn open_execution(&mut self, open_logs: bool) {
+        let Some(execution_view) = &mut self.executions else {
+            return;
+        };
+        if execution_view.history.is_some() {
+            return;
+        }
+        let Some(execution) = execution_view.selected().cloned() else {
+            return;
+        };
+        let Some(sfn_client) = self
+            .active_clients()
+            .map(|clients| clients.sfn_client.clone())
+        else {
+            return;
+        };
+
+        let execution_arn = execution.arn.clone();
+        if let Some(execution_view) = &mut self.executions {
+            execution_view.history = Some(ExecutionHistory {
+                execution,
+                events: None,
+                open_logs,
+                scroll: 0,
+            });
+        }
+        self.events.spawn(async move {
+            let history = sfn_client.get_execution_history(&execution_arn).await;
+            AppEvent::ExecutionHistoryLoaded {
+                execution_arn,
+                history,
+            }
+        });
+    }
+
+    /// View the logs of every function the opened execution invoked, each in a new tab, over
+    /// the time range of the execution.
+    fn open_execution_logs(&mut self) {
+        let Some(execution_history) = self
+            .executions
+            .as_ref()
+            .and_then(|execution_view| execution_view.history.as_ref())
+        else {
+            return;
+        };
+        let Some(Ok(events)) = &execution_history.events else {
+            return;
+        };
+        let function_names: Vec<String> = aws::step_functions::invoked_functions(events)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        if function_names.is_empty() {
+            self.error = Some("The execution didn't invoke any Lambda functions".to_string());
+            return;
+        }
+        let execution = &execution_history.execution;
+        let time_range = TimeRange::Between {
+            start: execution.start_date,
+            end: execution
+                .stop_date
+                .unwrap_or_else(|| Utc::now().timestamp_millis()),
+        };
+
+        self.executions = None;
+        for function_name in &function_names {
+            let mut tab = Tab::new(self.logs_client.clone(), self.active_region.clone());
+            tab.time_range = time_range;
+            self.tabs.push(tab);
+            self.tab = self.tabs.len() - 1;
+            self.load_logs(
+                aws::cloudwatch_logs::lambda_log_group_name(function_name),
+                Vec::new(),
+            );
+        }
+        self.notice = Some(format!(
+            "Viewing the logs of {} in new tabs",
+            function_names.join(", ")
+        ));
+    }
+
+    /// Load where the containers of the selected service log to, and its tasks, unless they
+    /// are loaded already.
+    fn load_service_logs(&mut self) {
+        if self.list_mode != ListMode::Services {
+            return;
+        }
+        let Some(service) = self.selected_service().cloned() else {
+            return;
+        };
+        if self.service_list.logs.contains_key(&service) {
+            return;
+        }
+        let Some(ecs_client) = self
+            .active_clients()
+            .map(|clients| clients.ecs_client.clone())
+        else {
+            return;
+        };
+
+        self.service_list
+            .logs
+            .insert(service.clone(), FunctionDetails::Loading);
+        self.events.spawn(async move {
+            let service_logs = ecs_client.get_service_logs(&service).await;
+            AppEvent::ServiceLogsLoaded {
+                service,
+                service_logs,
+            }
+        });
+    }
+
+    fn load_log_groups(&mut self) {
+        self.log_group_list.loading = true;
+        let logs_client = self.logs_client.clone();
+        self.events.spawn(async move {
+            AppEvent::LogGroupsLoaded(logs_client.get_all_log_groups().await)
+        });
+    }
+
+    /// The log group and name of the selected log stream.
+    fn selected_log_stream(&self) -> Option<(String, String)> {
+        let log_stream_list = self.tab().log_stream_list.as_ref()?;
+        let log_stream = log_stream_list
+            .log_streams
+            .get(log_stream_list.state.selected()?)?;
+
+        Some((
+            log_stream_list.log_group_name.clone(),
+            log_stream.name.clone(),
+        ))
+    }
+
+    /// The log group and names of the marked log streams, in the order listed, or else of the
+    /// selected log stream.
+    fn marked_log_streams(&self) -> Option<(String, Vec<String>)> {
+        let log_stream_list = self.tab().log_stream_list.as_ref()?;
+        if log_stream_list.marked.is_empty() {
+            let (log_group_name, log_stream_name) = self.selected_log_stream()?;
+            return Some((log_group_name, vec![log_stream_name]));
+        }
+
+        let log_stream_names = log_stream_list
+            .log_streams
+            .iter()
+            .filter(|log_stream| log_stream_list.marked.contains(&log_stream.name))
+            .map(|log_stream| log_stream.name.clone())
+            .collect();
+        Some((log_stream_list.log_group_name.clone(), log_stream_names))
+    }
+
+    /// Mark the selected log stream to view with the other marked log streams, or unmark it,
+    /// then select the next log stream.
+    fn mark_log_stream(&mut self) {
+        let Some((_, log_stream_name)) = self.selected_log_stream() else {
+            return;
+        };
+        let Some(log_stream_list) = &mut self.tab_mut().log_stream_list else {
+            return;
+        };
+
+        if !log_stream_list.marked.remove(&log_stream_name) {
+            if log_stream_list.marked.len() >= MAX_MARKED_LOG_STREAMS {
+                self.notice = Some(format!(
+                    "At most {MAX_MARKED_LOG_STREAMS} log streams can be viewed together"
+                ));
+                return;
+            }
+            log_stream_list.marked.insert(log_stream_name);
+        }
+        log_stream_list.state.select_next();
+    }
+
+    /// List the most recently active log streams of the selected function, log group, or
+    /// service.
+    ///
+    /// Only the log streams of the tasks of the first container of a service are listed, as
+    /// its log group may be shared with other services.
+    fn open_log_streams(&mut self) {
+        let Some(log_group_name) = self.select_log_group() else {
+            return;
+        };
+        let scope = match self.list_mode {
+            ListMode::Services => self
+                .selected_container_logs()
+                .and_then(aws::ecs::ContainerLogs::log_stream_prefix)
+                .map(LogStreamScope::Prefix),
+            _ => None,
+        };
+
+        self.tab_mut().log_view = None;
+        self.load_log_streams(log_group_name, scope);
+        self.tab_mut().focus = Focus::LogStreams;
+    }
+
+    /// Load the most recently active log streams of the log group `log_group_name`, only of
+    /// `scope` if set.
+    fn load_log_streams(&mut self, log_group_name: String, scope: Option<LogStreamScope>) {
+        self.tab_mut().log_stream_list = Some(LogStreamList {
+            log_group_name: log_group_name.clone(),
+            scope,
+            loading: true,
+            ..Default::default()
+        });
+
+        let logs_client = self.tab().logs_client.clone();
+        self.events.spawn(async move {
+            let log_streams = logs_client
+                .get_recent_log_streams(&log_group_name, MAX_LOG_STREAMS)
+                .await;
+            AppEvent::LogStreamsLoaded {
+                log_group_name,
+                log_streams,
+            }
+        });
+    }
+
+    fn close_log_streams(&mut self) {
+        self.tab_mut().log_stream_list = None;
+        self.tab_mut().focus = Focus::List;
+    }
+
+    /// Load the recent log events of the selected function or log group into the log viewer.
+    fn open_logs(&mut self) {
+        let Some(log_group_name) = self.select_log_group() else {
+            return;
+        };
+
+        self.load_logs(log_group_name, Vec::new());
+    }
+
+    /// The filter for log events of the log group `log_group_name`, and optionally only
+    /// of its log streams `log_stream_names`.
+    fn event_filter(
+        &self,
+        log_group_name: &str,
+        log_stream_names: &[String],
+    ) -> aws::cloudwatch_logs::EventFilter {
+        let (start_time, end_time) = self.tab().time_range.bounds(SystemTime::now());
+        aws::cloudwatch_logs::EventFilter {
+            start_time: Some(start_time),
+            end_time,
+            pattern: self.tab().filter_patterns.get(log_group_name).cloned(),
+            log_stream_names: log_stream_names.to_vec(),
+        }
+    }
+
+    /// Load the recent log events of the log group `log_group_name` into the log viewer,
+    /// optionally only of its log stream `log_stream_name`.
+    ///
+    /// Only log events matching the log group's filter pattern are loaded.
+    fn load_logs(&mut self, log_group_name: String, log_stream_names: Vec<String>) {
+        let filter = self.event_filter(&log_group_name, &log_stream_names);
+        self.open_log_view(
+            LogView {
+                log_group_name,
+                log_stream_names,
+                ..Default::default()
+            },
+            filter,
+        );
+    }
+
+    /// Load the recent log events of the log group `log_group_name` matching `filter` into the
+    /// log viewer, in place of the tab's time range and the log group's filter pattern.
+    fn load_filtered_logs(
+        &mut self,
+        log_group_name: String,
+        log_stream_names: Vec<String>,
+        filter: aws::cloudwatch_logs::EventFilter,
+    ) {
+        self.open_log_view(
+            LogView {
+                log_group_name,
+                log_stream_names,
+                filter: Some(filter.clone()),
+                ..Default::default()
+            },
+            filter,
+        );
+    }
+
+    /// View the logs of the invocation of `function` with the request ID `request_id`, from
+    /// the log events between `start_time` and `end_time`.
+    ///
+    /// Not every line of an invocation has its request ID, such as what the function prints,
+    /// so all the log events from the first to the last line that has it are loaded, and only
+    /// those between the invocation's `START` and `REPORT` lines are shown. The tab's time
+    /// range and filter pattern are left as they are.
+    fn view_invocation_logs(
+        &mut self,
+        function: &aws::lambda::Function,
+        request_id: String,
+        start_time: i64,
+        end_time: i64,
+    ) {
+        let log_group_name = aws::cloudwatch_logs::lambda_log_group_name(&function.name);
+        self.use_logs_region(function.region.as_deref());
+        let filter = aws::cloudwatch_logs::EventFilter {
+            start_time: Some(start_time),
+            end_time: Some(end_time),
+            ..Default::default()
+        };
+        self.load_invocation_logs(log_group_name, request_id, filter);
+    }
+
+    /// Load the log events of the log group `log_group_name` matching `filter` into the log
+    /// viewer, showing only those of the invocation with the request ID `request_id`.
+    fn load_invocation_logs(
+        &mut self,
+        log_group_name: String,
+        request_id: String,
+        filter: aws::cloudwatch_logs::EventFilter,
+    ) {
+        self.open_log_view(
+            LogView {
+                log_group_name,
+                filter: Some(filter.clone()),
+                invocations: Some(InvocationList::default()),
+                invocation: Some(request_id),
+                ..Default::default()
+            },
+            filter,
+        );
+    }
+
+    /// Open the log viewer `log_view` in place of the viewed logs, and load the recent log
+    /// events matching `filter` into it, only those around its request when of one invocation.
+    fn open_log_view(&mut self, log_view: LogView, filter: aws::cloudwatch_logs::EventFilter) {
+        let log_group_name = log_view.log_group_name.clone();
+        let log_stream_names = log_view.log_stream_names.clone();
+        let request_id = log_view.invocation.clone();
+        self.remember_recent(&log_group_name);
+
+        self.tab_mut().log_view = Some(LogView {
+            loading: true,
+            ..log_view
+        });
+        self.tab_mut().focus = Focus::Logs;
+
+        let logs_client = self.tab().logs_client.clone();
+        self.events.spawn(async move {
+            let events = match request_id {
+                Some(request_id) => {
+                    logs_client
+                        .get_request_log_events(
+                            &log_group_name,
+                            &request_id,
+                            &filter,
+                            MAX_LOG_EVENTS,
+                        )
+                        .await
+                }
+                None => {
+                    logs_client
+                        .get_recent_log_events(&log_group_name, &filter, MAX_LOG_EVENTS)
+                        .await
+                }
+            };
+            AppEvent::LogEventsLoaded {
+                log_group_name,
+                log_stream_names,
+                events,
+            }
+        });
+    }
+
+    /// Load the log events before the oldest event in the log viewer, unless none remain.
+    fn load_older_logs(&mut self) {
+        let Some(log_view) = &self.tab().log_view else {
+            return;
+        };
+        if log_view.loading || log_view.loading_older || log_view.loaded_oldest {
+            return;
+        }
+        let Some(oldest) = log_view.events.first() else {
+            return;
+        };
+        let log_group_name = log_view.log_group_name.clone();
+        let log_stream_names = log_view.log_stream_names.clone();
+        let filter = aws::cloudwatch_logs::EventFilter {
+            end_time: Some(oldest.timestamp - 1),
+            ..log_view
+                .filter
+                .clone()
+                .unwrap_or_else(|| self.event_filter(&log_group_name, &log_stream_names))
+        };
+
+        if let Some(log_view) = &mut self.tab_mut().log_view {
+            log_view.loading_older = true;
+        }
+
+        let logs_client = self.tab().logs_client.clone();
+        self.events.spawn(async move {
+            let events = logs_client
+                .get_recent_log_events(&log_group_name, &filter, MAX_LOG_EVENTS)
+                .await;
+            AppEvent::OlderLogEventsLoaded {
+                log_group_name,
+                log_stream_names,
+                events,
+            }
+        });
+    }
+
+    /// Open the log viewer for the log group `log_group_name`, optionally only of its log
+    /// stream `log_stream_name`, and tail its new log events.
+    fn start_live_tail(&mut self, log_group_name: String, log_stream_names: Vec<String>) {
+        if self
+            .tab()
+            .log_view
+            .as_ref()
+            .is_none_or(|log_view| !log_view.is_viewing(&log_group_name, &log_stream_names))
+        {
+            self.remember_recent(&log_group_name);
+            self.tab_mut().log_view = Some(LogView {
+                log_group_name,
+                log_stream_names,
+                ..Default::default()
+            });
+        }
+        self.tab_mut().focus = Focus::Logs;
+
+        if self
+            .tab()
+            .log_view
+            .as_ref()
+            .is_some_and(|log_view| log_view.live_tail.is_none())
+        {
+            self.toggle_live_tail();
+        }
+    }
+
+    /// Start or stop tailing the log group in the log viewer.
+    fn toggle_live_tail(&mut self) {
+        let tab = &mut self.tabs[self.tab];
+        let Some(log_view) = &mut tab.log_view else {
+            return;
+        };
+
+        if log_view.live_tail.take().is_some() {
+            log_view.follow_paused = false;
+            log_view.unseen_events = 0;
+            return;
+        }
+
+        self.spawn_live_tail(self.tab, None);
+    }
+
+    /// Tail the log group in the log viewer of the tab `tab`, after loading the log events of
+    /// the `gap` since an earlier session ended.
+    fn spawn_live_tail(&mut self, tab: usize, gap: Option<TailGap>) {
+        let tab = &mut self.tabs[tab];
+        let Some(log_view) = &mut tab.log_view else {
+            return;
+        };
+
+        log_view.resuming_live_tail = false;
+        let filter = aws::cloudwatch_logs::EventFilter {
+            pattern: match &log_view.filter {
+                Some(filter) => filter.pattern.clone(),
+                None => log_view
+                    .search_pattern
+                    .clone()
+                    .or_else(|| tab.filter_patterns.get(&log_view.log_group_name).cloned()),
+            },
+            log_stream_names: log_view.log_stream_names.clone(),
+            ..Default::default()
+        };
+        log_view.live_tail = Some(LiveTailTask::spawn(
+            &self.events,
+            &tab.logs_client,
+            log_view.log_group_name.clone(),
+            log_view.merged_log_group_names.clone(),
+            filter,
+            gap,
+        ));
+    }
+
+    /// Resume tailing in the log viewer of the tab `tab` from its last log event, if the
+    /// session ended when the credentials expired.
+    fn resume_live_tail(&mut self, tab: usize) {
+        let Some(log_view) = &self.tabs[tab].log_view else {
+            return;
+        };
+        if !log_view.resuming_live_tail {
+            return;
+        }
+
+        let gap = log_view.events.last().map(|last| TailGap {
+            start_time: last.timestamp,
+            seen: log_view
+                .events
+                .iter()
+                .rev()
+                .take_while(|event| event.timestamp == last.timestamp)
+                .map(|event| event.message.clone())
+                .collect(),
+        });
+        self.spawn_live_tail(tab, gap);
+    }
+
+    /// The log group of the log viewer when it is focused, otherwise the selected log group.
+    fn focused_log_group_name(&mut self) -> Option<String> {
+        let tab = self.tab();
+        match (&tab.focus, &tab.log_view) {
+            (Focus::Logs, Some(log_view)) => Some(log_view.log_group_name.clone()),
+            _ => self.select_log_group(),
+        }
+    }
+
+    /// Start editing the filter pattern to search the marked log groups with together, or the
+    /// log groups of the marked functions, else of the favorite functions.
+    fn open_group_search_input(&mut self) {
+        let (log_group_names, region): (Vec<String>, _) = match self.list_mode {
+            ListMode::LogGroups => (self.log_group_list.marked.iter().cloned().collect(), None),
+            ListMode::Functions => {
+                let function_list = &self.function_list;
+                let functions = if function_list.marked.is_empty() {
+                    &function_list.favorites
+                } else {
+                    &function_list.marked
+                };
+                // Log groups are searched with the client of a single region.
+                let region = functions
+                    .iter()
+                    .next()
+                    .and_then(|function| function.region.clone());
+                if functions.iter().any(|function| function.region != region) {
+                    self.error = Some(
+                        "Only the log groups of functions in the same region can be searched together"
+                            .to_string(),
+                    );
+                    return;
+                }
+                (
+                    functions
+                        .iter()
+                        .map(|function| aws::cloudwatch_logs::lambda_log_group_name(&function.name))
+                        .collect(),
+                    region,
+                )
+            }
+            _ => (Vec::new(), None),
+        };
+
+        if log_group_names.is_empty() {
+            self.notice = Some(
+                "Mark log groups, or functions, with Space, or star functions with b, to search them"
+                    .to_string(),
+            );
+            return;
+        }
+        if log_group_names.len() > MAX_SEARCHED_LOG_GROUPS {
+            self.error = Some(format!(
+                "At most {MAX_SEARCHED_LOG_GROUPS} log groups can be searched together"
+            ));
+            return;
+        }
+
+        if self.list_mode == ListMode::Functions {
+            self.use_logs_region(region.as_deref());
+        }
+        let mut log_group_names = log_group_names;
+        log_group_names.sort();
+        self.group_search_input = Some(TextInput::default());
+        self.group_search_log_group_names = log_group_names;
+    }
+
+    /// View the most recent log events of the log groups `log_group_names` matching `pattern`,
+    /// searched together, merged in the order they were logged.
+    fn search_log_groups(&mut self, log_group_names: Vec<String>, pattern: Option<String>) {
+        let Some((log_group_name, merged_log_group_names)) = log_group_names.split_first() else {
+            return;
+        };
+        if let Some(pattern) = &pattern {
+            self.history.add_filter_pattern(pattern);
+        }
+
+        let (start_time, end_time) = self.tab().time_range.bounds(SystemTime::now());
+        let filter = aws::cloudwatch_logs::EventFilter {
+            start_time: Some(start_time),
+            end_time,
+            pattern: pattern.clone(),
+            log_stream_names: Vec::new(),
+        };
+        self.tab_mut().log_view = Some(LogView {
+            log_group_name: log_group_name.clone(),
+            merged_log_group_names: merged_log_group_names.to_vec(),
+            search_pattern: pattern,
+            loading: true,
+            // Older log events are only loaded of a single log group.
+            loaded_oldest: true,
+            ..Default::default()
+        });
+        self.tab_mut().focus = Focus::Logs;
+
+        let logs_client = self.tab().logs_client.clone();
+        let log_group_name = log_group_name.clone();
+        self.events.spawn(async move {
+            let events = logs_client
+                .search_log_groups(&log_group_names, &filter, MAX_LOG_EVENTS)
+                .await;
+            AppEvent::LogEventsLoaded {
+                log_group_name,
+                log_stream_names: Vec::new(),
+                events,
+            }
+        });
+    }
+
+    /// Start editing the filter pattern of the viewed log group, or of the selected function.
+    fn open_filter_input(&mut self) {
+        let Some(log_group_name) = self.focused_log_group_name() else {
+            return;
+        };
+
+        let pattern = self
+            .tab()
+            .filter_patterns
+            .get(&log_group_name)
+            .map_or("", String::as_str);
+        self.filter_input = Some(TextInput::new(pattern));
+        self.filter_log_group_name = log_group_name;
+    }
+
+    /// Save the edited filter pattern and reload the log viewer with it.
+    fn apply_filter(&mut self) {
+        let Some(filter_input) = self.filter_input.take() else {
+            return;
+        };
+
+        let log_group_name = std::mem::take(&mut self.filter_log_group_name);
+        let pattern = filter_input.value.trim();
+        self.history.add_filter_pattern(pattern);
+        if pattern.is_empty() {
+            self.tab_mut().filter_patterns.remove(&log_group_name);
+        } else {
+            self.tab_mut()
+                .filter_patterns
+                .insert(log_group_name.clone(), pattern.to_string());
+        }
+
+        self.reload_logs(log_group_name);
+    }
+
+    /// Narrow the listed functions to those with the tags input, loading the tags of every
+    /// function to match them.
+    fn apply_tag_filter(&mut self) {
+        let Some(tag_filter_input) = self.tag_filter_input.take() else {
+            return;
+        };
+
+        let selected = self.function_list.selected().cloned();
+        self.function_list.tag_filter = tag_filter_input.value.trim().to_string();
+        self.function_list.update_matches(selected);
+    }
+
+    /// Open the AWS console in the browser, at the selected function or log group, or at the
+    /// log events being viewed in `mode`, over the same time range.
+    fn open_console(&mut self, mode: Mode) {
+        let tab = self.tab();
+        let (start_time, end_time) = tab.time_range.bounds(SystemTime::now());
+        let url = match mode {
+            Mode::List => match self.list_mode {
+                ListMode::Functions => self.selected_function().and_then(|function| {
+                    let region = function.region.as_ref().or(self.active_region.as_ref())?;
+                    Some(aws::console::lambda_function_url(region, &function.name))
+                }),
+                ListMode::LogGroups => self.selected_log_group().and_then(|log_group| {
+                    let region = self.active_region.as_ref()?;
+                    Some(aws::console::log_events_url(
+                        region,
+                        &log_group.name,
+                        None,
+                        None,
+                        None,
+                    ))
+                }),
+                ListMode::Services => self.selected_service().and_then(|service| {
+                    let region = self.active_region.as_ref()?;
+                    Some(aws::console::ecs_service_url(
+                        region,
+                        &service.cluster,
+                        &service.name,
+                    ))
+                }),
+                ListMode::StateMachines => {
+                    self.selected_state_machine().and_then(|state_machine| {
+                        let region = self.active_region.as_ref()?;
+                        Some(aws::console::state_machine_url(region, &state_machine.arn))
+                    })
+                }
+                ListMode::Stages => self.selected_stage().and_then(|stage| {
+                    let region = self.active_region.as_ref()?;
+                    Some(aws::console::api_stage_url(
+                        region,
+                        &stage.api_id,
+                        &stage.name,
+                    ))
+                }),
+                ListMode::Databases => self.database_list.selected().and_then(|database| {
+                    let region = self.active_region.as_ref()?;
+                    Some(aws::console::rds_database_url(
+                        region,
+                        &database.identifier,
+                        database.cluster,
+                    ))
+                }),
+                ListMode::Projects => self.project_list.selected().and_then(|project| {
+                    let region = self.active_region.as_ref()?;
+                    Some(aws::console::codebuild_project_url(region, &project.name))
+                }),
+            },
+            Mode::Executions => self.executions.as_ref().and_then(|execution_view| {
+                let execution = match &execution_view.history {
+                    Some(execution_history) => &execution_history.execution,
+                    None => execution_view.selected()?,
+                };
+                let region = self.active_region.as_ref()?;
+                Some(aws::console::execution_url(region, &execution.arn))
+            }),
+            Mode::Builds => self.builds.as_ref().and_then(|build_view| {
+                let build = build_view.selected()?;
+                let region = self.active_region.as_ref()?;
+                Some(aws::console::codebuild_build_url(
+                    region,
+                    &build_view.project.name,
+                    &build.id,
+                ))
+            }),
+            Mode::LogStreams => {
+                self.selected_log_stream()
+                    .and_then(|(log_group_name, log_stream_name)| {
+                        Some(aws::console::log_events_url(
+                            tab.region.as_ref()?,
+                            &log_group_name,
+                            Some(&log_stream_name),
+                            Some(start_time),
+                            end_time,
+                        ))
+                    })
+            }
+            // The log stream of the selected log event, as its log events are likely related.
+            Mode::Logs => tab.log_view.as_ref().and_then(|log_view| {
+                let log_stream_name = log_view
+                    .selected_log_event()
+                    .and_then(|log_event| log_event.log_stream_name.as_ref())
+                    .or(log_view.log_stream_names.first());
+                Some(aws::console::log_events_url(
+                    tab.region.as_ref()?,
+                    &log_view.log_group_name,
+                    log_stream_name.map(String::as_str),
+                    Some(start_time),
+                    end_time,
+                ))
+            }),
+            Mode::Insights => tab.insights_view.as_ref().and_then(|insights_view| {
+                Some(aws::console::log_events_url(
+                    tab.region.as_ref()?,
+                    &insights_view.log_group_name,
+                    None,
+                    Some(start_time),
+                    end_time,
+                ))
+            }),
+            _ => None,
+        };
+        let Some(url) = url else {
+            return;
+        };
+
+        match open::that_detached(&url) {
+            Ok(()) => self.notice = Some("Opened the AWS console in the browser".to_string()),
+            Err(error) => self.error = Some(format!("Failed to open {url}: {error}")),
+        }
+    }
+
+    /// Copy the message of the selected log event to the clipboard, after its timestamp if
+    /// `with_timestamp`.
+    fn copy_log_event(&mut self, with_timestamp: bool) {
+        let Some(log_event) = self
+            .tab()
+            .log_view
+            .as_ref()
+            .and_then(LogView::selected_log_event)
+        else {
+            return;
+        };
+
+        let text = if with_timestamp {
+            // Copied timestamps are never relative, as they would soon be wrong.
+            let timestamp_format = TimestampFormat {
+                style: TimestampStyle::Iso,
+                ..self.timestamp_format
+            };
+            let timestamp = timestamp_format.format(log_event.timestamp, Utc::now());
+            format!("{timestamp} {}", log_event.message.trim_end())
+        } else {
+            log_event.message.trim_end().to_string()
+        };
+
+        match clipboard::copy(&text) {
+            Ok(()) => self.notice = Some("Copied the log event to the clipboard".to_string()),
+            Err(error) => self.error = Some(format!("Failed to copy the log event: {error}")),
+        }
+    }
+
+    /// Copy a `curl` command requesting the Function URL of the selected function, once its
+    /// configuration is loaded.
+    fn copy_curl_command(&mut self) {
+        let Some(function) = self.selected_function() else {
+            return;
+        };
+        let Some(FunctionDetails::Loaded(configuration)) =
+            self.function_list.configurations.get(function)
+        else {
+            return;
+        };
+        let Some(url) = &configuration.url else {
+            self.error = Some(match &configuration.url_error {
+                Some(error) => format!(
+                    "Failed to load the Function URL of {}: {error}",
+                    function.name
+                ),
+                None => format!("{} has no Function URL", function.name),
+            });
+            return;
+        };
+
+        match clipboard::copy(&url.curl_command()) {
+            Ok(()) => self.notice = Some("Copied the curl command to the clipboard".to_string()),
+            Err(error) => self.error = Some(format!("Failed to copy the curl command: {error}")),
+        }
+    }
+
+    /// Edit the path to export the shown log events to, starting from a file named after the
+    /// log group.
+    fn open_export_input(&mut self) {
+        if let Some(log_view) = &self.tab().log_view {
+            let log_group_name = &log_view.log_group_name;
+            let name = log_group_name.rsplit('/').next().unwrap_or(log_group_name);
+            self.export_input = Some(TextInput::new(&format!("{name}.log")));
+        }
+    }
+
+    /// Write the shown log events to the edited path, in the background, in the format of its
+    /// extension.
+    ///
+    /// Log events are exported as shown, so hidden events are left out, and a paused
+    /// Live Tail session is exported as far as it has streamed.
+    fn export_log_events(&mut self) {
+        let Some(export_input) = self.export_input.take() else {
+            return;
+        };
+        let Some(log_view) = &self.tab().log_view else {
+            return;
+        };
+        let path = match export_input.value.trim() {
+            "" => return,
+            path => match (path.strip_prefix("~/"), dirs::home_dir()) {
+                (Some(path), Some(home_dir)) => home_dir.join(path),
+                _ => PathBuf::from(path),
+            },
+        };
+
+        // Exported timestamps are never relative, as they would soon be wrong.
+        let timestamp_format = TimestampFormat {
+            style: TimestampStyle::Iso,
+            ..self.timestamp_format
+        };
+        let now = Utc::now();
+        let contents = ExportFormat::from_path(&path).export(log_view.shown.iter().map(|&i| {
+            let log_event = &log_view.events[i];
+            (timestamp_format.format(log_event.timestamp, now), log_event)
+        }));
+        let count = log_view.shown.len();
+
+        self.events.spawn(async move {
+            match tokio::fs::write(&path, contents).await {
+                Ok(()) => {
+                    AppEvent::Notice(format!("Exported {count} log events to {}", path.display()))
+                }
+                Err(error) => AppEvent::Error(format!(
+                    "Failed to export log events to {}: {error}",
+                    path.display()
+                )),
+            }
+        });
+    }
+
+    /// Edit the S3 bucket to export the selected log group to, starting from the last one
+    /// exported to.
+    fn open_s3_export_input(&mut self) {
+        let Some(log_group_name) = self.focused_log_group_name() else {
+            return;
+        };
+        self.s3_export_input = Some(TextInput::new(
+            self.s3_destination.as_deref().unwrap_or_default(),
+        ));
+        self.s3_export_log_group_name = log_group_name;
+    }
+
+    /// Export the log events of the log group over the time range of the tab to the edited S3
+    /// bucket, by an export task that CloudWatch Logs runs in the background.
+    ///
+    /// How the export is going is checked until it ends, and shown as toasts.
+    fn export_to_s3(&mut self) {
+        let Some(s3_export_input) = self.s3_export_input.take() else {
+            return;
+        };
+        let log_group_name = std::mem::take(&mut self.s3_export_log_group_name);
+        let destination = s3_export_input.value.trim().to_string();
+        let Some((bucket, prefix)) = aws::cloudwatch_logs::parse_s3_destination(&destination)
+        else {
+            return;
+        };
+        let (bucket, prefix) = (bucket.to_string(), prefix.map(String::from));
+        let url = match &prefix {
+            Some(prefix) => format!("s3://{bucket}/{prefix}"),
+            None => format!("s3://{bucket}"),
+        };
+        self.s3_destination = Some(destination);
+
+        let logs_client = self.tab().logs_client.clone();
+        let now = SystemTime::now();
+        let (from, to) = self.tab().time_range.bounds(now);
+        let to = to.unwrap_or_else(|| epoch_millis(now));
+        let sender = self.events.sender();
+        self.notice = Some(format!("Exporting {log_group_name} to {url}..."));
+        self.events.spawn(async move {
+            let task_id = match logs_client
+                .create_export_task(&log_group_name, from, to, &bucket, prefix.as_deref())
+                .await
+            {
+                Ok(task_id) => task_id,
+                Err(error) => {
+                    return AppEvent::Error(format!(
+                        "Failed to export {log_group_name} to {url}: {error}"
+                    ));
+                }
+            };
+
+            let mut status = String::new();
+            loop {
+                tokio::time::sleep(EXPORT_POLL_INTERVAL).await;
+                let export_task = match logs_client.get_export_task(&task_id).await {
+                    Ok(Some(export_task)) => export_task,
+                    Ok(None) => {
+                        return AppEvent::Error(format!("Export task {task_id} no longer exists"));
+                    }
+                    Err(error) => {
+                        return AppEvent::Error(format!(
+                            "Failed to check on the export of {log_group_name}: {error}"
+                        ));
+                    }
+                };
+
+                if export_task.is_completed() {
+                    return AppEvent::Notice(format!("Exported {log_group_name} to {url}"));
+                } else if export_task.is_finished() {
+                    return AppEvent::Error(format!(
+                        "Export of {log_group_name} to {url} {}: {}",
+                        export_task.status.to_lowercase(),
+                        export_task.message.as_deref().unwrap_or("no reason given")
+                    ));
+                } else if export_task.status != status {
+                    let _ = sender.send(AppEvent::Notice(format!(
+                        "Exporting {log_group_name} to {url}, {}",
+                        export_task.status.to_lowercase()
+                    )));
+                    status = export_task.status;
+                }
+            }
+        });
+    }
+
+    /// Edit the path to download the selected log group to, starting from a gzipped JSON Lines
+    /// file named after the log group and the start of the time range.
+    fn open_download_input(&mut self) {
+        let Some(log_group_name) = self.focused_log_group_name() else {
+            return;
+        };
+        let name = log_group_name.rsplit('/').next().unwrap_or(&log_group_name);
+        let (start_time, _) = self.tab().time_range.bounds(SystemTime::now());
+        let start = chrono::DateTime::from_timestamp_millis(start_time)
+            .map(|start| start.format("%Y%m%dT%H%M").to_string())
+            .unwrap_or_default();
+        self.download_input = Some(TextInput::new(&format!("{name}-{start}.jsonl.gz")));
+        self.download_log_group_name = log_group_name;
+    }
+
+    /// Download every log event of the log group over the time range of the tab to the edited
+    /// path, a page at a time in the background, as JSON Lines gzipped if the path ends in
+    /// `.gz`.
+    ///
+    /// Unlike exporting the shown log events, this isn't limited to the log events that fit in
+    /// the log viewer, for post-mortems of hours of logs.
+    fn download_log_events(&mut self) {
+        let Some(download_input) = self.download_input.take() else {
+            return;
+        };
+        let log_group_name = std::mem::take(&mut self.download_log_group_name);
+        let path = match download_input.value.trim() {
+            "" => return,
+            path => match (path.strip_prefix("~/"), dirs::home_dir()) {
+                (Some(path), Some(home_dir)) => home_dir.join(path),
+                _ => PathBuf::from(path),
+            },
+        };
+
+        let filter = self.event_filter(&log_group_name, &[]);
+        let start_time = filter.start_time.unwrap_or_default();
+        let end_time = filter
+            .end_time
+            .unwrap_or_else(|| epoch_millis(SystemTime::now()));
+        let logs_client = self.tab().logs_client.clone();
+        let sender = self.events.sender();
+        let timestamp_format = TimestampFormat {
+            style: TimestampStyle::Iso,
+            ..self.timestamp_format
+        };
+
+        let id = self.next_download_id;
+        self.next_download_id += 1;
+        self.downloads.push(Download {
+            id,
+            log_group_name: log_group_name.clone(),
+            count: 0,
+            progress: 0.0,
+        });
+        self.events.spawn(async move {
+            let failed = |error: String| AppEvent::DownloadFinished {
+                id,
+                outcome: Err(format!(
+                    "Failed to download {log_group_name} to {}: {error}",
+                    path.display()
+                )),
+            };
+            let mut file = match ExportFile::create(&path).await {
+                Ok(file) => file,
+                Err(error) => return failed(error.to_string()),
+            };
+
+            let mut count = 0;
+            let mut latest = start_time;
+            let mut next_token = None;
+            loop {
+                let page = match logs_client
+                    .get_log_events_page(&log_group_name, &filter, DOWNLOAD_PAGE_SIZE, next_token)
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(error) => return failed(error.to_string()),
+                };
+                let now = Utc::now();
+                let contents =
+                    ExportFormat::JsonLines.export(page.log_events.iter().map(|log_event| {
+                        (timestamp_format.format(log_event.timestamp, now), log_event)
+                    }));
+                if let Err(error) = file.write(&contents).await {
+                    return failed(error.to_string());
+                }
+
+                // Pages are roughly in time order, so how far through the time range the
+                // latest log event is, is roughly how far through the download is.
+                count += page.log_events.len();
+                latest = page
+                    .log_events
+                    .iter()
+                    .map(|log_event| log_event.timestamp)
+                    .fold(latest, i64::max);
+                let progress = (latest - start_time) as f64 / (end_time - start_time).max(1) as f64;
+                let _ = sender.send(AppEvent::DownloadProgress {
+                    id,
+                    count,
+                    progress: progress.clamp(0.0, 1.0),
+                });
+
+                next_token = page.next_token;
+                if next_token.is_none() {
+                    break;
+                }
+            }
+
+            if let Err(error) = file.finish().await {
+                return failed(error.to_string());
+            }
+            AppEvent::DownloadFinished {
+                id,
+                outcome: Ok(format!(
+                    "Downloaded {count} log events of {log_group_name} to {}",
+                    path.display()
+                )),
+            }
+        });
+    }
+
+    /// Edit the search of the log viewer, starting from its current search.
+    fn open_search_input(&mut self) {
+        if let Some(log_view) = &self.tab().log_view {
+            let search = log_view.search.as_deref().unwrap_or_default();
+            self.search_input = Some(TextInput::new(search));
+        }
+    }
+
+    /// Search the log viewer for the edited text, going to the first match after the selection.
+    fn apply_search(&mut self) {
+        let Some(search_input) = self.search_input.take() else {
+            return;
+        };
+        let Some(log_view) = &mut self.tab_mut().log_view else {
+            return;
+        };
+
+        log_view.search = (!search_input.value.is_empty()).then_some(search_input.value);
+        self.search(true);
+    }
+
+    /// Go to the next, or previous, match of the log viewer's search.
+    fn search(&mut self, forward: bool) {
+        let Some(log_view) = &mut self.tab_mut().log_view else {
+            return;
+        };
+        let Some(search) = log_view.search.clone() else {
+            return;
+        };
+
+        if !log_view.select_match(forward) {
+            self.error = Some(format!("No log events match: {search}"));
+        }
+    }
+
+    /// Edit the regex filter of the log viewer, starting from its current regex.
+    fn open_regex_filter_input(&mut self) {
+        if let Some(log_view) = &self.tab().log_view {
+            let regex = log_view.regex_filter.as_ref().map_or("", Regex::as_str);
+            self.regex_filter_input = Some(TextInput::new(regex));
+        }
+    }
+
+    /// Show only the loaded log events matching the edited regex, or all of them if it's empty.
+    fn apply_regex_filter(&mut self) {
+        let Some(regex_filter_input) = self.regex_filter_input.take() else {
+            return;
+        };
+
+        let regex_filter = match regex_filter_input.value.as_str() {
+            "" => None,
+            regex => match Regex::new(regex) {
+                Ok(regex) => Some(regex),
+                Err(error) => {
+                    // Parse errors span several lines, pointing at the problem, which ends them.
+                    let error = error.to_string();
+                    let reason = error.lines().last().unwrap_or_default();
+                    self.error = Some(format!("Invalid regex {regex}, {reason}"));
+                    return;
+                }
+            },
+        };
+        if let Some(log_view) = &mut self.tab_mut().log_view {
+            log_view.regex_filter = regex_filter;
+            log_view.update_shown();
+        }
+    }
+
+    /// Edit the filter of the VPC flow log records, starting from its current filter.
+    fn open_flow_filter_input(&mut self) {
+        if let Some(flow_logs) = self
+            .tab()
+            .log_view
+            .as_ref()
+            .and_then(|log_view| log_view.flow_logs.as_ref())
+        {
+            let filter = flow_logs.filter.as_ref().map_or("", |(text, _)| text);
+            self.flow_filter_input = Some(TextInput::new(filter));
+        }
+    }
+
+    /// Show only the VPC flow log records matching the edited filter, or all of them if it's
+    /// empty.
+    fn apply_flow_filter(&mut self) {
+        let Some(flow_filter_input) = self.flow_filter_input.take() else {
+            return;
+        };
+
+        let filter = match flow_filter_input.value.trim() {
+            "" => None,
+            text => match aws::vpc::FlowFilter::parse(text) {
+                Some(filter) => Some((text.to_string(), filter)),
+                None => {
+                    let columns: Vec<&str> = aws::vpc::FlowField::ALL
+                        .iter()
+                        .map(|field| field.name())
+                        .collect();
+                    self.error = Some(format!(
+                        "Invalid filter {text}, expected column=value, such as action=REJECT, \
+                         of columns {}",
+                        columns.join(", ")
+                    ));
+                    return;
+                }
+            },
+        };
+        if let Some(flow_logs) = self
+            .tab_mut()
+            .log_view
+            .as_mut()
+            .and_then(|log_view| log_view.flow_logs.as_mut())
+        {
+            flow_logs.filter = filter;
+            flow_logs.update_shown();
+        }
+    }
+
+    /// Show the timeline of the X-Ray trace of the selected log event, or of the selected
+    /// invocation in `mode`.
+    fn show_trace(&mut self, mode: Mode) {
+        let Some(log_view) = &self.tab().log_view else {
+            return;
+        };
+        let messages: Vec<&str> = match mode {
+            Mode::Invocations => log_view
+                .invocations
+                .as_ref()
+                .and_then(InvocationList::selected)
+                .map(|invocation| {
+                    invocation
+                        .events
+                        .iter()
+                        .map(|&i| log_view.events[i].message.as_str())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => log_view
+                .selected_log_event()
+                .map(|log_event| vec![log_event.message.as_str()])
+                .unwrap_or_default(),
+        };
+        let Some(trace_id) = messages
+            .into_iter()
+            .find_map(aws::xray::find_trace_id)
+            .map(String::from)
+        else {
+            self.error = Some("No X-Ray trace ID found, is tracing enabled?".to_string());
+            return;
+        };
+        let region = &self.tab().region;
+        let Some(xray_client) = self
+            .clients
+            .iter()
+            .find(|clients| &clients.region == region)
+            .or(self.clients.first())
+            .map(|clients| clients.xray_client.clone())
+        else {
+            return;
+        };
+
+        self.trace = Some(TraceView {
+            trace_id: trace_id.clone(),
+            trace: None,
+            scroll: 0,
+        });
+        self.events.spawn(async move {
+            let trace = xray_client.get_trace(&trace_id).await;
+            AppEvent::TraceLoaded { trace_id, trace }
+        });
+    }
+
+    /// Show the CloudTrail events of the selected item over the time range of the tab, such as
+    /// who changed the configuration of a function around when errors started.
+    fn show_cloudtrail(&mut self) {
+        let resource_name = match self.list_mode {
+            ListMode::Functions => self
+                .selected_function()
+                .map(|function| function.name.clone()),
+            ListMode::LogGroups => self
+                .selected_log_group()
+                .map(|log_group| log_group.name.clone()),
+            ListMode::Services => self.selected_service().map(|service| service.name.clone()),
+            ListMode::StateMachines => self
+                .selected_state_machine()
+                .map(|state_machine| state_machine.arn.clone()),
+            ListMode::Stages => self.selected_stage().map(|stage| stage.api_id.clone()),
+            ListMode::Databases => self
+                .database_list
+                .selected()
+                .map(|database| database.identifier.clone()),
+            ListMode::Projects => self
+                .project_list
+                .selected()
+                .map(|project| project.name.clone()),
+        };
+        let Some(resource_name) = resource_name else {
+            return;
+        };
+        // Functions may be listed from several regions, everything else is in the active one.
+        let clients = match self.selected_function() {
+            Some(function) if self.list_mode == ListMode::Functions => self
+                .clients
+                .iter()
+                .find(|clients| clients.region == function.region),
+            _ => self.active_clients(),
+        };
+        let Some(cloudtrail_client) = clients.map(|clients| clients.cloudtrail_client.clone())
+        else {
+            return;
+        };
+        let now = SystemTime::now();
+        let (start_time, end_time) = self.tab().time_range.bounds(now);
+
+        self.cloudtrail = Some(CloudTrailView {
+            cloudtrail_client,
+            resource_name,
+            event_name: String::new(),
+            start_time,
+            end_time: end_time.unwrap_or_else(|| epoch_millis(now)),
+            events: None,
+            state: ListState::default(),
+            editing: None,
+        });
+        self.look_up_trail_events();
+    }
+
+    /// Look up the CloudTrail events being viewed again, such as once filtered differently.
+    fn look_up_trail_events(&mut self) {
+        let Some(cloudtrail) = &mut self.cloudtrail else {
+            return;
+        };
+
+        cloudtrail.events = None;
+        cloudtrail.state = ListState::default();
+        let cloudtrail_client = cloudtrail.cloudtrail_client.clone();
+        let resource_name = cloudtrail.resource_name.clone();
+        let event_name = cloudtrail.event_name.clone();
+        let (start_time, end_time) = (cloudtrail.start_time, cloudtrail.end_time);
+        self.events.spawn(async move {
+            let events = cloudtrail_client
+                .lookup_events(
+                    Some(resource_name.as_str()).filter(|name| !name.is_empty()),
+                    Some(event_name.as_str()).filter(|name| !name.is_empty()),
+                    start_time,
+                    end_time,
+                    MAX_TRAIL_EVENTS,
+                )
+                .await;
+            AppEvent::CloudTrailEventsLoaded {
+                resource_name,
+                event_name,
+                events,
+            }
+        });
+    }
+
+    /// Edit `filter` of the CloudTrail events being viewed.
+    fn edit_trail_filter(&mut self, filter: TrailFilter) {
+        if let Some(cloudtrail) = &mut self.cloudtrail {
+            let value = match filter {
+                TrailFilter::ResourceName => &cloudtrail.resource_name,
+                TrailFilter::EventName => &cloudtrail.event_name,
+            };
+            cloudtrail.editing = Some((filter, TextInput::new(value)));
+        }
+    }
+
+    /// Look up the CloudTrail events again with the edited filter, if it changed.
+    fn apply_trail_filter(&mut self) {
+        let Some(cloudtrail) = &mut self.cloudtrail else {
+            return;
+        };
+        let Some((filter, input)) = cloudtrail.editing.take() else {
+            return;
+        };
+        let value = input.value.trim().to_string();
+        let current = match filter {
+            TrailFilter::ResourceName => &mut cloudtrail.resource_name,
+            TrailFilter::EventName => &mut cloudtrail.event_name,
+        };
+        if *current != value {
+            *current = value;
+            self.look_up_trail_events();
+        }
+    }
+
+    /// Load the log events of the log group `log_group_name` into the log viewer again.
+    ///
+    /// Keeps viewing the same log streams, and keeps tailing, if the log group is being viewed.
+    fn reload_logs(&mut self, log_group_name: String) {
+        let (log_stream_names, was_live) = match &self.tab().log_view {
+            Some(log_view) if log_view.log_group_name == log_group_name => (
+                log_view.log_stream_names.clone(),
+                log_view.live_tail.is_some(),
+            ),
+            _ => (Vec::new(), false),
+        };
+        self.load_logs(log_group_name, log_stream_names);
+        if was_live {
+            self.toggle_live_tail();
+        }
+    }
+
+    /// Open the full-screen Logs Insights mode for the viewed log group, or of the selected function.
+    fn open_insights(&mut self) {
+        let Some(log_group_name) = self.focused_log_group_name() else {
+            return;
+        };
+
+        self.tab_mut().insights_view = Some(InsightsView {
+            log_group_name,
+            query: TextInput::new(DEFAULT_INSIGHTS_QUERY),
+            editing: true,
+            ..Default::default()
+        });
+        self.tab_mut().focus = Focus::Insights;
+    }
+
+    /// Replace the Logs Insights query with the one being built, to edit as text.
+    fn edit_built_query(&mut self) {
+        let Some(query_builder) = self.query_builder.take() else {
+            return;
+        };
+        if let Some(insights_view) = &mut self.tab_mut().insights_view {
+            insights_view.query = TextInput::new(&query_builder.query());
+            insights_view.editing = true;
+        }
+    }
+
+    /// Run the edited Logs Insights query over the recent log events.
+    fn run_insights_query(&mut self) {
+        let tab = &mut self.tabs[self.tab];
+        let Some(insights_view) = &mut tab.insights_view else {
+            return;
+        };
+
+        insights_view.editing = false;
+        insights_view.running = true;
+        self.history.add_query(&insights_view.query.value);
+
+        let logs_client = tab.logs_client.clone();
+        let log_group_name = insights_view.log_group_name.clone();
+        let query = insights_view.query.value.clone();
+        let now = SystemTime::now();
+        let (start_time, end_time) = tab.time_range.bounds(now);
+        let end_time = end_time.unwrap_or_else(|| epoch_millis(now));
+        self.events.spawn(async move {
+            let results = logs_client
+                .run_query(
+                    std::slice::from_ref(&log_group_name),
+                    &query,
+                    start_time / 1000,
+                    end_time / 1000,
+                )
+                .await;
+            AppEvent::QueryResultsLoaded {
+                log_group_name,
+                results,
+            }
+        });
+    }
+
+    /// Load the log event of the selected Logs Insights result, to show the whole of it.
+    fn show_log_record(&mut self) {
+        let tab = self.tab();
+        let Some(row) = tab
+            .insights_view
+            .as_ref()
+            .and_then(InsightsView::selected_row)
+        else {
+            return;
+        };
+        // Rows aggregated by `stats` have no single log event.
+        let Some(ptr) = row.ptr.clone() else {
+            self.notice = Some("The result isn't of a single log event".to_string());
+            return;
+        };
+
+        let logs_client = tab.logs_client.clone();
+        self.notice = Some("Loading the log event...".to_string());
+        self.events.spawn(async move {
+            AppEvent::LogRecordLoaded(logs_client.get_log_record(&ptr).await)
+        });
+    }
+
+    /// Save the Logs Insights query by the name input, replacing any query saved by that name,
+    /// and to CloudWatch Logs too if configured to.
+    fn save_query(&mut self) {
+        let Some(query_name_input) = self.query_name_input.take() else {
+            return;
+        };
+        let name = query_name_input.value.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let tab = self.tab();
+        let Some(insights_view) = &tab.insights_view else {
+            return;
+        };
+        let logs_client = tab.logs_client.clone();
+        let log_group_name = insights_view.log_group_name.clone();
+        let query = insights_view.query.value.clone();
+
+        match self
+            .saved_queries
+            .iter_mut()
+            .find(|saved| saved.name == name)
+        {
+            Some(saved) => saved.query.clone_from(&query),
+            None => {
+                self.saved_queries.push(SavedQuery {
+                    name: name.clone(),
+                    query: query.clone(),
+                });
+                self.saved_queries.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+        self.notice = Some(format!("Saved query {name}"));
+
+        if !self.sync_queries {
+            return;
+        }
+        // Update the query definition of the same name, if there is one, rather than add another.
+        let id = self
+            .query_definitions
+            .iter()
+            .find(|query_definition| query_definition.name == name)
+            .map(|query_definition| query_definition.id.clone());
+        self.events.spawn(async move {
+            match logs_client
+                .put_query_definition(id.as_deref(), &name, &query, &[log_group_name])
+                .await
+            {
+                Ok(_) => AppEvent::Notice(format!("Saved query {name} to CloudWatch Logs")),
+                Err(error) => AppEvent::Error(error.to_string()),
+            }
+        });
+    }
+
+    /// The Logs Insights queries to pick from, by label: those named in the config, those saved
+    /// in the app, and those saved in CloudWatch Logs.
+    fn query_choices(&self) -> Vec<(String, String)> {
+        self.configured_queries
+            .iter()
+            .chain(&self.saved_queries)
+            .map(|saved| (saved.name.clone(), saved.query.clone()))
+            .chain(self.query_definitions.iter().map(|query_definition| {
+                (
+                    format!("{} (CloudWatch)", query_definition.name),
+                    query_definition.query.clone(),
+                )
+            }))
+            .collect()
+    }
+
+    /// List the saved Logs Insights queries to run, loading those saved in CloudWatch Logs
+    /// again in the background.
+    fn open_query_picker(&mut self) {
+        let tab = self.tab();
+        if tab.insights_view.is_none() {
+            return;
+        }
+        let logs_client = tab.logs_client.clone();
+
+        let items = self
+            .query_choices()
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect();
+        self.open_picker(PickerKind::Query, items, "");
+        self.events.spawn(async move {
+            AppEvent::QueryDefinitionsLoaded(logs_client.get_all_query_definitions().await)
+        });
+    }
+
+    /// Leave Logs Insights mode, returning to the pane it was opened from.
+    fn close_insights(&mut self) {
+        self.tab_mut().insights_view = None;
+        self.tab_mut().focus = if self.tab().log_view.is_some() {
+            Focus::Logs
+        } else {
+            Focus::List
+        };
+    }
+
+    fn close_logs(&mut self) {
+        self.tab_mut().log_view = None;
+        self.tab_mut().focus = if self.tab().log_stream_list.is_some() {
+            Focus::LogStreams
+        } else {
+            Focus::List
+        };
+    }
+
+    /// Open the recent log events of the selected function or log group in a new tab.
+    fn open_tab(&mut self) {
+        if self.selected_log_group_name().is_none() {
+            return;
+        }
+
+        self.tabs.push(Tab::new(
+            self.logs_client.clone(),
+            self.active_region.clone(),
+        ));
+        self.tab = self.tabs.len() - 1;
+        self.open_logs();
+    }
+
+    /// Close the current tab, stopping its Live Tail session, or empty it if it is the last tab.
+    fn close_tab(&mut self) {
+        if self.tabs.len() == 1 {
+            self.tabs[0] = Tab::new(self.logs_client.clone(), self.active_region.clone());
+            return;
+        }
+
+        self.tabs.remove(self.tab);
+        self.tab = self.tab.min(self.tabs.len() - 1);
+    }
+
+    fn tab(&self) -> &Tab {
+        &self.tabs[self.tab]
+    }
+
+    fn tab_mut(&mut self) -> &mut Tab {
+        &mut self.tabs[self.tab]
+    }
+
+    fn list_state_mut(&mut self) -> &mut ListState {
+        match self.list_mode {
+            ListMode::Functions => &mut self.function_list.state,
+            ListMode::LogGroups => &mut self.log_group_list.state,
+            ListMode::Services => &mut self.service_list.state,
+            ListMode::StateMachines => &mut self.state_machine_list.state,
+            ListMode::Stages => &mut self.stage_list.state,
+            ListMode::Databases => &mut self.database_list.state,
+            ListMode::Projects => &mut self.project_list.state,
+        }
+    }
+
+    fn select_none(&mut self) {
+        self.list_state_mut().select(None);
+    }
+}
+
+/// The item at `row` of a list scrolled down to the item `offset`, given the height of every item.
+fn item_at_row(offset: usize, row: usize, heights: impl Iterator<Item = usize>) -> Option<usize> {
+    let mut top = 0;
+    for (i, height) in heights.enumerate().skip(offset) {
+        top += height;
+        if row < top {
+            return Some(i);
+        }
+    }
+    None
+}
@@ -0,0 +1,16 @@
+//! Copying text to the system clipboard, through the terminal.
+//!
+//! The OSC 52 escape sequence asks the terminal to set its clipboard, which works over SSH
+//! too, where the clipboard of the machine running the app isn't the user's.
+use std::io::{self, Write};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// Copy `text` to the clipboard of the terminal.
+///
+/// Terminals without OSC 52 support ignore the request, so success isn't guaranteed.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()
+}
@@ -0,0 +1,111 @@
+//! Exporting log events to files, as plain text, JSON Lines, or CSV.
+use std::io::{self, Write};
+use std::path::Path;
+
+use aws_logs_tui::aws::cloudwatch_logs::LogEvent;
+use flate2::{Compression, write::GzEncoder};
+use serde_json::json;
+use tokio::{fs::File, io::AsyncWriteExt};
+
+/// The format of an exported file, chosen by the extension of its path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// A log event per line, after its timestamp.
+    Text,
+    /// A JSON object per line, with the timestamp, log stream, and message of a log event.
+    JsonLines,
+    /// A header row, then a row per log event with its timestamp, log stream, and message.
+    Csv,
+}
+
+impl ExportFormat {
+    /// The format of the file at `path`, plain text unless it ends in `.jsonl` or `.csv`.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("jsonl") => ExportFormat::JsonLines,
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Text,
+        }
+    }
+
+    /// The contents of a file of `log_events`, each with its already formatted timestamp.
+    pub fn export<'a>(
+        self,
+        log_events: impl IntoIterator<Item = (String, &'a LogEvent)>,
+    ) -> String {
+        let mut contents = match self {
+            ExportFormat::Csv => "timestamp,log_stream_name,message\n".to_string(),
+            ExportFormat::Text | ExportFormat::JsonLines => String::new(),
+        };
+
+        for (timestamp, log_event) in log_events {
+            let message = log_event.message.trim_end();
+            let log_stream_name = log_event.log_stream_name.as_deref().unwrap_or_default();
+            let line = match self {
+                ExportFormat::Text => format!("{timestamp} {message}"),
+                ExportFormat::JsonLines => json!({
+                    "timestamp": timestamp,
+                    "log_stream_name": log_stream_name,
+                    "message": message,
+                })
+                .to_string(),
+                ExportFormat::Csv => [timestamp.as_str(), log_stream_name, message]
+                    .map(csv_field)
+                    .join(","),
+            };
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+
+        contents
+    }
+}
+
+/// The `field` of a CSV row, quoted if it has commas, quotes, or line breaks.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// A file written a part at a time, such as a page of log events at a time, gzipped if its
+/// path ends in `.gz`.
+pub struct ExportFile {
+    file: File,
+    // The gzip stream, whose compressed output is taken to write to the file after each part.
+    gzip: Option<GzEncoder<Vec<u8>>>,
+}
+
+impl ExportFile {
+    pub async fn create(path: &Path) -> io::Result<Self> {
+        let gzip = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("gz"))
+            .then(|| GzEncoder::new(Vec::new(), Compression::default()));
+        Ok(Self {
+            file: File::create(path).await?,
+            gzip,
+        })
+    }
+
+    pub async fn write(&mut self, contents: &str) -> io::Result<()> {
+        match &mut self.gzip {
+            Some(gzip) => {
+                gzip.write_all(contents.as_bytes())?;
+                let compressed = std::mem::take(gzip.get_mut());
+                self.file.write_all(&compressed).await
+            }
+            None => self.file.write_all(contents.as_bytes()).await,
+        }
+    }
+
+    /// Write the end of the gzip stream, if gzipped, and flush the file.
+    pub async fn finish(mut self) -> io::Result<()> {
+        if let Some(gzip) = self.gzip.take() {
+            self.file.write_all(&gzip.finish()?).await?;
+        }
+        self.file.flush().await
+    }
+}
@@ -0,0 +1,158 @@
+//! The JSON of a log message as a tree, whose objects and arrays are expanded and collapsed to
+//! explore deeply nested payloads a level at a time.
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+// Objects and arrays nested this deep start collapsed.
+const EXPANDED_DEPTH: usize = 2;
+
+/// A value of the tree as shown, on a row of its own.
+#[derive(Debug)]
+pub struct Row {
+    /// How many objects and arrays the value is nested in.
+    pub depth: usize,
+    /// The key of the value in its object, or index in its array, or `None` for the root.
+    pub key: Option<String>,
+    /// The value as JSON, or how many keys, or items, an object or array has.
+    pub value: String,
+    /// Whether an object or array is collapsed, or `None` for other values.
+    pub collapsed: Option<bool>,
+    // The keys, and indexes, of the value from the root.
+    path: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct JsonTree {
+    root: Value,
+    // The paths of the collapsed objects and arrays.
+    collapsed: HashSet<Vec<String>>,
+    /// The row selected.
+    pub selected: usize,
+}
+
+impl JsonTree {
+    pub fn new(root: Value) -> Self {
+        let mut tree = Self {
+            root,
+            collapsed: HashSet::new(),
+            selected: 0,
+        };
+        tree.collapsed = tree
+            .rows_of(|_| false)
+            .into_iter()
+            .filter(|row| row.collapsed.is_some() && row.depth >= EXPANDED_DEPTH)
+            .map(|row| row.path)
+            .collect();
+        tree
+    }
+
+    /// The rows of the values not in collapsed objects, or arrays.
+    pub fn rows(&self) -> Vec<Row> {
+        self.rows_of(|path| self.collapsed.contains(path))
+    }
+
+    /// Select the row at `index`, or the last row past them.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.rows().len().saturating_sub(1));
+    }
+
+    /// Expand the selected object, or array, or collapse it if expanded.
+    pub fn toggle(&mut self) {
+        if let Some(row) = self.selected_row()
+            && row.collapsed.is_some()
+            && !self.collapsed.remove(&row.path)
+        {
+            self.collapsed.insert(row.path);
+        }
+    }
+
+    /// Expand the selected object, or array.
+    pub fn expand(&mut self) {
+        if let Some(row) = self.selected_row() {
+            self.collapsed.remove(&row.path);
+        }
+    }
+
+    /// Collapse the selected object, or array, or select the one it is in if already collapsed,
+    /// or not one.
+    pub fn collapse(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+
+        if row.collapsed == Some(false) {
+            self.collapsed.insert(row.path);
+        } else if let Some((_, parent)) = row.path.split_last()
+            && let Some(index) = self
+                .rows()
+                .iter()
+                .position(|row| row.path.as_slice() == parent)
+        {
+            self.selected = index;
+        }
+    }
+
+    fn selected_row(&self) -> Option<Row> {
+        self.rows().into_iter().nth(self.selected)
+    }
+
+    fn rows_of(&self, collapsed: impl Fn(&Vec<String>) -> bool) -> Vec<Row> {
+        let mut rows = Vec::new();
+        push_rows(&self.root, None, &mut Vec::new(), &collapsed, &mut rows);
+        rows
+    }
+}
+
+fn push_rows(
+    value: &Value,
+    key: Option<String>,
+    path: &mut Vec<String>,
+    collapsed: &impl Fn(&Vec<String>) -> bool,
+    rows: &mut Vec<Row>,
+) {
+    let (children, value): (Vec<(String, &Value)>, String) = match value {
+        Value::Object(map) if !map.is_empty() => (
+            map.iter()
+                .map(|(key, value)| (key.clone(), value))
+                .collect(),
+            count(map.len(), "key"),
+        ),
+        Value::Array(items) if !items.is_empty() => (
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (index.to_string(), value))
+                .collect(),
+            count(items.len(), "item"),
+        ),
+        // Empty objects, and arrays, have nothing to expand.
+        _ => (Vec::new(), value.to_string()),
+    };
+
+    let is_collapsed = !children.is_empty() && collapsed(path);
+    rows.push(Row {
+        depth: path.len(),
+        key,
+        value,
+        collapsed: (!children.is_empty()).then_some(is_collapsed),
+        path: path.clone(),
+    });
+    if is_collapsed {
+        return;
+    }
+
+    for (key, child) in children {
+        path.push(key.clone());
+        push_rows(child, Some(key), path, collapsed, rows);
+        path.pop();
+    }
+}
+
+fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{n} {noun}s")
+    }
+}
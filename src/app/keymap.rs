@@ -0,0 +1,919 @@
+//! Keybindings of the TUI.
+//!
+//! Every key press is mapped to an [`Action`] through the bindings of the current [`Mode`],
+//! so the help overlay lists exactly the keys that are handled.
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::log_level::LogLevel;
+
+/// Something the user can do, whether by key press or otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Back,
+    Confirm,
+    Help,
+    CommandPalette,
+    Next,
+    Previous,
+    First,
+    Last,
+    PageDown,
+    PageUp,
+    SelectNone,
+    OpenLogStreams,
+    Mark,
+    OpenLogs,
+    LiveTail,
+    ToggleFollow,
+    Filter,
+    Find,
+    FilterTags,
+    SortFunctions,
+    GroupList,
+    FilterRuntime,
+    FilterArchitecture,
+    Insights,
+    EditQuery,
+    BuildQuery,
+    EditBuiltQuery,
+    SaveQuery,
+    PickQuery,
+    Refresh,
+    ToggleListMode,
+    SwitchProfile,
+    SwitchRegion,
+    PickTimeRange,
+    PickRecent,
+    ToggleMouse,
+    GrowList,
+    ShrinkList,
+    CollapseList,
+    TogglePrettyJson,
+    ToggleTable,
+    ToggleWrap,
+    AcknowledgeAlert,
+    ToggleLineNumbers,
+    ToggleTimestamps,
+    ToggleStreamNames,
+    ScrollLeft,
+    ScrollRight,
+    ToggleVolume,
+    JumpToPeak,
+    Search,
+    SearchNext,
+    SearchPrevious,
+    FilterRegex,
+    ToggleLevel(LogLevel),
+    ToggleColdStarts,
+    GroupInvocations,
+    OpenInvocation,
+    ShowFlowLogs,
+    SortByColumn,
+    ReverseSort,
+    FilterFlowLogs,
+    ShowTrace,
+    ShowEvent,
+    ToggleJson,
+    ExpandJson,
+    CollapseJson,
+    BrowseExecutions,
+    SwitchLogs,
+    ShowCloudTrail,
+    FilterTrailResource,
+    FilterTrailEvents,
+    OpenExecution,
+    OpenExecutionLogs,
+    BrowseEventSources,
+    BrowseFailedEvents,
+    OpenFailedEventLogs,
+    BrowseBuilds,
+    OpenBuild,
+    ToggleFavorite,
+    Export,
+    ExportToS3,
+    Download,
+    SearchLogGroups,
+    OpenConsole,
+    ShowEnvironment,
+    BrowseVersions,
+    Invoke,
+    DryRunInvoke,
+    SetRetention,
+    RevealValues,
+    CopyMessage,
+    CopyLogEvent,
+    CopyCurl,
+    ToggleTimeZone,
+    ToggleRelativeTime,
+    NewTab,
+    CloseTab,
+    NextTab,
+    PreviousTab,
+    Retry,
+    SsoLogin,
+}
+
+impl Action {
+    /// What the action does, for the help overlay.
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::Back => "Go back",
+            Action::Confirm => "Confirm",
+            Action::Help => "Show keybindings",
+            Action::CommandPalette => "Open the command palette",
+            Action::Next => "Move down",
+            Action::Previous => "Move up",
+            Action::First => "Go to top",
+            Action::Last => "Go to bottom",
+            Action::PageDown => "Move down half a page",
+            Action::PageUp => "Move up half a page",
+            Action::SelectNone => "Unselect",
+            Action::OpenLogStreams => "Browse log streams",
+            Action::Mark => "Mark to view, or tail, together with the others marked",
+            Action::OpenLogs => {
+                "View recent logs, or expand the selected stack, or prefix, or collapse it"
+            }
+            Action::LiveTail => "Live tail logs",
+            Action::ToggleFollow => "Pause following new log events of Live Tail, or follow again",
+            Action::Filter => "Filter logs with a filter pattern",
+            Action::FilterTags => "Filter functions by tag",
+            Action::SortFunctions => "Sort functions by date or code size, and log groups by size",
+            Action::GroupList => {
+                "Group functions by CloudFormation stack, and log groups by prefix, or not"
+            }
+            Action::FilterRuntime => "Show only functions of the next runtime",
+            Action::FilterArchitecture => "Show only functions of the next architecture",
+            Action::Find => "Fuzzy find functions",
+            Action::Insights => "Query logs with Logs Insights",
+            Action::EditQuery => "Edit the query",
+            Action::BuildQuery => "Build the query a command at a time, without knowing the syntax",
+            Action::EditBuiltQuery => "Edit the query being built as text instead",
+            Action::SaveQuery => "Save the query by name",
+            Action::PickQuery => "Run a saved query",
+            Action::Refresh => "Refresh functions",
+            Action::ToggleListMode => "Switch what is listed, such as functions or log groups",
+            Action::SwitchProfile => "Switch AWS profile",
+            Action::SwitchRegion => "Switch AWS region",
+            Action::PickTimeRange => "Pick the time range of log events",
+            Action::PickRecent => "Switch to a recently viewed log group",
+            Action::ToggleMouse => "Capture the mouse, or leave it to select text",
+            Action::GrowList => "Grow the list, shrinking the pane under it",
+            Action::ShrinkList => "Shrink the list, growing the pane under it",
+            Action::CollapseList => "Collapse the list, or restore it",
+            Action::TogglePrettyJson => "Pretty-print JSON log messages, or not",
+            Action::ToggleTable => "Show the JSON fields of log messages as a table, or not",
+            Action::ToggleWrap => "Wrap long log messages, or cut them off",
+            Action::AcknowledgeAlert => "Acknowledge the alert of tailed log events, hiding it",
+            Action::ToggleLineNumbers => "Show line numbers left of log messages, or hide them",
+            Action::ToggleTimestamps => "Show timestamps left of log messages, or hide them",
+            Action::ToggleStreamNames => {
+                "Show the log streams of log messages left of them, or hide them"
+            }
+            Action::ScrollLeft => "Scroll log messages left, when cut off",
+            Action::ScrollRight => "Scroll log messages right, when cut off",
+            Action::ToggleVolume => "Show the volume of log events over time, or hide it",
+            Action::JumpToPeak => "Go to the log events of the busiest time",
+            Action::Search => "Search the loaded log events",
+            Action::SearchNext => "Go to the next search match",
+            Action::SearchPrevious => "Go to the previous search match",
+            Action::FilterRegex => "Show only loaded log events matching a regex",
+            Action::Export => "Export the shown log events to a file",
+            Action::ExportToS3 => "Export the log group over the time range to an S3 bucket",
+            Action::Download => "Download the log group over the time range to a file",
+            Action::SearchLogGroups => {
+                "Search the marked log groups, or favorite functions, at once"
+            }
+            Action::OpenConsole => "Open in the AWS console, in the browser",
+            Action::ShowEnvironment => "Show the environment variables of the function",
+            Action::BrowseVersions => "Browse log streams of a version, or alias, of the function",
+            Action::Invoke => "Invoke the function, then tail the logs of the invocation",
+            Action::DryRunInvoke => "Check that the function can be invoked, without running it",
+            Action::SetRetention => "Set how long the log group keeps its log events",
+            Action::RevealValues => "Reveal the values, or mask them again",
+            Action::CopyMessage => "Copy the log message",
+            Action::CopyLogEvent => "Copy the log message, after its timestamp",
+            Action::CopyCurl => "Copy a curl command requesting the Function URL of the function",
+            Action::ToggleColdStarts => "Show only cold starts, or every log event",
+            Action::GroupInvocations => "Group log events by the invocation that logged them",
+            Action::OpenInvocation => "View the log events of the invocation",
+            Action::ShowFlowLogs => "Show VPC flow log records as a table, or as log events",
+            Action::SortByColumn => "Sort the rows by the next column",
+            Action::ReverseSort => "Reverse the order the rows are sorted in",
+            Action::FilterFlowLogs => "Show only flow log records with fields like action=REJECT",
+            Action::ShowTrace => "Show the X-Ray trace of the log event, or invocation",
+            Action::ShowEvent => "Show the whole log event, with its JSON pretty-printed",
+            Action::ToggleJson => "Expand the selected JSON object, or array, or collapse it",
+            Action::ExpandJson => "Expand the selected JSON object, or array",
+            Action::CollapseJson => "Collapse the selected JSON object, or go to the one it is in",
+            Action::BrowseExecutions => "Browse the recent executions of the state machine",
+            Action::SwitchLogs => "Switch between the logs of stages, or of databases",
+            Action::ShowCloudTrail => "Show who changed the resource, from its CloudTrail events",
+            Action::FilterTrailResource => "Show only CloudTrail events of a resource",
+            Action::FilterTrailEvents => "Show only CloudTrail events whose name contains text",
+            Action::OpenExecution => "Show the event history of the execution",
+            Action::OpenExecutionLogs => {
+                "View logs of the functions the execution invoked, in new tabs"
+            }
+            Action::BrowseEventSources => {
+                "Browse the event source mappings of the function, such as SQS queues"
+            }
+            Action::BrowseFailedEvents => {
+                "Peek at the failed events in the dead-letter queue of the function"
+            }
+            Action::OpenFailedEventLogs => "View the logs of the invocation that failed the event",
+            Action::BrowseBuilds => "Browse the recent builds of the CodeBuild project",
+            Action::OpenBuild => "View the logs of the build, tailing them while it runs",
+            Action::ToggleFavorite => "Star the function as a favorite, listed first, or unstar it",
+            Action::ToggleLevel(level) => match level {
+                LogLevel::Error => "Show or hide ERROR log events",
+                LogLevel::Warn => "Show or hide WARN log events",
+                LogLevel::Info => "Show or hide INFO log events",
+                LogLevel::Debug => "Show or hide DEBUG log events",
+            },
+            Action::ToggleTimeZone => "Show times in UTC or local time",
+            Action::ToggleRelativeTime => "Show times as ISO-8601 or relative times",
+            Action::NewTab => "View recent logs in a new tab",
+            Action::CloseTab => "Close the tab",
+            Action::NextTab => "Next tab",
+            Action::PreviousTab => "Previous tab",
+            Action::Retry => "Retry",
+            Action::SsoLogin => "Sign in again with aws sso login, then retry",
+        }
+    }
+
+    /// Whether the action is worth running from the command palette, unlike moving around.
+    fn is_command(self) -> bool {
+        !matches!(
+            self,
+            Action::Back
+                | Action::Confirm
+                | Action::CommandPalette
+                | Action::Next
+                | Action::Previous
+                | Action::First
+                | Action::Last
+                | Action::PageDown
+                | Action::PageUp
+        )
+    }
+}
+
+/// What is receiving key presses, each with its own keybindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    List,
+    LogStreams,
+    Logs,
+    Invocations,
+    FlowLogs,
+    Insights,
+    /// Editing text, such as a filter pattern, where other keys edit the text.
+    Input,
+    Picker,
+    Palette,
+    /// Confirming a change to AWS resources, before it is made.
+    Confirmation,
+    Environment,
+    Trace,
+    Event,
+    Executions,
+    EventSources,
+    FailedEvents,
+    Builds,
+    CloudTrail,
+    ErrorScreen,
+    Help,
+}
+
+impl Mode {
+    pub const ALL: [Mode; 20] = [
+        Mode::List,
+        Mode::LogStreams,
+        Mode::Logs,
+        Mode::Invocations,
+        Mode::FlowLogs,
+        Mode::Insights,
+        Mode::Input,
+        Mode::Picker,
+        Mode::Palette,
+        Mode::Confirmation,
+        Mode::Environment,
+        Mode::Trace,
+        Mode::Event,
+        Mode::Executions,
+        Mode::EventSources,
+        Mode::FailedEvents,
+        Mode::Builds,
+        Mode::CloudTrail,
+        Mode::ErrorScreen,
+        Mode::Help,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Mode::List => "Functions & Log Groups",
+            Mode::LogStreams => "Log Streams",
+            Mode::Logs => "Logs",
+            Mode::Invocations => "Invocations",
+            Mode::FlowLogs => "VPC Flow Logs",
+            Mode::Insights => "Logs Insights",
+            Mode::Input => "Text Input",
+            Mode::Picker => "Pickers",
+            Mode::Palette => "Command Palette",
+            Mode::Confirmation => "Confirmations",
+            Mode::Environment => "Environment Variables",
+            Mode::Trace => "X-Ray Traces",
+            Mode::Event => "Log Events",
+            Mode::Executions => "Step Functions Executions",
+            Mode::EventSources => "Event Source Mappings",
+            Mode::FailedEvents => "Failed Events",
+            Mode::Builds => "CodeBuild Builds",
+            Mode::CloudTrail => "CloudTrail Events",
+            Mode::ErrorScreen => "Errors",
+            Mode::Help => "Help",
+        }
+    }
+
+    /// The keybindings of the mode, in the order they are listed in the help overlay.
+    pub fn bindings(self) -> &'static [Binding] {
+        match self {
+            Mode::List => LIST_BINDINGS,
+            Mode::LogStreams => LOG_STREAMS_BINDINGS,
+            Mode::Logs => LOGS_BINDINGS,
+            Mode::Invocations => INVOCATIONS_BINDINGS,
+            Mode::FlowLogs => FLOW_LOGS_BINDINGS,
+            Mode::Insights => INSIGHTS_BINDINGS,
+            Mode::Input => INPUT_BINDINGS,
+            Mode::Picker => PICKER_BINDINGS,
+            Mode::Palette => PALETTE_BINDINGS,
+            Mode::Confirmation => CONFIRMATION_BINDINGS,
+            Mode::Environment => ENVIRONMENT_BINDINGS,
+            Mode::Trace => TRACE_BINDINGS,
+            Mode::Event => EVENT_BINDINGS,
+            Mode::Executions => EXECUTIONS_BINDINGS,
+            Mode::EventSources => EVENT_SOURCES_BINDINGS,
+            Mode::FailedEvents => FAILED_EVENTS_BINDINGS,
+            Mode::Builds => BUILDS_BINDINGS,
+            Mode::CloudTrail => CLOUDTRAIL_BINDINGS,
+            Mode::ErrorScreen => ERROR_SCREEN_BINDINGS,
+            Mode::Help => HELP_BINDINGS,
+        }
+    }
+
+    /// Whether keys that aren't bound to an action edit text.
+    pub fn edits_text(self) -> bool {
+        matches!(self, Mode::Input | Mode::Palette)
+    }
+
+    /// The actions of the mode that can be run from the command palette.
+    pub fn commands(self) -> Vec<Action> {
+        self.bindings()
+            .iter()
+            .map(|binding| binding.action)
+            .filter(|action| action.is_command())
+            .collect()
+    }
+
+    /// The action bound to `key` in this mode, if any.
+    pub fn action(self, key: KeyEvent) -> Option<Action> {
+        self.bindings()
+            .iter()
+            .find(|binding| binding.keys.iter().any(|bound| bound.matches(key)))
+            .map(|binding| binding.action)
+    }
+
+    /// A key bound more than once in this mode, if any, of which only the first binding would
+    /// ever run.
+    pub fn duplicate_key(self) -> Option<Key> {
+        let keys: Vec<Key> = self
+            .bindings()
+            .iter()
+            .flat_map(|binding| binding.keys.iter().copied())
+            .collect();
+        keys.iter()
+            .enumerate()
+            .find(|&(i, key)| keys[..i].contains(key))
+            .map(|(_, &key)| key)
+    }
+}
+
+/// A key, with the modifiers that must be held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Key {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl Key {
+    const fn new(code: KeyCode) -> Self {
+        Self {
+            code,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    const fn char(c: char) -> Self {
+        Self::new(KeyCode::Char(c))
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self {
+            code: KeyCode::Char(c),
+            modifiers: KeyModifiers::CONTROL,
+        }
+    }
+
+    fn matches(self, key: KeyEvent) -> bool {
+        // Shift is implied by the character itself, such as `G`.
+        let modifiers = key.modifiers - KeyModifiers::SHIFT;
+        self.code == key.code && self.modifiers == modifiers
+    }
+
+    /// The key as shown in the help overlay, such as `Ctrl-d` or `↑`.
+    pub fn label(self) -> String {
+        let code = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::BackTab => "Shift-Tab".to_string(),
+            code => code.to_string(),
+        };
+
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            format!("Ctrl-{code}")
+        } else {
+            code
+        }
+    }
+}
+
+/// Keys bound to an action.
+#[derive(Debug)]
+pub struct Binding {
+    pub keys: &'static [Key],
+    pub action: Action,
+}
+
+const fn bind(keys: &'static [Key], action: Action) -> Binding {
+    Binding { keys, action }
+}
+
+const QUIT: Binding = bind(&[Key::char('q')], Action::Quit);
+const HELP: Binding = bind(&[Key::char('?')], Action::Help);
+const COMMAND_PALETTE: Binding = bind(&[Key::char(':')], Action::CommandPalette);
+const TOGGLE_MOUSE: Binding = bind(&[Key::char('m')], Action::ToggleMouse);
+const GROW_LIST: Binding = bind(&[Key::char('+'), Key::char('=')], Action::GrowList);
+const SHRINK_LIST: Binding = bind(&[Key::char('-')], Action::ShrinkList);
+const COLLAPSE_LIST: Binding = bind(&[Key::char('z')], Action::CollapseList);
+const PICK_TIME_RANGE: Binding = bind(&[Key::char('T')], Action::PickTimeRange);
+const PICK_RECENT: Binding = bind(&[Key::char('H')], Action::PickRecent);
+const TOGGLE_TIME_ZONE: Binding = bind(&[Key::char('u')], Action::ToggleTimeZone);
+const TOGGLE_RELATIVE_TIME: Binding = bind(&[Key::char('a')], Action::ToggleRelativeTime);
+const NEW_TAB: Binding = bind(&[Key::char('o')], Action::NewTab);
+const CLOSE_TAB: Binding = bind(&[Key::char('x')], Action::CloseTab);
+const OPEN_CONSOLE: Binding = bind(&[Key::char('O')], Action::OpenConsole);
+const MARK: Binding = bind(&[Key::char(' ')], Action::Mark);
+const ACKNOWLEDGE_ALERT: Binding = bind(&[Key::char('!')], Action::AcknowledgeAlert);
+const NEXT_TAB: Binding = bind(&[Key::new(KeyCode::Tab)], Action::NextTab);
+const PREVIOUS_TAB: Binding = bind(&[Key::new(KeyCode::BackTab)], Action::PreviousTab);
+const NEXT: Binding = bind(&[Key::char('j'), Key::new(KeyCode::Down)], Action::Next);
+const PREVIOUS: Binding = bind(&[Key::char('k'), Key::new(KeyCode::Up)], Action::Previous);
+const FIRST: Binding = bind(&[Key::char('g'), Key::new(KeyCode::Home)], Action::First);
+const LAST: Binding = bind(&[Key::char('G'), Key::new(KeyCode::End)], Action::Last);
+const PAGE_DOWN: Binding = bind(
+    &[Key::ctrl('d'), Key::new(KeyCode::PageDown)],
+    Action::PageDown,
+);
+const PAGE_UP: Binding = bind(&[Key::ctrl('u'), Key::new(KeyCode::PageUp)], Action::PageUp);
+
+const LIST_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    HELP,
+    COMMAND_PALETTE,
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(
+        &[Key::char('h'), Key::new(KeyCode::Left)],
+        Action::SelectNone,
+    ),
+    bind(
+        &[Key::char('l'), Key::new(KeyCode::Right)],
+        Action::OpenLogStreams,
+    ),
+    bind(&[Key::new(KeyCode::Enter)], Action::OpenLogs),
+    bind(&[Key::char('t')], Action::LiveTail),
+    bind(&[Key::char('f')], Action::Filter),
+    bind(&[Key::char('/')], Action::Find),
+    bind(&[Key::char('#')], Action::FilterTags),
+    bind(&[Key::char('s')], Action::SortFunctions),
+    bind(&[Key::char('c')], Action::GroupList),
+    bind(&[Key::char('u')], Action::FilterRuntime),
+    bind(&[Key::char('a')], Action::FilterArchitecture),
+    bind(&[Key::char('e')], Action::ShowEnvironment),
+    bind(&[Key::char('U')], Action::CopyCurl),
+    bind(&[Key::char('V')], Action::BrowseVersions),
+    bind(&[Key::char('E')], Action::BrowseExecutions),
+    bind(&[Key::char('M')], Action::BrowseEventSources),
+    bind(&[Key::char('Q')], Action::BrowseFailedEvents),
+    bind(&[Key::char('B')], Action::BrowseBuilds),
+    bind(&[Key::char('b')], Action::ToggleFavorite),
+    MARK,
+    bind(&[Key::char('A')], Action::SwitchLogs),
+    bind(&[Key::char('C')], Action::ShowCloudTrail),
+    bind(&[Key::char('I')], Action::Invoke),
+    bind(&[Key::char('N')], Action::DryRunInvoke),
+    bind(&[Key::char('L')], Action::SetRetention),
+    bind(&[Key::char('S')], Action::ExportToS3),
+    bind(&[Key::char('D')], Action::Download),
+    bind(&[Key::char('F')], Action::SearchLogGroups),
+    bind(&[Key::char('i')], Action::Insights),
+    bind(&[Key::char('r')], Action::Refresh),
+    bind(&[Key::char('v')], Action::ToggleListMode),
+    bind(&[Key::char('P')], Action::SwitchProfile),
+    bind(&[Key::char('R')], Action::SwitchRegion),
+    ACKNOWLEDGE_ALERT,
+    PICK_TIME_RANGE,
+    PICK_RECENT,
+    OPEN_CONSOLE,
+    TOGGLE_MOUSE,
+    GROW_LIST,
+    SHRINK_LIST,
+    COLLAPSE_LIST,
+    NEW_TAB,
+    CLOSE_TAB,
+    NEXT_TAB,
+    PREVIOUS_TAB,
+];
+
+const LOG_STREAMS_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(
+        &[
+            Key::new(KeyCode::Esc),
+            Key::char('h'),
+            Key::new(KeyCode::Left),
+        ],
+        Action::Back,
+    ),
+    HELP,
+    COMMAND_PALETTE,
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(&[Key::new(KeyCode::Enter)], Action::OpenLogs),
+    bind(&[Key::char('t')], Action::LiveTail),
+    MARK,
+    PICK_TIME_RANGE,
+    PICK_RECENT,
+    TOGGLE_TIME_ZONE,
+    TOGGLE_RELATIVE_TIME,
+    OPEN_CONSOLE,
+    TOGGLE_MOUSE,
+    GROW_LIST,
+    SHRINK_LIST,
+    COLLAPSE_LIST,
+    CLOSE_TAB,
+    NEXT_TAB,
+    PREVIOUS_TAB,
+];
+
+const LOGS_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    HELP,
+    COMMAND_PALETTE,
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(&[Key::new(KeyCode::Enter)], Action::ShowEvent),
+    bind(&[Key::char('t')], Action::LiveTail),
+    bind(&[Key::char(' ')], Action::ToggleFollow),
+    bind(&[Key::char('f')], Action::Filter),
+    bind(&[Key::char('i')], Action::Insights),
+    bind(&[Key::char('J')], Action::TogglePrettyJson),
+    bind(&[Key::char('s')], Action::ToggleVolume),
+    bind(&[Key::char('p')], Action::JumpToPeak),
+    bind(&[Key::char('/')], Action::Search),
+    bind(&[Key::char('n')], Action::SearchNext),
+    bind(&[Key::char('N')], Action::SearchPrevious),
+    bind(&[Key::char('&')], Action::FilterRegex),
+    bind(&[Key::char('w')], Action::ToggleWrap),
+    bind(&[Key::char('C')], Action::ToggleTable),
+    ACKNOWLEDGE_ALERT,
+    bind(&[Key::char('L')], Action::ToggleLineNumbers),
+    bind(&[Key::char('D')], Action::ToggleTimestamps),
+    bind(&[Key::char('S')], Action::ToggleStreamNames),
+    bind(
+        &[Key::char('h'), Key::new(KeyCode::Left)],
+        Action::ScrollLeft,
+    ),
+    bind(
+        &[Key::char('l'), Key::new(KeyCode::Right)],
+        Action::ScrollRight,
+    ),
+    bind(&[Key::char('e')], Action::Export),
+    bind(&[Key::char('y')], Action::CopyMessage),
+    bind(&[Key::char('Y')], Action::CopyLogEvent),
+    bind(&[Key::char('1')], Action::ToggleLevel(LogLevel::Error)),
+    bind(&[Key::char('2')], Action::ToggleLevel(LogLevel::Warn)),
+    bind(&[Key::char('3')], Action::ToggleLevel(LogLevel::Info)),
+    bind(&[Key::char('4')], Action::ToggleLevel(LogLevel::Debug)),
+    bind(&[Key::char('c')], Action::ToggleColdStarts),
+    bind(&[Key::char('I')], Action::GroupInvocations),
+    bind(&[Key::char('W')], Action::ShowFlowLogs),
+    bind(&[Key::char('X')], Action::ShowTrace),
+    PICK_TIME_RANGE,
+    PICK_RECENT,
+    TOGGLE_TIME_ZONE,
+    TOGGLE_RELATIVE_TIME,
+    OPEN_CONSOLE,
+    TOGGLE_MOUSE,
+    GROW_LIST,
+    SHRINK_LIST,
+    COLLAPSE_LIST,
+    CLOSE_TAB,
+    NEXT_TAB,
+    PREVIOUS_TAB,
+];
+
+const INVOCATIONS_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    HELP,
+    COMMAND_PALETTE,
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(
+        &[
+            Key::new(KeyCode::Enter),
+            Key::char('l'),
+            Key::new(KeyCode::Right),
+        ],
+        Action::OpenInvocation,
+    ),
+    bind(&[Key::char('X')], Action::ShowTrace),
+    PICK_TIME_RANGE,
+    PICK_RECENT,
+    TOGGLE_TIME_ZONE,
+    TOGGLE_RELATIVE_TIME,
+    TOGGLE_MOUSE,
+    GROW_LIST,
+    SHRINK_LIST,
+    COLLAPSE_LIST,
+    CLOSE_TAB,
+    NEXT_TAB,
+    PREVIOUS_TAB,
+];
+
+const FLOW_LOGS_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(
+        &[
+            Key::new(KeyCode::Esc),
+            Key::char('h'),
+            Key::new(KeyCode::Left),
+        ],
+        Action::Back,
+    ),
+    HELP,
+    COMMAND_PALETTE,
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(&[Key::char('W')], Action::ShowFlowLogs),
+    bind(&[Key::char('s')], Action::SortByColumn),
+    bind(&[Key::char('S')], Action::ReverseSort),
+    bind(&[Key::char('f')], Action::FilterFlowLogs),
+    PICK_TIME_RANGE,
+    PICK_RECENT,
+    TOGGLE_TIME_ZONE,
+    TOGGLE_RELATIVE_TIME,
+    TOGGLE_MOUSE,
+    GROW_LIST,
+    SHRINK_LIST,
+    COLLAPSE_LIST,
+    CLOSE_TAB,
+    NEXT_TAB,
+    PREVIOUS_TAB,
+];
+
+const INSIGHTS_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    HELP,
+    COMMAND_PALETTE,
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(&[Key::char('e'), Key::char('i')], Action::EditQuery),
+    bind(&[Key::new(KeyCode::Enter)], Action::ShowEvent),
+    bind(&[Key::char('s')], Action::SortByColumn),
+    bind(&[Key::char('r')], Action::ReverseSort),
+    bind(&[Key::char('b')], Action::BuildQuery),
+    bind(&[Key::char('S')], Action::SaveQuery),
+    bind(&[Key::char('Q')], Action::PickQuery),
+    PICK_TIME_RANGE,
+    PICK_RECENT,
+    OPEN_CONSOLE,
+    TOGGLE_MOUSE,
+    NEXT_TAB,
+    PREVIOUS_TAB,
+];
+
+const INPUT_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Enter)], Action::Confirm),
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    bind(&[Key::new(KeyCode::Down)], Action::Next),
+    bind(&[Key::new(KeyCode::Up)], Action::Previous),
+    bind(&[Key::new(KeyCode::Tab)], Action::EditBuiltQuery),
+];
+
+const PICKER_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Enter)], Action::Confirm),
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+];
+
+const PALETTE_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Enter)], Action::Confirm),
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+    bind(&[Key::new(KeyCode::Down)], Action::Next),
+    bind(&[Key::new(KeyCode::Up)], Action::Previous),
+];
+
+const ENVIRONMENT_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    bind(&[Key::char('v')], Action::RevealValues),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+];
+
+const TRACE_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+];
+
+const EVENT_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    bind(
+        &[Key::new(KeyCode::Enter), Key::char(' ')],
+        Action::ToggleJson,
+    ),
+    bind(
+        &[Key::char('l'), Key::new(KeyCode::Right)],
+        Action::ExpandJson,
+    ),
+    bind(
+        &[Key::char('h'), Key::new(KeyCode::Left)],
+        Action::CollapseJson,
+    ),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+];
+
+const EXECUTIONS_BINDINGS: &[Binding] = &[
+    bind(
+        &[
+            Key::new(KeyCode::Esc),
+            Key::char('q'),
+            Key::char('h'),
+            Key::new(KeyCode::Left),
+        ],
+        Action::Back,
+    ),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(
+        &[
+            Key::new(KeyCode::Enter),
+            Key::char('l'),
+            Key::new(KeyCode::Right),
+        ],
+        Action::OpenExecution,
+    ),
+    bind(&[Key::char('L')], Action::OpenExecutionLogs),
+    OPEN_CONSOLE,
+];
+
+const EVENT_SOURCES_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+];
+
+const FAILED_EVENTS_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(&[Key::new(KeyCode::Enter)], Action::ShowEvent),
+    bind(
+        &[Key::char('L'), Key::char('l'), Key::new(KeyCode::Right)],
+        Action::OpenFailedEventLogs,
+    ),
+];
+
+const BUILDS_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(
+        &[
+            Key::new(KeyCode::Enter),
+            Key::char('l'),
+            Key::new(KeyCode::Right),
+        ],
+        Action::OpenBuild,
+    ),
+    OPEN_CONSOLE,
+];
+
+const CLOUDTRAIL_BINDINGS: &[Binding] = &[
+    bind(&[Key::new(KeyCode::Esc), Key::char('q')], Action::Back),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+    bind(&[Key::char('f')], Action::FilterTrailResource),
+    bind(&[Key::char('/')], Action::FilterTrailEvents),
+];
+
+const CONFIRMATION_BINDINGS: &[Binding] = &[
+    bind(&[Key::char('y'), Key::new(KeyCode::Enter)], Action::Confirm),
+    bind(&[Key::char('n'), Key::new(KeyCode::Esc)], Action::Back),
+];
+
+const ERROR_SCREEN_BINDINGS: &[Binding] = &[
+    QUIT,
+    bind(&[Key::char('r')], Action::Retry),
+    bind(&[Key::char('l')], Action::SsoLogin),
+    bind(&[Key::new(KeyCode::Esc)], Action::Back),
+];
+
+const HELP_BINDINGS: &[Binding] = &[
+    bind(
+        &[Key::new(KeyCode::Esc), Key::char('?'), Key::char('q')],
+        Action::Back,
+    ),
+    NEXT,
+    PREVIOUS,
+    FIRST,
+    LAST,
+    PAGE_DOWN,
+    PAGE_UP,
+];
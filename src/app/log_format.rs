@@ -0,0 +1,198 @@
+//! Formatting of log messages for the log viewer.
+use std::ops::Range;
+
+use aws_logs_tui::aws;
+use serde_json::Value;
+
+use crate::config::ColumnConfig;
+
+/// Widths of the namespace, pod, and container columns of lines logged by Kubernetes containers.
+pub const KUBERNETES_NAMESPACE_WIDTH: usize = 16;
+pub const KUBERNETES_POD_WIDTH: usize = 32;
+pub const KUBERNETES_CONTAINER_WIDTH: usize = 16;
+/// Width of all the Kubernetes columns, each followed by a space.
+pub const KUBERNETES_COLUMNS_WIDTH: usize =
+    KUBERNETES_NAMESPACE_WIDTH + KUBERNETES_POD_WIDTH + KUBERNETES_CONTAINER_WIDTH + 3;
+
+/// Width of JSON columns without a width of their own.
+pub const DEFAULT_COLUMN_WIDTH: usize = 16;
+
+/// The message with its JSON pretty-printed over several lines, or `None` if it has no JSON.
+///
+/// Any text before the JSON, such as the timestamp, request ID, and level that Lambda runtimes
+/// log before it, is kept on its own line.
+pub fn pretty_json(message: &str) -> Option<String> {
+    let (prefix, value) = parse_json(message)?;
+    let pretty = serde_json::to_string_pretty(&value).ok()?;
+    Some(match prefix {
+        "" => pretty,
+        prefix => format!("{prefix}\n{pretty}"),
+    })
+}
+
+/// The text before the JSON of the message, and the JSON, or `None` if it has no JSON.
+pub fn parse_json(message: &str) -> Option<(&str, Value)> {
+    let message = message.trim_end();
+
+    // The prefix may have brackets of its own, such as `[INFO]`, so try every opening bracket.
+    message.match_indices(['{', '[']).find_map(|(start, _)| {
+        let value = serde_json::from_str(&message[start..]).ok()?;
+        Some((message[..start].trim_end(), value))
+    })
+}
+
+/// The fields of the JSON of the message at the paths of the `columns`, as a row of a table, or
+/// `None` if it has no JSON, or none of the fields.
+///
+/// Each field is padded, or cut off, to the width of its column, except the last.
+pub fn json_columns(message: &str, columns: &[ColumnConfig]) -> Option<String> {
+    let (_, value) = parse_json(message)?;
+    let fields: Vec<Option<String>> = columns
+        .iter()
+        .map(|column| json_field(&value, &column.path))
+        .collect();
+    if fields.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let cells: Vec<String> = fields
+        .into_iter()
+        .zip(columns)
+        .enumerate()
+        .map(|(i, (field, column))| {
+            let field = field.unwrap_or_else(|| "-".to_string());
+            if i + 1 == columns.len() {
+                return field;
+            }
+            let width = column.width.unwrap_or(DEFAULT_COLUMN_WIDTH);
+            let field = if field.chars().count() > width {
+                let cut: String = field.chars().take(width.saturating_sub(1)).collect();
+                format!("{cut}…")
+            } else {
+                field
+            };
+            format!("{field:<width$}")
+        })
+        .collect();
+    Some(cells.join(" │ "))
+}
+
+/// The field of the JSON at the dot-separated `path`, such as `request.id`, or `items.0` for an
+/// item of an array, with strings unquoted.
+fn json_field(value: &Value, path: &str) -> Option<String> {
+    let field = path.split('.').try_fold(value, |value, key| match value {
+        Value::Array(items) => items.get(key.parse::<usize>().ok()?),
+        _ => value.get(key),
+    })?;
+    Some(match field {
+        Value::String(string) => string.clone(),
+        field => field.to_string(),
+    })
+}
+
+/// A compact summary of a message with a known format, such as a Lambda `REPORT` line, or
+/// `None` for other messages.
+pub fn summary(message: &str) -> Option<String> {
+    report_summary(message)
+        .or_else(|| access_log_summary(message))
+        .or_else(|| slow_query_summary(message))
+}
+
+/// A compact summary of the statistics in a Lambda `REPORT` line, or `None` for other messages.
+fn report_summary(message: &str) -> Option<String> {
+    let report = aws::lambda::InvocationReport::parse(message)?;
+
+    let mut summary = format!(
+        "REPORT {:.2} ms ({:.0} ms billed) · {} of {} MB",
+        report.duration, report.billed_duration, report.max_memory_used, report.memory_size
+    );
+    if let Some(init_duration) = report.init_duration {
+        summary.push_str(&format!(" · cold start {init_duration:.2} ms"));
+    }
+    summary.push_str(&format!(" · {}", report.request_id));
+
+    Some(summary)
+}
+
+/// A compact summary of the status, latency, and path of an API Gateway access log, or `None`
+/// for other messages.
+fn access_log_summary(message: &str) -> Option<String> {
+    let access_log = aws::api_gateway::AccessLog::parse(message)?;
+
+    let mut summary = access_log.status.to_string();
+    if let Some(latency) = access_log.latency {
+        summary.push_str(&format!(" · {latency:.0} ms"));
+    }
+    summary.push_str(" · ");
+    if let Some(method) = &access_log.method {
+        summary.push_str(&format!("{method} "));
+    }
+    summary.push_str(&access_log.path);
+
+    Some(summary)
+}
+
+/// A compact summary of the duration, rows, user, and statement of a slow query of an RDS
+/// database, or `None` for other messages.
+fn slow_query_summary(message: &str) -> Option<String> {
+    let slow_query = aws::rds::SlowQuery::parse(message)?;
+
+    let mut summary = format!("SLOW {:.2} ms", slow_query.duration);
+    if let (Some(rows_sent), Some(rows_examined)) = (slow_query.rows_sent, slow_query.rows_examined)
+    {
+        summary.push_str(&format!(" · {rows_sent} of {rows_examined} rows"));
+    }
+    if let Some(user) = &slow_query.user {
+        summary.push_str(&format!(" · {user}"));
+    }
+    if !slow_query.statement.is_empty() {
+        summary.push_str(&format!(" · {}", slow_query.statement));
+    }
+
+    Some(summary)
+}
+
+/// Whether the message is of a cold start, either the `INIT_START` line that Lambda logs when
+/// initializing a new execution environment, or the `REPORT` line of its first invocation.
+pub fn is_cold_start(message: &str) -> bool {
+    message.starts_with("INIT_START")
+        || aws::lambda::InvocationReport::parse(message)
+            .is_some_and(|report| report.init_duration.is_some())
+}
+
+/// The `text` split into rows of at most `width` characters, at least one even if it's empty.
+pub fn wrap(text: &str, width: usize) -> Vec<&str> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    let mut start = 0;
+    for (count, (i, _)) in text.char_indices().enumerate() {
+        if count > 0 && count % width == 0 {
+            rows.push(&text[start..i]);
+            start = i;
+        }
+    }
+    rows.push(&text[start..]);
+    rows
+}
+
+/// The byte ranges of `text` that match `query`, ignoring ASCII case, without overlapping.
+pub fn find_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    if query.is_empty() {
+        return matches;
+    }
+
+    let mut start = 0;
+    while let Some(offset) = text[start..].char_indices().find_map(|(i, _)| {
+        text[start + i..]
+            .get(..query.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(query))
+            .then_some(i)
+    }) {
+        let match_start = start + offset;
+        start = match_start + query.len();
+        matches.push(match_start..start);
+    }
+
+    matches
+}
@@ -0,0 +1,61 @@
+//! A Logs Insights query built a command at a time, for those who don't know the syntax.
+use super::text_input::TextInput;
+
+/// The commands of a query, each with an example of its arguments.
+pub const COMMANDS: [(&str, &str); 5] = [
+    ("fields", "@timestamp, @message, @requestId"),
+    ("filter", "@message like /ERROR/ and @duration > 1000"),
+    ("stats", "count(*) by bin(5m)"),
+    ("sort", "@timestamp desc"),
+    ("limit", "100"),
+];
+
+/// The arguments of each of the [`COMMANDS`] of a query being built, those left empty left out.
+#[derive(Debug)]
+pub struct QueryBuilder {
+    pub arguments: [TextInput; COMMANDS.len()],
+    /// The command whose arguments are being edited.
+    pub editing: usize,
+}
+
+impl Default for QueryBuilder {
+    fn default() -> Self {
+        Self {
+            arguments: [
+                TextInput::new("@timestamp, @message"),
+                TextInput::default(),
+                TextInput::default(),
+                TextInput::new("@timestamp desc"),
+                TextInput::new("100"),
+            ],
+            editing: 0,
+        }
+    }
+}
+
+impl QueryBuilder {
+    pub fn editing(&mut self) -> &mut TextInput {
+        &mut self.arguments[self.editing]
+    }
+
+    /// Edit the arguments of the next command, or else the previous one.
+    pub fn edit_next(&mut self, next: bool) {
+        self.editing = if next {
+            (self.editing + 1).min(COMMANDS.len() - 1)
+        } else {
+            self.editing.saturating_sub(1)
+        };
+    }
+
+    /// The query, with a command of each of the arguments, such as
+    /// `fields @timestamp, @message | sort @timestamp desc | limit 100`.
+    pub fn query(&self) -> String {
+        COMMANDS
+            .iter()
+            .zip(&self.arguments)
+            .filter(|(_, arguments)| !arguments.value.trim().is_empty())
+            .map(|((command, _), arguments)| format!("{command} {}", arguments.value.trim()))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
@@ -0,0 +1,97 @@
+//! A single line of editable text.
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    style::Stylize,
+    text::Span,
+};
+
+/// A single line of editable text, such as a filter pattern.
+#[derive(Debug, Default)]
+pub struct TextInput {
+    pub value: String,
+    // Position of the cursor, in characters.
+    cursor: usize,
+    // The entry of a history being shown in place of the text input, and that text.
+    recalled: Option<usize>,
+    draft: String,
+}
+
+impl TextInput {
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            cursor: value.chars().count(),
+            ..Default::default()
+        }
+    }
+
+    /// Show the entry of `history`, oldest first, before the one shown, or after it if not
+    /// `older`, returning to the text input after the most recent entry.
+    pub fn recall(&mut self, history: &[String], older: bool) {
+        let recalled = match (self.recalled, older) {
+            (None, true) => history.len().checked_sub(1),
+            (None, false) => return,
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) => Some(i + 1).filter(|&i| i < history.len()),
+        };
+        if self.recalled.is_none() {
+            self.draft = std::mem::take(&mut self.value);
+        }
+
+        self.value = match recalled {
+            Some(i) => history[i].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.recalled = recalled;
+        self.cursor = self.value.chars().count();
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        // Editing an entry of a history makes it the text input.
+        if matches!(
+            key.code,
+            KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete
+        ) {
+            self.recalled = None;
+        }
+
+        match key.code {
+            KeyCode::Char(c) => {
+                self.value.insert(self.byte_index(), c);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.value.remove(self.byte_index());
+            }
+            KeyCode::Delete if self.cursor < self.value.chars().count() => {
+                self.value.remove(self.byte_index());
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.value.chars().count()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.value.chars().count(),
+            _ => {}
+        }
+    }
+
+    fn byte_index(&self) -> usize {
+        self.value
+            .char_indices()
+            .nth(self.cursor)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+
+    /// The text with the cursor drawn as a reversed character.
+    pub fn to_spans(&self) -> Vec<Span<'_>> {
+        let (before, after) = self.value.split_at(self.byte_index());
+        let mut after = after.chars();
+        let under_cursor = after.next().map_or(" ".to_string(), String::from);
+
+        vec![
+            Span::raw(before),
+            Span::raw(under_cursor).reversed(),
+            Span::raw(after.as_str()),
+        ]
+    }
+}
@@ -0,0 +1,72 @@
+//! Notifications from background tasks, stacked over the bottom right of the screen until they
+//! are dismissed after a while.
+//!
+//! Unlike the footer, which tells what became of the last key press until the next one,
+//! toasts tell what became of work done in the background, without interrupting whatever is
+//! being viewed meanwhile.
+use std::time::{Duration, Instant};
+
+// Most toasts shown at once, dropping the oldest.
+const MAX_TOASTS: usize = 4;
+
+/// How bad what a toast tells of is, which is also how long it is shown for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn lifetime(self) -> Duration {
+        match self {
+            Self::Info => Duration::from_secs(4),
+            Self::Warning => Duration::from_secs(6),
+            Self::Error => Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Toast {
+    pub severity: Severity,
+    pub message: String,
+    shown_at: Instant,
+}
+
+/// The toasts being shown, oldest first.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+}
+
+impl Toasts {
+    pub fn push(&mut self, severity: Severity, message: impl Into<String>) {
+        let message = message.into();
+        // The same toast again is shown for longer, rather than twice.
+        self.toasts
+            .retain(|toast| toast.severity != severity || toast.message != message);
+        self.toasts.push(Toast {
+            severity,
+            message,
+            shown_at: Instant::now(),
+        });
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+    }
+
+    /// Dismiss the toasts shown for long enough.
+    pub fn expire(&mut self) {
+        self.toasts
+            .retain(|toast| toast.shown_at.elapsed() < toast.severity.lifetime());
+    }
+
+    pub fn dismiss_all(&mut self) {
+        self.toasts.clear();
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+}
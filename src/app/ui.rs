@@ -0,0 +1,3735 @@
+//! Rendering of the TUI.
+use std::ops::Range;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    symbols,
+    text::{Line, Span, Text},
+    widgets::{
+        Block, BorderType, Borders, Cell, Clear, HighlightSpacing, List, ListItem, ListState,
+        Padding, Paragraph, Row, Sparkline, StatefulWidget, Table, Tabs, Widget, Wrap,
+    },
+};
+
+use aws_logs_tui::aws;
+use chrono::Utc;
+
+use crate::{
+    log_level::LogLevel,
+    theme::Theme,
+    timestamp::{TimeZone, TimestampFormat, TimestampStyle},
+};
+
+use super::{
+    App, BuildView, CloudTrailView, Confirmation, EnvironmentView, ErrorScreen, EventSourceView,
+    EventView, ExecutionView, FailedEventView, FunctionDetails, FunctionRow, FunctionSort,
+    HelpView, InvokeInput, ListMode, LogGroupRow, LogView, Palette, Picker, PickerKind,
+    TimeRangeForm, TraceView, TrailFilter,
+    keymap::Mode,
+    log_format,
+    query_builder::{self, QueryBuilder},
+    text_input::TextInput,
+    toast::{Severity, Toasts},
+    volume::Volume,
+};
+
+// Size of the error screen, shrunk to fit smaller terminals.
+const ERROR_SCREEN_WIDTH: u16 = 72;
+const ERROR_SCREEN_HEIGHT: u16 = 10;
+// Taller to show the output of aws sso login, when signing in again.
+const SSO_LOGIN_HEIGHT: u16 = 20;
+// Size of a picker popup, shrunk to fit smaller terminals.
+const PICKER_WIDTH: u16 = 48;
+const PICKER_HEIGHT: u16 = 20;
+// Size of the time range form, shrunk to fit smaller terminals.
+const TIME_RANGE_FORM_WIDTH: u16 = 48;
+const TIME_RANGE_FORM_HEIGHT: u16 = 6;
+
+// Width of a confirmation popup, as tall as its question wraps to.
+const CONFIRMATION_WIDTH: u16 = 60;
+
+const QUERY_BUILDER_WIDTH: u16 = 80;
+const QUERY_BUILDER_HEIGHT: u16 = 16;
+// Size of the help overlay, shrunk to fit smaller terminals.
+const HELP_WIDTH: u16 = 64;
+const HELP_HEIGHT: u16 = 30;
+// Width of the names column of the function info.
+const FUNCTION_FIELD_WIDTH: usize = 15;
+// Size of the environment variables popup, shrunk to fit smaller terminals.
+const ENVIRONMENT_WIDTH: u16 = 72;
+const ENVIRONMENT_HEIGHT: u16 = 20;
+// Shown in place of the values of environment variables, until revealed.
+const MASKED_VALUE: &str = "••••••••";
+
+// Size of the popup of a whole log event, shrunk to fit smaller terminals.
+const EVENT_WIDTH: u16 = 100;
+const EVENT_HEIGHT: u16 = 30;
+
+const TRACE_WIDTH: u16 = 100;
+const TRACE_HEIGHT: u16 = 24;
+// Widths of the names of segments, and of their durations, around their bars.
+const TRACE_NAME_WIDTH: usize = 32;
+const TRACE_DURATION_WIDTH: usize = 10;
+// Width of the keys column of the help overlay.
+// Size of the popup of the executions of a state machine, shrunk to fit smaller terminals.
+const EXECUTIONS_WIDTH: u16 = 100;
+const EXECUTIONS_HEIGHT: u16 = 24;
+
+// Width of the status, and duration, columns of executions, and the type column of events.
+const EXECUTION_STATUS_WIDTH: usize = 10;
+const EXECUTION_DURATION_WIDTH: usize = 10;
+const HISTORY_EVENT_TYPE_WIDTH: usize = 28;
+
+// Size of the popup of the event source mappings of a function, shrunk to fit smaller terminals.
+const EVENT_SOURCES_WIDTH: u16 = 100;
+const EVENT_SOURCES_HEIGHT: u16 = 16;
+
+// Width of the state, kind of source, and batch size columns of event source mappings.
+const EVENT_SOURCE_STATE_WIDTH: usize = 9;
+const EVENT_SOURCE_KIND_WIDTH: usize = 8;
+const EVENT_SOURCE_BATCH_WIDTH: usize = 10;
+
+// Size of the popup of the failed events of a function, shrunk to fit smaller terminals.
+const FAILED_EVENTS_WIDTH: u16 = 120;
+const FAILED_EVENTS_HEIGHT: u16 = 24;
+
+// Width of the request ID column of failed events, as long as a UUID.
+const REQUEST_ID_WIDTH: usize = 36;
+
+// Size of the popup of the builds of a CodeBuild project, shrunk to fit smaller terminals.
+const BUILDS_WIDTH: u16 = 100;
+const BUILDS_HEIGHT: u16 = 24;
+
+// Width of the status, number, and duration columns of builds.
+const BUILD_STATUS_WIDTH: usize = 11;
+const BUILD_NUMBER_WIDTH: usize = 6;
+const BUILD_DURATION_WIDTH: usize = 10;
+
+// Size of the popup of CloudTrail events, shrunk to fit smaller terminals.
+const CLOUDTRAIL_WIDTH: u16 = 120;
+const CLOUDTRAIL_HEIGHT: u16 = 24;
+// Width of the name, and username, columns of CloudTrail events.
+const TRAIL_EVENT_NAME_WIDTH: usize = 36;
+const TRAIL_USERNAME_WIDTH: usize = 28;
+
+const HELP_KEYS_WIDTH: usize = 18;
+
+// Height of the volume of log events, its title and the rows of its bars.
+const VOLUME_HEIGHT: u16 = 4;
+
+// Frames of the spinner shown while loading, advanced every tick.
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+// Width of the region column of the function list, fitting the longest region names.
+const REGION_COLUMN_WIDTH: usize = 16;
+
+// Widest a Logs Insights result column is drawn, unless it is the last column.
+const MAX_INSIGHTS_COLUMN_WIDTH: u16 = 40;
+
+// Widths of the address columns of VPC flow log records, fitting IPv6 addresses, and of the rest.
+const FLOW_ADDRESS_WIDTH: u16 = 39;
+const FLOW_COLUMN_WIDTH: u16 = 10;
+
+// Width of the log stream name column left of log messages, fitting the IDs that Lambda ends
+// them with.
+const STREAM_NAME_WIDTH: usize = 32;
+
+// Width of the labels of the functions, or log streams, that merged log events came from.
+const SOURCE_WIDTH: usize = 24;
+
+// Width of the progress bars of downloads, in the status bar.
+const DOWNLOAD_BAR_WIDTH: usize = 10;
+
+// Width of toasts, shrunk to fit smaller terminals, and the most lines of a message shown.
+const TOAST_WIDTH: u16 = 60;
+const TOAST_MAX_LINES: usize = 3;
+
+impl Widget for &mut App {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // The alert is pinned above everything else until acknowledged.
+        let [alert_area, area] = Layout::vertical([
+            Constraint::Length(u16::from(self.alert.is_some())),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+        if let Some(alert) = &self.alert {
+            Paragraph::new(format!(
+                " ⚠ {} tailed log events matched alerts, last: {} · ! to acknowledge",
+                alert.count, alert.message
+            ))
+            .style(self.theme.error.reversed().bold())
+            .render(alert_area, buf);
+        }
+
+        let [header_area, main_area, footer_area] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        if self.tabs[self.tab].insights_view.is_some() {
+            self.render_header(header_area, buf);
+            self.render_footer(footer_area, buf);
+            self.render_insights_view(main_area, buf);
+        } else {
+            self.render_main(header_area, main_area, footer_area, buf);
+        }
+
+        if let Some(picker) = &mut self.picker {
+            App::render_picker(picker, &self.theme, area, buf);
+        }
+        if let Some(confirmation) = &self.confirmation {
+            App::render_confirmation(confirmation, &self.theme, area, buf);
+        }
+        if let Some(time_range_form) = &self.time_range_form {
+            App::render_time_range_form(
+                time_range_form,
+                self.timestamp_format.time_zone,
+                &self.theme,
+                area,
+                buf,
+            );
+        }
+        if let Some(query_builder) = &self.query_builder {
+            App::render_query_builder(query_builder, &self.theme, area, buf);
+        }
+        if let Some(palette) = &mut self.palette {
+            App::render_palette(palette, &self.theme, area, buf);
+        }
+        let spinner = self.spinner();
+        if let Some(environment) = &mut self.environment {
+            let details = self.function_list.configurations.get(&environment.function);
+            App::render_environment(environment, details, spinner, &self.theme, area, buf);
+        }
+        if let Some(trace) = &mut self.trace {
+            App::render_trace(trace, spinner, &self.theme, area, buf);
+        }
+        // Failed events are shown in full over the popup listing them.
+        if let Some(failed_event_view) = &mut self.failed_events {
+            let timestamp_format = self.timestamp_format;
+            App::render_failed_events(
+                failed_event_view,
+                timestamp_format,
+                spinner,
+                &self.theme,
+                area,
+                buf,
+            );
+        }
+        if let Some(event_view) = &mut self.event_view {
+            App::render_event(event_view, self.timestamp_format, &self.theme, area, buf);
+        }
+        if let Some(execution_view) = &mut self.executions {
+            let timestamp_format = self.timestamp_format;
+            App::render_executions(
+                execution_view,
+                timestamp_format,
+                spinner,
+                &self.theme,
+                area,
+                buf,
+            );
+        }
+        if let Some(event_source_view) = &mut self.event_sources {
+            App::render_event_sources(event_source_view, spinner, &self.theme, area, buf);
+        }
+        if let Some(build_view) = &mut self.builds {
+            let timestamp_format = self.timestamp_format;
+            App::render_builds(
+                build_view,
+                timestamp_format,
+                spinner,
+                &self.theme,
+                area,
+                buf,
+            );
+        }
+        if let Some(cloudtrail) = &mut self.cloudtrail {
+            let timestamp_format = self.timestamp_format;
+            App::render_cloudtrail(
+                cloudtrail,
+                timestamp_format,
+                spinner,
+                &self.theme,
+                area,
+                buf,
+            );
+        }
+        if let Some(error_screen) = &self.error_screen {
+            App::render_error_screen(error_screen, &self.theme, area, buf);
+        }
+        if let Some(help) = &mut self.help {
+            App::render_help(help, &self.theme, area, buf);
+        }
+        App::render_toasts(&self.toasts, &self.theme, area, buf);
+    }
+}
+
+/// Rendering logic for the app
+impl App {
+    fn render_main(
+        &mut self,
+        header_area: Rect,
+        main_area: Rect,
+        footer_area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let list_constraint = if self.split.collapsed {
+            Constraint::Length(0)
+        } else {
+            Constraint::Percentage(self.split.list_percent)
+        };
+        // Tabs are only shown once there are several.
+        let tabs_height = if self.tabs.len() > 1 { 1 } else { 0 };
+        let [list_area, tabs_area, item_area] = Layout::vertical([
+            list_constraint,
+            Constraint::Length(tabs_height),
+            Constraint::Fill(1),
+        ])
+        .areas(main_area);
+        self.render_tabs(tabs_area, buf);
+        self.list_area = list_area;
+        self.item_area = item_area;
+
+        self.render_header(header_area, buf);
+        if let Some((filter, input)) = self
+            .cloudtrail
+            .as_ref()
+            .and_then(|cloudtrail| cloudtrail.editing.as_ref())
+        {
+            App::render_trail_filter_input(*filter, input, footer_area, buf);
+        } else if self.function_list.finding {
+            App::render_find_input(&self.function_list.query, footer_area, buf);
+        } else if let Some(filter_input) = &self.filter_input {
+            App::render_filter_input(filter_input, footer_area, buf);
+        } else if let Some(search_input) = &self.search_input {
+            App::render_search_input(search_input, footer_area, buf);
+        } else if let Some(group_search_input) = &self.group_search_input {
+            App::render_group_search_input(
+                group_search_input,
+                self.group_search_log_group_names.len(),
+                footer_area,
+                buf,
+            );
+        } else if let Some(regex_filter_input) = &self.regex_filter_input {
+            App::render_regex_filter_input(regex_filter_input, footer_area, buf);
+        } else if let Some(flow_filter_input) = &self.flow_filter_input {
+            App::render_flow_filter_input(flow_filter_input, footer_area, buf);
+        } else if let Some(export_input) = &self.export_input {
+            App::render_export_input(export_input, footer_area, buf);
+        } else if let Some(download_input) = &self.download_input {
+            App::render_download_input(download_input, footer_area, buf);
+        } else if let Some(s3_export_input) = &self.s3_export_input {
+            App::render_s3_export_input(
+                s3_export_input,
+                &self.s3_export_log_group_name,
+                &self.tabs[self.tab].time_range.label(),
+                footer_area,
+                buf,
+            );
+        } else if let Some(tag_filter_input) = &self.tag_filter_input {
+            App::render_tag_filter_input(tag_filter_input, footer_area, buf);
+        } else if let Some(query_name_input) = &self.query_name_input {
+            App::render_query_name_input(query_name_input, footer_area, buf);
+        } else if let Some(invoke_input) = &self.invoke_input {
+            App::render_invoke_input(invoke_input, footer_area, buf);
+        } else {
+            self.render_footer(footer_area, buf);
+        }
+        match self.list_mode {
+            ListMode::Functions => self.render_list(list_area, buf),
+            ListMode::LogGroups => self.render_log_group_list(list_area, buf),
+            ListMode::Services => self.render_service_list(list_area, buf),
+            ListMode::StateMachines => self.render_state_machine_list(list_area, buf),
+            ListMode::Stages => self.render_stage_list(list_area, buf),
+            ListMode::Databases => self.render_database_list(list_area, buf),
+            ListMode::Projects => self.render_project_list(list_area, buf),
+        }
+        if self.tabs[self.tab]
+            .log_view
+            .as_ref()
+            .is_some_and(LogView::shows_invocations)
+        {
+            self.render_invocation_list(item_area, buf);
+        } else if self.tabs[self.tab]
+            .log_view
+            .as_ref()
+            .is_some_and(|log_view| log_view.flow_logs.is_some())
+        {
+            self.render_flow_log_table(item_area, buf);
+        } else if self.tabs[self.tab].log_view.is_some() {
+            self.render_log_view(item_area, buf);
+        } else if self.tabs[self.tab].log_stream_list.is_some() {
+            self.render_log_stream_list(item_area, buf);
+        } else {
+            match self.list_mode {
+                ListMode::Functions => self.render_selected_item(item_area, buf),
+                ListMode::LogGroups => self.render_selected_log_group(item_area, buf),
+                ListMode::Services => self.render_selected_service(item_area, buf),
+                ListMode::StateMachines => self.render_selected_state_machine(item_area, buf),
+                ListMode::Stages => self.render_selected_stage(item_area, buf),
+                ListMode::Databases => self.render_selected_database(item_area, buf),
+                ListMode::Projects => self.render_selected_project(item_area, buf),
+            }
+        }
+    }
+
+    fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let titles = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| format!(" {} {} ", i + 1, tab.title()));
+
+        Tabs::new(titles)
+            .select(self.tab)
+            .style(theme.dimmed.bg(theme.row_bg))
+            .highlight_style(theme.selected.fg(theme.text))
+            .padding("", "")
+            .divider("│")
+            .render(area, buf);
+    }
+
+    /// The profile, and the role assumed with it if any.
+    fn profile_label(&self) -> String {
+        match &self.role_arn {
+            // Roles are named after the last `/` of their ARN, after any path.
+            Some(role_arn) => format!(
+                "{} as {}",
+                self.profile.as_deref().unwrap_or("default"),
+                role_arn.rsplit('/').next().unwrap_or(role_arn)
+            ),
+            None => self.profile.as_deref().unwrap_or("default").to_string(),
+        }
+    }
+
+    fn render_header(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let profile = self.profile_label();
+        let region = self.active_region.as_deref().unwrap_or("no region");
+        let location = if self.is_multi_region() {
+            format!(
+                "{profile} @ {} regions, logs in {region}",
+                self.clients.len()
+            )
+        } else {
+            format!("{profile} @ {region}")
+        };
+
+        let mut location = Line::raw(location).fg(theme.text);
+        if let Some(identity) = &self.caller_identity {
+            location.push_span(Span::raw(format!(" · {} ", identity.account)));
+            location.push_span(Span::styled(identity.arn.as_str(), theme.dimmed));
+        }
+        if aws::retry::is_rate_limited() {
+            location.push_span(Span::styled(" · rate limited, retrying…", theme.dimmed));
+        }
+
+        let title = if self.is_production() {
+            Line::styled(
+                " ⚠ AWS Logs TUI · PRODUCTION ⚠ ",
+                theme.error.reversed().bold(),
+            )
+        } else {
+            Line::raw("AWS Logs TUI").bold()
+        };
+        Paragraph::new(vec![title, location])
+            .centered()
+            .render(area, buf);
+    }
+
+    fn render_footer(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        if let Some(error) = &self.error {
+            Paragraph::new(error.as_str())
+                .style(theme.error)
+                .centered()
+                .render(area, buf);
+            return;
+        }
+        if let Some(notice) = &self.notice {
+            Paragraph::new(notice.as_str())
+                .fg(theme.text)
+                .centered()
+                .render(area, buf);
+            return;
+        }
+
+        self.render_status_bar(area, buf);
+    }
+
+    /// Render where the logs are viewed from, so which account is being viewed is never a
+    /// guess, and whether the viewed logs are being tailed.
+    fn render_status_bar(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let account = self.caller_identity.as_ref().map_or_else(
+            || format!("{} account", self.spinner()),
+            |identity| identity.account.clone(),
+        );
+        let region = if self.is_multi_region() {
+            format!("{} regions", self.clients.len())
+        } else {
+            self.active_region
+                .as_deref()
+                .unwrap_or("no region")
+                .to_string()
+        };
+        let tab = &self.tabs[self.tab];
+
+        let separator = || Span::styled(" │ ", theme.dimmed);
+        let mut spans = vec![
+            Span::raw(" "),
+            Span::raw(self.profile_label()).bold(),
+            separator(),
+            if self.is_production() {
+                Span::styled(account, theme.error.bold())
+            } else {
+                Span::raw(account)
+            },
+            separator(),
+            Span::raw(region),
+            separator(),
+            Span::raw(tab.time_range.label()),
+        ];
+        if let Some(log_view) = &tab.log_view {
+            spans.push(separator());
+            if log_view.live_tail.is_some() && log_view.following() {
+                spans.push(Span::styled(" LIVE ", theme.live));
+            } else if log_view.live_tail.is_some() {
+                spans.push(Span::raw("paused"));
+            } else if log_view.resuming_live_tail {
+                spans.push(Span::raw("resuming live tail"));
+            } else {
+                spans.push(Span::styled("not tailing", theme.dimmed));
+            }
+        }
+        for download in &self.downloads {
+            let name = download
+                .log_group_name
+                .rsplit('/')
+                .next()
+                .unwrap_or(&download.log_group_name);
+            let filled = ((download.progress * DOWNLOAD_BAR_WIDTH as f64).round() as usize)
+                .min(DOWNLOAD_BAR_WIDTH);
+            spans.extend([
+                separator(),
+                Span::raw(format!("⬇ {name} ")),
+                Span::styled("█".repeat(filled), theme.matched),
+                Span::styled("░".repeat(DOWNLOAD_BAR_WIDTH - filled), theme.dimmed),
+                Span::raw(format!(
+                    " {:.0}% {} events",
+                    download.progress * 100.0,
+                    download.count
+                )),
+            ]);
+        }
+
+        let help = Line::styled(": for commands, ? for help ", theme.dimmed);
+        let [status_area, help_area] = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(u16::try_from(help.width()).unwrap_or(0)),
+        ])
+        .areas(area);
+        Paragraph::new(Line::from(spans))
+            .fg(theme.text)
+            .render(status_area, buf);
+        help.render(help_area, buf);
+    }
+
+    fn render_filter_input(filter_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Filter pattern: ").bold()];
+        spans.extend(filter_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_search_input(search_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Search: ").bold()];
+        spans.extend(search_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_group_search_input(
+        group_search_input: &TextInput,
+        log_groups: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let mut spans = vec![Span::raw(format!("Search {log_groups} log groups for: ")).bold()];
+        spans.extend(group_search_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_regex_filter_input(regex_filter_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Show matching regex: ").bold()];
+        spans.extend(regex_filter_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_flow_filter_input(flow_filter_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans =
+            vec![Span::raw("Filter flow logs (such as action=REJECT dstport=22): ").bold()];
+        spans.extend(flow_filter_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_export_input(export_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Export to (.log, .jsonl, or .csv): ").bold()];
+        spans.extend(export_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_download_input(download_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Download to (.jsonl, or .jsonl.gz to gzip): ").bold()];
+        spans.extend(download_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_s3_export_input(
+        s3_export_input: &TextInput,
+        log_group_name: &str,
+        time_range: &str,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let mut spans = vec![
+            Span::raw(format!(
+                "Export {log_group_name} ({time_range}) to S3 (bucket/prefix): "
+            ))
+            .bold(),
+        ];
+        spans.extend(s3_export_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_tag_filter_input(tag_filter_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Filter by tags (such as team=payments): ").bold()];
+        spans.extend(tag_filter_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_query_name_input(query_name_input: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Save query as: ").bold()];
+        spans.extend(query_name_input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_invoke_input(invoke_input: &InvokeInput, area: Rect, buf: &mut Buffer) {
+        let label = if invoke_input.dry_run {
+            "Dry run with payload: "
+        } else {
+            "Invoke with payload: "
+        };
+        let mut spans = vec![Span::raw(label).bold()];
+        spans.extend(invoke_input.payload.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_trail_filter_input(
+        filter: TrailFilter,
+        input: &TextInput,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let label = match filter {
+            TrailFilter::ResourceName => "Events of resource (empty for all): ",
+            TrailFilter::EventName => "Events named like: ",
+        };
+        let mut spans = vec![Span::raw(label).bold()];
+        spans.extend(input.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    /// The current frame of the loading spinner.
+    fn spinner(&self) -> &'static str {
+        SPINNER_FRAMES[self.ticks % SPINNER_FRAMES.len()]
+    }
+
+    fn render_find_input(query: &TextInput, area: Rect, buf: &mut Buffer) {
+        let mut spans = vec![Span::raw("Find: ").bold()];
+        spans.extend(query.to_spans());
+
+        Paragraph::new(Line::from(spans)).render(area, buf);
+    }
+
+    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let spinner = self.spinner();
+        let theme = &self.theme;
+        let mut title = match &self.function_list.functions {
+            Some(functions) if self.function_list.cached && self.function_list.loading => {
+                format!(
+                    "Functions {spinner} {} cached, refreshing…",
+                    functions.len()
+                )
+            }
+            Some(functions) if self.function_list.cached => {
+                format!("Functions {} cached", functions.len())
+            }
+            Some(functions) if self.function_list.loading => {
+                format!("Functions {spinner} {}", functions.len())
+            }
+            _ => "Functions".to_string(),
+        };
+        let query = self.function_list.query.value.trim();
+        if !query.is_empty() {
+            title.push_str(&format!(
+                " [/{query}: {} matches]",
+                self.function_list.matches.len()
+            ));
+        }
+        let sort = self.function_list.sort;
+        if sort != FunctionSort::Name {
+            title.push_str(&format!(" [by {}]", sort.label()));
+        }
+        let loading = self
+            .function_list
+            .tags
+            .values()
+            .filter(|tags| matches!(tags, FunctionDetails::Loading))
+            .count();
+        let tag_filter = &self.function_list.tag_filter;
+        if !tag_filter.is_empty() {
+            title.push_str(&format!(
+                " [#{tag_filter}: {} matches",
+                self.function_list.matches.len()
+            ));
+            if loading > 0 {
+                title.push_str(&format!(", {spinner} loading {loading} tags"));
+            }
+            title.push(']');
+        }
+        if self.function_list.grouped {
+            title.push_str(" [by stack");
+            if loading > 0 && tag_filter.is_empty() {
+                title.push_str(&format!(", {spinner} loading {loading} tags"));
+            }
+            title.push(']');
+        }
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        // Iterate through the `functions` matching the query and stylize them.
+        let all_functions = self.function_list.functions.as_deref().unwrap_or_default();
+        let show_region = self.is_multi_region();
+        let favorites = &self.function_list.favorites;
+        let marked = &self.function_list.marked;
+        // How long ago functions last logged, whichever way timestamps are shown.
+        let activity_format = TimestampFormat {
+            style: TimestampStyle::Relative,
+            ..self.timestamp_format
+        };
+        let now = Utc::now();
+
+        let mut list_area = block.inner(area);
+        block.render(area, buf);
+        if self.shows_filter_chips() {
+            let [chips_area, rest] =
+                Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(list_area);
+            self.filter_chips().render(chips_area, buf);
+            list_area = rest;
+        }
+
+        // Pages of functions are listed as they arrive, while the rest are still loading.
+        let loading_more = self.function_list.loading_regions > 0
+            && !self.function_list.cached
+            && !all_functions.is_empty();
+        let matches = &self.function_list.matches;
+        let rows = &self.function_list.rows;
+        let collapsed = &self.function_list.collapsed;
+        let shown = visible_items(
+            &mut self.function_list.state,
+            rows.len() + usize::from(loading_more),
+            usize::from(list_area.height),
+            |_| 1,
+        );
+        let mut state = window_state(&self.function_list.state, &shown);
+        let functions: Vec<ListItem> = shown
+            .map(|i| {
+                let function_match = match rows.get(i) {
+                    Some(FunctionRow::Function(i)) => &matches[*i],
+                    Some(FunctionRow::Stack { name, count }) => {
+                        let arrow = if collapsed.contains(name) {
+                            "▸"
+                        } else {
+                            "▾"
+                        };
+                        let name = name.as_deref().unwrap_or("No stack");
+                        return ListItem::new(Line::from(vec![
+                            Span::styled(format!("{arrow} {name}"), theme.header),
+                            Span::styled(format!(" ({count})"), theme.dimmed),
+                        ]))
+                        .bg(theme.alternate_bg(i));
+                    }
+                    None => {
+                        return ListItem::new(Line::styled(
+                            format!("  {spinner} loading more…"),
+                            theme.dimmed,
+                        ));
+                    }
+                };
+                let color = theme.alternate_bg(i);
+                let function = &all_functions[function_match.index];
+                ListItem::from(ListItemFunction {
+                    function,
+                    positions: &function_match.positions,
+                    show_region,
+                    favorite: (!favorites.is_empty()).then(|| favorites.contains(function)),
+                    marked: (!marked.is_empty()).then(|| marked.contains(function)),
+                    last_activity: match self.function_list.last_activity.get(function) {
+                        Some(FunctionDetails::Loaded(Some(last_event_time))) => {
+                            Some(activity_format.format(*last_event_time, now))
+                        }
+                        Some(FunctionDetails::Loaded(None)) => Some("never logged".to_string()),
+                        _ => None,
+                    },
+                    sorted_by: self.function_sorted_by(function),
+                    theme,
+                })
+                .bg(color)
+            })
+            .collect();
+
+        // Create a List from the shown functions and highlight the currently selected one
+        let list = List::new(functions)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        // We need to disambiguate this trait method as both `Widget` and `StatefulWidget` share the
+        // same method name `render`.
+        StatefulWidget::render(list, list_area, buf, &mut state);
+    }
+
+    /// What `function` is sorted by, shown after its name, unless the functions are sorted by
+    /// name.
+    fn function_sorted_by(&self, function: &aws::lambda::Function) -> Option<String> {
+        let summary = self.function_list.summaries.get(function);
+        match self.function_list.sort {
+            FunctionSort::Name => None,
+            FunctionSort::LastModified => Some(
+                summary
+                    .and_then(|summary| summary.last_modified.clone())
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            FunctionSort::CodeSize => Some(summary.map_or_else(
+                || "-".to_string(),
+                |summary| format!("{:.1} MB", summary.code_size as f64 / 1_000_000.0),
+            )),
+        }
+    }
+
+    /// The runtimes and architectures of the listed functions, to narrow them to, with those
+    /// they are narrowed to highlighted.
+    fn filter_chips(&self) -> Line<'_> {
+        let theme = &self.theme;
+        let function_list = &self.function_list;
+        let chips = |spans: &mut Vec<Span>, values: Vec<String>, active: Option<&String>| {
+            for value in values {
+                let style = if active == Some(&value) {
+                    theme.selected
+                } else {
+                    theme.dimmed
+                };
+                spans.push(Span::styled(format!(" {value} "), style));
+            }
+        };
+
+        let mut spans = vec![Span::styled(" Runtime (u):", theme.header)];
+        chips(
+            &mut spans,
+            function_list.runtimes(),
+            function_list.runtime_filter.as_ref(),
+        );
+        spans.push(Span::styled("  Architecture (a):", theme.header));
+        chips(
+            &mut spans,
+            function_list.architectures(),
+            function_list.architecture_filter.as_ref(),
+        );
+
+        Line::from(spans).bg(theme.row_bg)
+    }
+
+    fn render_selected_item(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let info = match (&self.function_list.functions, self.selected_function()) {
+            (None, _) if self.function_list.loading => {
+                Text::raw(format!("{} Loading functions...", self.spinner()))
+            }
+            (None, _) => Text::raw("No functions available..."),
+            (Some(_), None) => Text::raw("Nothing selected..."),
+            (Some(_), Some(function)) => self.function_info(function),
+        };
+
+        // We show the function's info under the list in this paragraph
+        let block = Block::new()
+            .title(Line::raw("Function Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        // We can now render the item info
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    /// The name of `function`, its configuration once loaded, and its metrics over the time
+    /// range of the tab once loaded.
+    fn function_info(&self, function: &aws::lambda::Function) -> Text<'_> {
+        let theme = &self.theme;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<FUNCTION_FIELD_WIDTH$}"), theme.dimmed),
+                Span::raw(value),
+            ])
+        };
+
+        let mut text = Text::from(field("Name", function.name.clone()));
+        match self.function_list.configurations.get(function) {
+            None | Some(FunctionDetails::Loading) => {
+                text.push_line(format!("{} Loading configuration...", self.spinner()));
+            }
+            Some(FunctionDetails::Failed(error)) => {
+                text.push_line(Line::styled(error.to_string(), theme.error));
+            }
+            Some(FunctionDetails::Loaded(configuration)) => {
+                let unset = || "-".to_string();
+                text.extend([
+                    field("ARN", configuration.arn.clone().unwrap_or_else(unset)),
+                    field(
+                        "Runtime",
+                        configuration.runtime.clone().unwrap_or_else(unset),
+                    ),
+                    field(
+                        "Handler",
+                        configuration.handler.clone().unwrap_or_else(unset),
+                    ),
+                    field(
+                        "Memory",
+                        configuration
+                            .memory_size
+                            .map_or_else(unset, |memory_size| format!("{memory_size} MB")),
+                    ),
+                    field(
+                        "Timeout",
+                        configuration
+                            .timeout
+                            .map_or_else(unset, |timeout| format!("{timeout} s")),
+                    ),
+                    field("Architecture", configuration.architectures.join(", ")),
+                    field(
+                        "Last modified",
+                        configuration.last_modified.clone().unwrap_or_else(unset),
+                    ),
+                ]);
+                if configuration.layers.is_empty() {
+                    text.push_line(field("Layers", unset()));
+                }
+                for (i, layer) in configuration.layers.iter().enumerate() {
+                    let name = if i == 0 { "Layers" } else { "" };
+                    let value = match &layer.version {
+                        Some(version) => format!("{} (version {version})", layer.name),
+                        None => layer.name.clone(),
+                    };
+                    text.push_line(field(name, value));
+                }
+                text.push_line(match (&configuration.url, &configuration.url_error) {
+                    (Some(url), _) => field("URL", format!("{} ({})", url.url, url.auth_type)),
+                    (None, Some(error)) => field("URL", format!("unknown, {error}")),
+                    (None, None) => field("URL", unset()),
+                });
+                if let Some(on_failure_destination) = &configuration.on_failure_destination {
+                    text.push_line(field("On failure", on_failure_destination.clone()));
+                } else if let Some(error) = &configuration.on_failure_error {
+                    text.push_line(field("On failure", format!("unknown, {error}")));
+                }
+                if let Some(dead_letter_target) = &configuration.dead_letter_target {
+                    text.push_line(field("Dead letters", dead_letter_target.clone()));
+                }
+            }
+        }
+        match self.function_list.tags.get(function) {
+            None | Some(FunctionDetails::Loading) => {
+                text.push_line(field("Tags", format!("{} loading...", self.spinner())));
+            }
+            Some(FunctionDetails::Failed(error)) => {
+                text.push_line(Line::styled(error.to_string(), theme.error));
+            }
+            Some(FunctionDetails::Loaded(tags)) if tags.is_empty() => {
+                text.push_line(field("Tags", "-".to_string()));
+            }
+            Some(FunctionDetails::Loaded(tags)) => {
+                let tags: Vec<String> = tags
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect();
+                text.push_line(field("Tags", tags.join(", ")));
+            }
+        }
+
+        let time_range = self.tabs[self.tab].time_range;
+        text.push_line("");
+        text.push_line(Line::styled(
+            format!("Metrics ({})", time_range.label()),
+            theme.header,
+        ));
+        match self
+            .function_list
+            .metrics
+            .get(&(function.clone(), time_range))
+        {
+            None | Some(FunctionDetails::Loading) => {
+                text.push_line(format!("{} Loading metrics...", self.spinner()));
+            }
+            Some(FunctionDetails::Failed(error)) => {
+                text.push_line(Line::styled(error.to_string(), theme.error));
+            }
+            Some(FunctionDetails::Loaded(metrics)) => {
+                let errors = match metrics.error_rate() {
+                    Some(error_rate) => format!("{} ({error_rate:.1}%)", metrics.errors),
+                    None => metrics.errors.to_string(),
+                };
+                let duration = |duration: Option<f64>| {
+                    duration.map_or("-".to_string(), |duration| format!("{duration:.0} ms"))
+                };
+                text.extend([
+                    field("Invocations", metrics.invocations.to_string()),
+                    field("Errors", errors),
+                    field("Throttles", metrics.throttles.to_string()),
+                    field("Avg duration", duration(metrics.average_duration)),
+                    field("Max duration", duration(metrics.maximum_duration)),
+                ]);
+            }
+        }
+
+        text
+    }
+
+    fn render_log_group_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let mut title = "Log Groups".to_string();
+        if self.log_group_list.by_size {
+            title.push_str(" [by size]");
+        }
+        if self.log_group_list.tree {
+            title.push_str(" [by prefix]");
+        }
+        let marked = &self.log_group_list.marked;
+        if !marked.is_empty() {
+            title.push_str(&format!(" ({} marked)", marked.len()));
+        }
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let all_log_groups = self
+            .log_group_list
+            .log_groups
+            .as_deref()
+            .unwrap_or_default();
+        let expanded = &self.log_group_list.expanded;
+        let log_groups: Vec<ListItem> = self
+            .log_group_list
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let (log_group, depth, name_start) = match row {
+                    LogGroupRow::LogGroup {
+                        index,
+                        depth,
+                        name_start,
+                    } => (&all_log_groups[*index], *depth, *name_start),
+                    LogGroupRow::Prefix {
+                        prefix,
+                        depth,
+                        name_start,
+                        count,
+                    } => {
+                        let arrow = if expanded.contains(prefix) {
+                            "▾"
+                        } else {
+                            "▸"
+                        };
+                        let label = &prefix[*name_start..];
+                        return ListItem::new(Line::from(vec![
+                            Span::raw("  ".repeat(*depth)),
+                            Span::styled(format!("{arrow} {label}"), theme.header),
+                            Span::styled(format!(" ({count})"), theme.dimmed),
+                        ]))
+                        .bg(theme.alternate_bg(i));
+                    }
+                };
+                let mut spans = vec![Span::raw("  ".repeat(depth))];
+                if marked.contains(&log_group.name) {
+                    spans.push(Span::styled("● ", theme.matched));
+                } else if !marked.is_empty() {
+                    spans.push(Span::raw("  "));
+                }
+                spans.push(Span::styled(&log_group.name[name_start..], theme.text));
+                if let Some(cluster_log_group) = aws::eks::ClusterLogGroup::parse(&log_group.name) {
+                    spans.push(Span::styled(
+                        format!("  [{}]", cluster_log_group.label()),
+                        theme.dimmed,
+                    ));
+                }
+                if let Some(stored_bytes) = log_group.stored_bytes {
+                    spans.push(Span::styled(
+                        format!("  · {}", format_bytes(stored_bytes)),
+                        theme.dimmed,
+                    ));
+                }
+                if let Some(created) = log_group
+                    .creation_time
+                    .and_then(chrono::DateTime::from_timestamp_millis)
+                {
+                    spans.push(Span::styled(
+                        format!("  · created {}", created.format("%Y-%m-%d")),
+                        theme.dimmed,
+                    ));
+                }
+                // Log groups that never expire stand out, to audit them.
+                let retention = aws::cloudwatch_logs::retention_label(log_group.retention_in_days);
+                spans.push(Span::styled(
+                    format!("  · {}", retention.to_lowercase()),
+                    if log_group.retention_in_days.is_some() {
+                        theme.dimmed
+                    } else {
+                        theme.error
+                    },
+                ));
+                ListItem::new(Line::from(spans)).bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(log_groups)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.log_group_list.state);
+    }
+
+    fn render_selected_log_group(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let info = match (&self.log_group_list.log_groups, self.selected_log_group()) {
+            (None, _) if self.log_group_list.loading => {
+                format!("{} Loading log groups...", self.spinner())
+            }
+            (None, _) => "No log groups available...".to_string(),
+            (Some(_), None) => "Nothing selected...".to_string(),
+            (Some(_), Some(log_group)) => {
+                let mut info = log_group.name.clone();
+                if let Some(arn) = &log_group.arn {
+                    info.push_str(&format!("\n{arn}"));
+                }
+                info.push_str(&format!(
+                    "\nRetention: {}",
+                    aws::cloudwatch_logs::retention_label(log_group.retention_in_days)
+                ));
+                if let Some(stored_bytes) = log_group.stored_bytes {
+                    info.push_str(&format!("\nStored: {}", format_bytes(stored_bytes)));
+                }
+                if let Some(creation_time) = log_group.creation_time {
+                    info.push_str(&format!(
+                        "\nCreated: {}",
+                        self.timestamp_format.format(creation_time, Utc::now())
+                    ));
+                }
+                if let Some(cluster_log_group) = aws::eks::ClusterLogGroup::parse(&log_group.name) {
+                    let cluster = cluster_log_group.cluster.as_deref().unwrap_or("-");
+                    info.push_str(&format!(
+                        "\n\nEKS cluster: {cluster}\nLogs: {}",
+                        cluster_log_group.kind
+                    ));
+                }
+                info
+            }
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Log Group Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn render_service_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let block = Block::new()
+            .title(Line::raw("ECS Services").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let services: Vec<ListItem> = self
+            .service_list
+            .services
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, service)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{}/", service.cluster), theme.dimmed),
+                    Span::styled(service.name.as_str(), theme.text),
+                ]))
+                .bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(services)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.service_list.state);
+    }
+
+    fn render_selected_service(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let info = match (&self.service_list.services, self.selected_service()) {
+            (None, _) if self.service_list.loading => {
+                Text::raw(format!("{} Loading services...", self.spinner()))
+            }
+            (None, _) => Text::raw("No services available..."),
+            (Some(_), None) => Text::raw("Nothing selected..."),
+            (Some(_), Some(service)) => self.service_info(service),
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Service Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    /// The name of `service`, and where its containers log to, and its tasks, once loaded.
+    fn service_info(&self, service: &aws::ecs::Service) -> Text<'_> {
+        let theme = &self.theme;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<FUNCTION_FIELD_WIDTH$}"), theme.dimmed),
+                Span::raw(value),
+            ])
+        };
+
+        let mut text = Text::from(vec![
+            field("Name", service.name.clone()),
+            field("Cluster", service.cluster.clone()),
+        ]);
+        let service_logs = match self.service_list.logs.get(service) {
+            None | Some(FunctionDetails::Loading) => {
+                text.push_line(format!("{} Loading log configuration...", self.spinner()));
+                return text;
+            }
+            Some(FunctionDetails::Failed(error)) => {
+                text.push_line(Line::styled(error.to_string(), theme.error));
+                return text;
+            }
+            Some(FunctionDetails::Loaded(service_logs)) => service_logs,
+        };
+
+        text.push_line("");
+        text.push_line(Line::styled("Containers", theme.header));
+        if service_logs.containers.is_empty() {
+            text.push_line(Line::styled(
+                "No containers log with the awslogs log driver",
+                theme.dimmed,
+            ));
+        }
+        for container_logs in &service_logs.containers {
+            text.push_line(field(
+                &container_logs.container_name,
+                container_logs.log_group_name.clone(),
+            ));
+        }
+
+        text.push_line("");
+        text.push_line(Line::styled(
+            format!("Running tasks ({})", service_logs.task_ids.len()),
+            theme.header,
+        ));
+        // The log stream of each task, of the first container, as its log streams are viewed.
+        for task_id in &service_logs.task_ids {
+            text.push_line(match service_logs.containers.first() {
+                Some(container_logs) => container_logs.log_stream_name(task_id),
+                None => task_id.clone(),
+            });
+        }
+
+        text
+    }
+
+    fn render_state_machine_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let block = Block::new()
+            .title(Line::raw("State Machines").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let state_machines: Vec<ListItem> = self
+            .state_machine_list
+            .state_machines
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, state_machine)| {
+                ListItem::new(Line::styled(state_machine.name.as_str(), theme.text))
+                    .bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(state_machines)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.state_machine_list.state);
+    }
+
+    fn render_selected_state_machine(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let info = match (
+            &self.state_machine_list.state_machines,
+            self.selected_state_machine(),
+        ) {
+            (None, _) if self.state_machine_list.loading => {
+                format!("{} Loading state machines...", self.spinner())
+            }
+            (None, _) => "No state machines available...".to_string(),
+            (Some(_), None) => "Nothing selected...".to_string(),
+            (Some(_), Some(state_machine)) => {
+                format!("{}\n{}", state_machine.name, state_machine.arn)
+            }
+        };
+
+        let block = Block::new()
+            .title(Line::raw("State Machine Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn render_stage_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let title = if self.stage_list.execution_logs {
+            "API Stages (execution logs)"
+        } else {
+            "API Stages (access logs)"
+        };
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let stages: Vec<ListItem> = self
+            .stage_list
+            .stages
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, stage)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{}/", stage.api_name), theme.dimmed),
+                    Span::styled(stage.name.as_str(), theme.text),
+                ]))
+                .bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(stages)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.stage_list.state);
+    }
+
+    fn render_selected_stage(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<FUNCTION_FIELD_WIDTH$}"), theme.dimmed),
+                Span::raw(value),
+            ])
+        };
+        let info = match (&self.stage_list.stages, self.selected_stage()) {
+            (None, _) if self.stage_list.loading => {
+                Text::raw(format!("{} Loading API stages...", self.spinner()))
+            }
+            (None, _) => Text::raw("No API stages available..."),
+            (Some(_), None) => Text::raw("Nothing selected..."),
+            (Some(_), Some(stage)) => {
+                let execution_logs = if stage.execution_logging {
+                    stage.execution_log_group_name()
+                } else {
+                    format!("{} (disabled)", stage.execution_log_group_name())
+                };
+                Text::from(vec![
+                    field("Stage", stage.name.clone()),
+                    field("API", format!("{} ({})", stage.api_name, stage.api_id)),
+                    field(
+                        "Access logs",
+                        stage
+                            .access_log_group_name
+                            .clone()
+                            .unwrap_or_else(|| "disabled".to_string()),
+                    ),
+                    field("Execution logs", execution_logs),
+                ])
+            }
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Stage Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn render_database_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let title = match &self.database_list.log {
+            Some(log) => format!("RDS Databases ({log} logs)"),
+            None => "RDS Databases".to_string(),
+        };
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let databases: Vec<ListItem> = self
+            .database_list
+            .databases
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, database)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(database.identifier.as_str(), theme.text),
+                    Span::styled(format!("  {}", database.engine), theme.dimmed),
+                ]))
+                .bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(databases)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.database_list.state);
+    }
+
+    fn render_selected_database(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<FUNCTION_FIELD_WIDTH$}"), theme.dimmed),
+                Span::raw(value),
+            ])
+        };
+        let info = match (&self.database_list.databases, self.database_list.selected()) {
+            (None, _) if self.database_list.loading => {
+                Text::raw(format!("{} Loading RDS databases...", self.spinner()))
+            }
+            (None, _) => Text::raw("No RDS databases available..."),
+            (Some(_), None) => Text::raw("Nothing selected..."),
+            (Some(_), Some(database)) => {
+                let kind = if database.cluster {
+                    "DB cluster"
+                } else {
+                    "DB instance"
+                };
+                let mut lines = vec![
+                    field("Database", database.identifier.clone()),
+                    field("Engine", format!("{} ({kind})", database.engine)),
+                ];
+                match self.database_list.selected_log() {
+                    Some(log) => lines.extend([
+                        field("Logs", database.log_exports.join(", ")),
+                        field("Viewing", database.log_group_name(log)),
+                    ]),
+                    None => lines.push(field(
+                        "Logs",
+                        "none exported to CloudWatch Logs".to_string(),
+                    )),
+                }
+                Text::from(lines)
+            }
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Database Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn render_project_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let block = Block::new()
+            .title(Line::raw("CodeBuild Projects").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let projects: Vec<ListItem> = self
+            .project_list
+            .projects
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, project)| {
+                let style = if project.log_group_name.is_some() {
+                    theme.text.into()
+                } else {
+                    theme.dimmed
+                };
+                ListItem::new(Span::styled(project.name.as_str(), style)).bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(projects)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut self.project_list.state);
+    }
+
+    fn render_selected_project(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<FUNCTION_FIELD_WIDTH$}"), theme.dimmed),
+                Span::raw(value),
+            ])
+        };
+        let info = match (&self.project_list.projects, self.project_list.selected()) {
+            (None, _) if self.project_list.loading => {
+                Text::raw(format!("{} Loading CodeBuild projects...", self.spinner()))
+            }
+            (None, _) => Text::raw("No CodeBuild projects available..."),
+            (Some(_), None) => Text::raw("Nothing selected..."),
+            (Some(_), Some(project)) => Text::from(vec![
+                field("Project", project.name.clone()),
+                field(
+                    "Log group",
+                    project
+                        .log_group_name
+                        .clone()
+                        .unwrap_or_else(|| "none, CloudWatch Logs are disabled".to_string()),
+                ),
+                Line::raw(""),
+                Line::styled("Press B to browse its recent builds...", theme.dimmed),
+            ]),
+        };
+
+        let block = Block::new()
+            .title(Line::raw("Project Info").centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        Paragraph::new(info)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+
+    fn render_log_stream_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let spinner = self.spinner();
+        let Some(log_stream_list) = &mut self.tabs[self.tab].log_stream_list else {
+            return;
+        };
+
+        let mut title = format!("Log Streams: {}", log_stream_list.log_group_name);
+        if let Some(scope) = &log_stream_list.scope {
+            title.push_str(&format!(" [{}]", scope.label()));
+        }
+        if !log_stream_list.marked.is_empty() {
+            title.push_str(&format!(" ({} marked)", log_stream_list.marked.len()));
+        }
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        if log_stream_list.log_streams.is_empty() {
+            let message = if log_stream_list.loading {
+                format!("{spinner} Loading log streams...")
+            } else {
+                "No log streams...".to_string()
+            };
+            Paragraph::new(message)
+                .block(block.padding(Padding::horizontal(1)))
+                .fg(theme.text)
+                .render(area, buf);
+            return;
+        }
+
+        let timestamp_format = self.timestamp_format;
+        let width = timestamp_format.width();
+        let now = Utc::now();
+        let log_streams: Vec<ListItem> = log_stream_list
+            .log_streams
+            .iter()
+            .enumerate()
+            .map(|(i, log_stream)| {
+                let last_event = log_stream
+                    .last_event_timestamp
+                    .map_or("-".to_string(), |millis| {
+                        timestamp_format.format(millis, now)
+                    });
+                let mark = if log_stream_list.marked.contains(&log_stream.name) {
+                    Span::styled("● ", theme.matched)
+                } else {
+                    Span::raw("  ")
+                };
+                ListItem::new(Line::from(vec![
+                    mark,
+                    Span::styled(
+                        format!("{last_event:<width$}  {}", log_stream.name),
+                        theme.text,
+                    ),
+                ]))
+                .bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(log_streams)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut log_stream_list.state);
+    }
+
+    fn render_invocation_list(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let Some(log_view) = &mut self.tabs[self.tab].log_view else {
+            return;
+        };
+        log_view.levels();
+        let Some(invocation_list) = &mut log_view.invocations else {
+            return;
+        };
+
+        let block = Block::new()
+            .title(
+                Line::raw(format!(
+                    "Invocations: {} ({})",
+                    log_view.log_group_name,
+                    invocation_list.invocations.len()
+                ))
+                .centered(),
+            )
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        if invocation_list.invocations.is_empty() {
+            Paragraph::new("No invocations, Lambda logs START and REPORT lines around them...")
+                .block(block.padding(Padding::horizontal(1)))
+                .fg(theme.text)
+                .render(area, buf);
+            return;
+        }
+
+        let timestamp_format = self.timestamp_format;
+        let width = timestamp_format.width();
+        let now = Utc::now();
+        let invocations: Vec<ListItem> = invocation_list
+            .invocations
+            .iter()
+            .enumerate()
+            .map(|(i, invocation)| {
+                let started = invocation.events.first().map_or("-".to_string(), |&j| {
+                    timestamp_format.format(log_view.events[j].timestamp, now)
+                });
+                let failed = invocation.events.iter().any(|&j| {
+                    log_view.levels[j] == Some(LogLevel::Error)
+                        || log_view.events[j].message.contains("Task timed out")
+                });
+                let (status, color) = match &invocation.report {
+                    _ if failed => ("FAILED ", theme.log_error),
+                    Some(_) => ("OK     ", theme.log_info),
+                    None => ("RUNNING", theme.text),
+                };
+                let stats = invocation.report.as_ref().map_or(String::new(), |report| {
+                    format!(
+                        "{:>10.2} ms {:>5} MB",
+                        report.duration, report.max_memory_used
+                    )
+                });
+                let cold = invocation
+                    .report
+                    .as_ref()
+                    .is_some_and(|report| report.init_duration.is_some());
+
+                let spans = vec![
+                    Span::styled(format!("{started:>width$} "), theme.dimmed),
+                    Span::styled(status, color),
+                    Span::styled(format!("{stats:<22} "), theme.text),
+                    Span::styled(if cold { "cold " } else { "     " }, theme.cold_start),
+                    Span::styled(invocation.request_id.as_str(), theme.text),
+                    Span::styled(
+                        format!(" ({} log events)", invocation.events.len()),
+                        theme.dimmed,
+                    ),
+                ];
+                ListItem::new(Line::from(spans)).bg(theme.alternate_bg(i))
+            })
+            .collect();
+
+        let list = List::new(invocations)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut invocation_list.state);
+    }
+
+    fn render_flow_log_table(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let Some(log_view) = &mut self.tabs[self.tab].log_view else {
+            return;
+        };
+        let Some(flow_logs) = &mut log_view.flow_logs else {
+            return;
+        };
+
+        let mut title = format!(
+            "VPC Flow Logs: {} ({} records)",
+            log_view.log_group_name,
+            flow_logs.shown.len()
+        );
+        if let Some((field, descending)) = flow_logs.sort {
+            let order = if descending {
+                "descending"
+            } else {
+                "ascending"
+            };
+            title.push_str(&format!(", by {} {order}", field.name()));
+        }
+        if let Some((text, _)) = &flow_logs.filter {
+            title.push_str(&format!(", filtered by {text}"));
+        }
+        let block = Block::new()
+            .title(Line::raw(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        if flow_logs.shown.is_empty() {
+            let message = if flow_logs.records.is_empty() {
+                "No flow log records, only records in the default format are shown..."
+            } else {
+                "No flow log records match the filter..."
+            };
+            Paragraph::new(message)
+                .block(block.padding(Padding::horizontal(1)))
+                .fg(theme.text)
+                .render(area, buf);
+            return;
+        }
+
+        let timestamp_format = self.timestamp_format;
+        let now = Utc::now();
+        let mut widths = vec![Constraint::Length(
+            u16::try_from(timestamp_format.width()).unwrap_or(u16::MAX),
+        )];
+        widths.extend(aws::vpc::FlowField::ALL.map(|field| match field {
+            aws::vpc::FlowField::SourceAddress | aws::vpc::FlowField::DestinationAddress => {
+                Constraint::Length(FLOW_ADDRESS_WIDTH)
+            }
+            _ => Constraint::Length(FLOW_COLUMN_WIDTH),
+        }));
+
+        // The sorted column is marked with the direction it is sorted in.
+        let header = Row::new(
+            ["time".to_string()]
+                .into_iter()
+                .chain(aws::vpc::FlowField::ALL.map(|field| match flow_logs.sort {
+                    Some((sorted, descending)) if sorted == field => {
+                        format!("{} {}", field.name(), if descending { "▼" } else { "▲" })
+                    }
+                    _ => field.name().to_string(),
+                }))
+                .map(Cell::from),
+        )
+        .style(theme.header);
+        let rows = flow_logs.shown.iter().enumerate().map(|(i, &record)| {
+            let (event, flow_log) = &flow_logs.records[record];
+            let time = timestamp_format.format(log_view.events[*event].timestamp, now);
+            let cells = [Cell::from(time).style(theme.dimmed)].into_iter().chain(
+                aws::vpc::FlowField::ALL.map(|field| {
+                    let value = flow_log.value(field);
+                    let color = match value.as_str() {
+                        "REJECT" if field == aws::vpc::FlowField::Action => theme.log_error,
+                        "ACCEPT" if field == aws::vpc::FlowField::Action => theme.log_info,
+                        _ => theme.text,
+                    };
+                    Cell::from(value).fg(color)
+                }),
+            );
+            Row::new(cells).bg(theme.alternate_bg(i))
+        });
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(block)
+            .row_highlight_style(theme.selected)
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(table, area, buf, &mut flow_logs.state);
+    }
+
+    fn render_log_view(&mut self, area: Rect, buf: &mut Buffer) {
+        self.volume_area = Rect::default();
+        let theme = &self.theme;
+        let spinner = self.spinner();
+        let tab = &mut self.tabs[self.tab];
+        let Some(log_view) = &mut tab.log_view else {
+            return;
+        };
+        if self.pretty_json {
+            log_view.pretty_messages();
+        }
+        log_view.levels();
+        log_view.summaries();
+        log_view.kubernetes_logs();
+        if self.show_table {
+            log_view.table_rows(&self.columns);
+        } else {
+            log_view.table_rows.clear();
+        }
+        // Widths of the columns of the gutter left of messages, 0 if hidden.
+        let gutters = &self.gutters;
+        let line_number_width = if gutters.line_numbers {
+            log_view.shown.len().to_string().len()
+        } else {
+            0
+        };
+        let timestamp_width = if gutters.timestamps {
+            self.timestamp_format.width()
+        } else {
+            0
+        };
+        let stream_name_width = if gutters.stream_names {
+            STREAM_NAME_WIDTH
+        } else {
+            0
+        };
+        // Merged events are labeled with the function, or log stream, they came from.
+        let source_width = if log_view.is_merged() {
+            SOURCE_WIDTH
+        } else {
+            0
+        };
+        // New events are only marked while tailing, and if marked at all.
+        let marker_width = if log_view.live_tail.is_some() && !self.new_event_highlight.is_zero() {
+            1
+        } else {
+            0
+        };
+        // Each shown column is followed by a space.
+        let gutter_width = [
+            marker_width,
+            line_number_width,
+            timestamp_width,
+            stream_name_width,
+            source_width,
+        ]
+        .into_iter()
+        .filter(|&width| width > 0)
+        .map(|width| width + 1)
+        .sum::<usize>();
+        let now = Utc::now();
+        // Matches are highlighted as the search is edited.
+        let search = match &self.search_input {
+            Some(search_input) => Some(search_input.value.as_str()),
+            None => log_view.search.as_deref(),
+        }
+        .filter(|search| !search.is_empty());
+
+        let mut title = vec![Span::raw(log_view.log_group_name.as_str())];
+        if !log_view.merged_log_group_names.is_empty() {
+            title.push(Span::raw(format!(
+                " + {} more",
+                log_view.merged_log_group_names.len()
+            )));
+        }
+        match log_view.log_stream_names.as_slice() {
+            [] => {}
+            [log_stream_name] => title.push(Span::raw(format!(" › {log_stream_name}"))),
+            log_stream_names => {
+                title.push(Span::raw(format!(
+                    " › {} log streams",
+                    log_stream_names.len()
+                )));
+            }
+        }
+        let time_range = log_view.time_range().unwrap_or(tab.time_range);
+        title.push(Span::raw(format!(" ({})", time_range.label())));
+        if let Some(request_id) = &log_view.invocation {
+            title.push(Span::raw(format!(" › {request_id}")));
+        }
+        let pattern = match &log_view.filter {
+            Some(filter) => filter.pattern.as_ref(),
+            None => log_view
+                .search_pattern
+                .as_ref()
+                .or_else(|| tab.filter_patterns.get(&log_view.log_group_name)),
+        };
+        if let Some(pattern) = pattern {
+            title.push(Span::raw(format!(" [{pattern}]")));
+        }
+        if let Some(regex_filter) = &log_view.regex_filter {
+            title.push(Span::raw(format!(" &{}", regex_filter.as_str())));
+        }
+        if !log_view.hidden_levels.is_empty() {
+            let shown_levels: Vec<&str> = LogLevel::ALL
+                .into_iter()
+                .filter(|level| !log_view.hidden_levels.contains(level))
+                .map(LogLevel::label)
+                .collect();
+            title.push(Span::raw(format!(" {{{}}}", shown_levels.join(","))));
+        }
+        if log_view.cold_starts_only {
+            title.push(Span::raw(" {cold starts}"));
+        }
+        if log_view.regex_filter.is_some()
+            || !log_view.hidden_levels.is_empty()
+            || log_view.cold_starts_only
+        {
+            title.push(Span::raw(format!(" ({} hidden)", log_view.hidden())));
+        }
+        if self.show_table {
+            let paths: Vec<&str> = self
+                .columns
+                .iter()
+                .map(|column| column.path.as_str())
+                .collect();
+            title.push(Span::raw(format!(" columns: {}", paths.join(" │ "))));
+        }
+        if let Some(search) = search {
+            let matching = log_view
+                .shown
+                .iter()
+                .filter(|&&i| {
+                    !log_format::find_matches(&log_view.events[i].message, search).is_empty()
+                })
+                .count();
+            title.push(Span::raw(format!(" /{search} ({matching} matching)")));
+        }
+        if log_view.loading_older {
+            title.push(Span::raw(format!(" {spinner} loading older...")));
+        }
+        let column_offset = if self.wrap_lines {
+            0
+        } else {
+            log_view.column_offset
+        };
+        if column_offset > 0 {
+            title.push(Span::raw(format!(" [from column {}]", column_offset + 1)));
+        }
+        if log_view.live_tail.is_some() && log_view.following() {
+            title.extend([Span::raw(" "), Span::styled(" LIVE ", theme.live)]);
+        } else if log_view.live_tail.is_some() {
+            title.extend([
+                Span::raw(" "),
+                Span::styled(" PAUSED ", theme.dimmed.reversed()),
+            ]);
+            if log_view.unseen_events > 0 {
+                title.push(Span::raw(format!(
+                    " {} new events, Space to follow",
+                    log_view.unseen_events
+                )));
+            }
+        } else if log_view.resuming_live_tail {
+            title.push(Span::raw(format!(" {spinner} resuming live tail...")));
+        }
+
+        let block = Block::new()
+            .title(Line::from(title).centered())
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        if log_view.events.is_empty() {
+            let message = if log_view.loading {
+                format!("{spinner} Loading log events...")
+            } else if log_view.live_tail.is_some() {
+                format!("{spinner} Waiting for new log events...")
+            } else {
+                "No recent log events...".to_string()
+            };
+            Paragraph::new(message)
+                .block(block.padding(Padding::horizontal(1)))
+                .fg(theme.text)
+                .render(area, buf);
+            return;
+        }
+
+        // The volume is drawn above the log events, when there is room for both.
+        let mut area = area;
+        let mut volume_area = Rect::default();
+        log_view.volume = None;
+        if self.show_volume && area.height >= VOLUME_HEIGHT * 2 {
+            [volume_area, area] =
+                Layout::vertical([Constraint::Length(VOLUME_HEIGHT), Constraint::Fill(1)])
+                    .areas(area);
+            let (start, end) = tab.time_range.bounds(now.into());
+            let end = end.unwrap_or(now.timestamp_millis());
+            let timestamps = log_view.shown.iter().map(|&i| log_view.events[i].timestamp);
+            let volume = Volume::new(timestamps, start, end, usize::from(volume_area.width));
+
+            let mut title = format!("Log events per {}", volume.bucket_label());
+            if let Some(peak) = volume.peak() {
+                title.push_str(&format!(
+                    " (peak of {} at {})",
+                    volume.counts[peak],
+                    self.timestamp_format.format(volume.bucket_start(peak), now)
+                ));
+            }
+            let volume_block = Block::new()
+                .title(Line::raw(title).centered())
+                .borders(Borders::TOP)
+                .border_set(symbols::border::EMPTY)
+                .border_style(theme.header)
+                .bg(theme.row_bg);
+            Sparkline::default()
+                .block(volume_block)
+                .data(&volume.counts)
+                .fg(theme.log_info)
+                .render(volume_area, buf);
+            log_view.volume = Some(volume);
+        }
+
+        let pretty_json = self.pretty_json;
+        // Messages are wrapped right of the gutter, which the highlight symbol isn't left of.
+        log_view.wrap_width = self
+            .wrap_lines
+            .then(|| usize::from(block.inner(area).width).saturating_sub(gutter_width));
+        let mut log_state = std::mem::take(&mut log_view.state);
+        let shown = visible_items(
+            &mut log_state,
+            log_view.shown.len(),
+            usize::from(block.inner(area).height),
+            |j| log_view.event_height(log_view.shown[j], pretty_json),
+        );
+        let mut state = window_state(&log_state, &shown);
+        log_view.state = log_state;
+        let events: Vec<ListItem> = log_view.shown[shown.clone()]
+            .iter()
+            .zip(shown)
+            .map(|(&i, position)| {
+                let event = &log_view.events[i];
+                let color = if log_format::is_cold_start(&event.message) {
+                    theme.cold_start
+                } else {
+                    theme.log_level(log_view.levels[i])
+                };
+                let mut columns = log_view
+                    .kubernetes_log(i, pretty_json)
+                    .map(|kubernetes_log| kubernetes_columns(kubernetes_log, theme))
+                    .unwrap_or_default();
+                let indent = if columns.is_empty() {
+                    String::new()
+                } else {
+                    " ".repeat(log_format::KUBERNETES_COLUMNS_WIDTH)
+                };
+                let rows: Vec<&str> = match log_view.wrap_width {
+                    Some(wrap_width) => {
+                        let width = log_view.message_width(i, pretty_json, wrap_width);
+                        log_view
+                            .message_lines(i, pretty_json)
+                            .into_iter()
+                            .flat_map(|line| log_format::wrap(line, width))
+                            .collect()
+                    }
+                    None => log_view
+                        .message_lines(i, pretty_json)
+                        .into_iter()
+                        .map(|line| skip_chars(line, column_offset))
+                        .collect(),
+                };
+
+                // The gutter is left of every row of the message, only filled in left of its
+                // first row, as are any Kubernetes columns.
+                let mut gutter = Vec::new();
+                if marker_width > 0 {
+                    let marker = if log_view.arrived_within(i, self.new_event_highlight) {
+                        "●"
+                    } else {
+                        " "
+                    };
+                    gutter.push(Span::styled(
+                        format!("{marker} "),
+                        theme
+                            .live
+                            .bg
+                            .map_or(theme.dimmed, |color| Style::new().fg(color)),
+                    ));
+                }
+                if line_number_width > 0 {
+                    gutter.push(Span::styled(
+                        format!("{:>line_number_width$} ", position + 1),
+                        theme.dimmed,
+                    ));
+                }
+                if timestamp_width > 0 {
+                    let timestamp = self.timestamp_format.format(event.timestamp, now);
+                    gutter.push(Span::styled(
+                        format!("{timestamp:>timestamp_width$} "),
+                        theme.dimmed,
+                    ));
+                }
+                if stream_name_width > 0 {
+                    let stream_name = event.log_stream_name.as_deref().unwrap_or("-");
+                    // The end of a stream name tells it apart, such as the ID of the
+                    // execution environment of a Lambda function.
+                    let skipped = stream_name
+                        .chars()
+                        .count()
+                        .saturating_sub(stream_name_width);
+                    let stream_name = skip_chars(stream_name, skipped);
+                    gutter.push(Span::styled(
+                        format!("{stream_name:<stream_name_width$} "),
+                        theme.dimmed,
+                    ));
+                }
+                if source_width > 0 {
+                    let source = log_view.source(i).unwrap_or("-");
+                    let skipped = source.chars().count().saturating_sub(source_width);
+                    gutter.push(Span::styled(
+                        format!("{:<source_width$} ", skip_chars(source, skipped)),
+                        Style::new().fg(theme.source(source)),
+                    ));
+                }
+                let lines: Vec<Line> = rows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(j, row)| {
+                        let mut spans = vec![Span::raw(" ".repeat(gutter_width))];
+                        if j == 0 {
+                            spans = std::mem::take(&mut gutter);
+                            spans.append(&mut columns);
+                        } else if !indent.is_empty() {
+                            spans.push(Span::raw(indent.clone()));
+                        }
+                        spans.extend(highlight_matches(row, search, color, theme));
+                        Line::from(spans)
+                    })
+                    .collect();
+                ListItem::new(lines)
+            })
+            .collect();
+
+        let list = List::new(events)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, area, buf, &mut state);
+        self.volume_area = volume_area;
+    }
+}
+
+impl App {
+    /// Render a failed call to AWS in a popup centered over `area`.
+    fn render_error_screen(
+        error_screen: &ErrorScreen,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let height = match error_screen.sso_login {
+            Some(_) => SSO_LOGIN_HEIGHT,
+            None => ERROR_SCREEN_HEIGHT,
+        };
+        let popup_area = centered_area(area, ERROR_SCREEN_WIDTH, height);
+
+        let error = &error_screen.error;
+        let keys = match error_screen.sso_login {
+            Some(_) => " l to sign in, r to retry, Esc to dismiss, q to quit ",
+            None => " r to retry, Esc to dismiss, q to quit ",
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.error)
+            .title(Line::raw(format!(" {} failed ", error.operation())).centered())
+            .title_bottom(Line::raw(keys).centered())
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        let mut text = Text::default();
+        if let Some(code) = error.code() {
+            text.push_line(Line::styled(code, theme.error));
+        }
+        text.push_line(Line::raw(error.message()));
+
+        if let Some(sso_login) = &error_screen.sso_login {
+            text.push_line(Line::default());
+            text.push_line(Line::from(vec![
+                Span::raw("The SSO session expired. Press l to sign in with "),
+                Span::styled(sso_login.command(), theme.header),
+                Span::raw(", or run it in another terminal and press r to retry."),
+            ]));
+            if sso_login.running() || !sso_login.output.is_empty() {
+                text.push_line(Line::default());
+            }
+            for line in &sso_login.output {
+                text.push_line(Line::styled(line.as_str(), theme.dimmed));
+            }
+            if sso_login.running() {
+                text.push_line(Line::styled(
+                    "Waiting for the device to be authorized...",
+                    theme.dimmed,
+                ));
+            }
+            if let Some(error) = &sso_login.error {
+                text.push_line(Line::styled(error.as_str(), theme.error));
+            }
+        }
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(text)
+            .block(block)
+            .fg(theme.text)
+            .wrap(Wrap { trim: false })
+            .render(popup_area, buf);
+    }
+
+    /// Render the toasts stacked over the bottom right of `area`, above the footer, newest at
+    /// the bottom.
+    fn render_toasts(toasts: &Toasts, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let width = TOAST_WIDTH.min(area.width);
+        let inner_width = usize::from(width.saturating_sub(4)).max(1);
+        let mut bottom = area.bottom().saturating_sub(1);
+        for toast in toasts.iter().rev() {
+            let lines = toast
+                .message
+                .chars()
+                .count()
+                .div_ceil(inner_width)
+                .clamp(1, TOAST_MAX_LINES);
+            let height = u16::try_from(lines).unwrap_or(1) + 2;
+            if bottom < area.y + height {
+                break;
+            }
+
+            bottom -= height;
+            let toast_area = Rect::new(area.right() - width, bottom, width, height);
+            let style = match toast.severity {
+                Severity::Info => theme.header,
+                Severity::Warning => Style::from(theme.log_warn),
+                Severity::Error => theme.error,
+            };
+            Clear.render(toast_area, buf);
+            Paragraph::new(toast.message.as_str())
+                .block(
+                    Block::bordered()
+                        .border_type(BorderType::Rounded)
+                        .border_style(style)
+                        .padding(Padding::horizontal(1))
+                        .bg(theme.row_bg),
+                )
+                .fg(theme.text)
+                .wrap(Wrap { trim: true })
+                .render(toast_area, buf);
+        }
+    }
+
+    /// Render a picker in a popup centered over `area`.
+    fn render_picker(picker: &mut Picker, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let popup_area = centered_area(area, PICKER_WIDTH, PICKER_HEIGHT);
+
+        let title = match picker.kind {
+            PickerKind::Profile => " AWS Profiles ",
+            PickerKind::Region => " AWS Regions ",
+            PickerKind::TimeRange => " Time Range ",
+            PickerKind::Version => " Versions & Aliases ",
+            PickerKind::Recent => " Recently Viewed ",
+            PickerKind::Query => " Saved Queries ",
+            PickerKind::Retention => " Retention ",
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(title).centered())
+            .title_bottom(Line::raw(" Enter to switch, Esc to cancel ").centered())
+            .bg(theme.row_bg);
+
+        Clear.render(popup_area, buf);
+        if picker.items.is_empty() {
+            Paragraph::new("Nothing to pick from...")
+                .block(block.padding(Padding::horizontal(1)))
+                .fg(theme.text)
+                .render(popup_area, buf);
+            return;
+        }
+
+        let items: Vec<ListItem> = picker
+            .items
+            .iter()
+            .map(|item| ListItem::new(Line::styled(item.as_str(), theme.text)))
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, popup_area, buf, &mut picker.state);
+    }
+
+    /// Render the time range form in a popup centered over `area`.
+    fn render_time_range_form(
+        time_range_form: &TimeRangeForm,
+        time_zone: TimeZone,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, TIME_RANGE_FORM_WIDTH, TIME_RANGE_FORM_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(" Time Range ").centered())
+            .title_bottom(Line::raw(" Enter to apply, ↓↑ to switch, Esc to cancel ").centered())
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        let fields = [
+            (
+                "Start: ",
+                &time_range_form.start,
+                !time_range_form.editing_end,
+            ),
+            ("End:   ", &time_range_form.end, time_range_form.editing_end),
+        ];
+        let mut lines: Vec<Line> = fields
+            .into_iter()
+            .map(|(label, input, editing)| {
+                let mut spans = vec![Span::raw(label).bold()];
+                if editing {
+                    spans.extend(input.to_spans());
+                } else {
+                    spans.push(Span::raw(input.value.as_str()));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        let time_zone = match time_zone {
+            TimeZone::Utc => "UTC",
+            TimeZone::Local => "local time",
+        };
+        lines.push(Line::default());
+        lines.push(Line::styled(
+            format!("Like 2024-06-01 13:00, in {time_zone}"),
+            theme.dimmed,
+        ));
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(lines)
+            .block(block)
+            .fg(theme.text)
+            .render(popup_area, buf);
+    }
+
+    /// Render the Logs Insights query being built in a popup centered over `area`, with an
+    /// example of the arguments of each command, and the query they build.
+    /// Render the question of a confirmation in a popup centered over `area`.
+    fn render_confirmation(
+        confirmation: &Confirmation,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(" Confirm ").centered())
+            .title_bottom(Line::raw(" y to confirm, n to cancel ").centered())
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+        let lines = log_format::wrap(
+            &confirmation.question,
+            usize::from(CONFIRMATION_WIDTH.saturating_sub(4)),
+        );
+        let height = u16::try_from(lines.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2);
+        let popup_area = centered_area(area, CONFIRMATION_WIDTH, height);
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(
+            lines
+                .into_iter()
+                .map(|line| Line::raw(line.to_string()))
+                .collect::<Vec<_>>(),
+        )
+        .block(block)
+        .fg(theme.text)
+        .render(popup_area, buf);
+    }
+
+    fn render_query_builder(
+        query_builder: &QueryBuilder,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, QUERY_BUILDER_WIDTH, QUERY_BUILDER_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(" Build Query ").centered())
+            .title_bottom(
+                Line::raw(" Enter to run, ↓↑ to switch, Tab to edit as text, Esc to cancel ")
+                    .centered(),
+            )
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+        let inner_width = usize::from(block.inner(popup_area).width);
+
+        let mut lines = Vec::new();
+        for (i, ((command, example), arguments)) in query_builder::COMMANDS
+            .iter()
+            .zip(&query_builder.arguments)
+            .enumerate()
+        {
+            let mut spans = vec![Span::raw(format!("{command:<7}")).bold()];
+            if i == query_builder.editing {
+                spans.extend(arguments.to_spans());
+            } else {
+                spans.push(Span::raw(arguments.value.as_str()));
+            }
+            lines.push(Line::from(spans));
+            lines.push(Line::styled(
+                format!("{:7}like {example}", ""),
+                theme.dimmed,
+            ));
+        }
+        lines.push(Line::default());
+        lines.extend(
+            log_format::wrap(&query_builder.query(), inner_width)
+                .into_iter()
+                .map(|row| Line::styled(row.to_string(), theme.dimmed)),
+        );
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(lines)
+            .block(block)
+            .fg(theme.text)
+            .render(popup_area, buf);
+    }
+
+    /// Render the command palette in a popup centered over `area`.
+    fn render_palette(palette: &mut Palette, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let popup_area = centered_area(area, PICKER_WIDTH, PICKER_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(format!(" {} Commands ", palette.mode.title())).centered())
+            .title_bottom(Line::raw(" Enter to run, Esc to cancel ").centered())
+            .bg(theme.row_bg);
+        let inner_area = block.inner(popup_area);
+        let [query_area, commands_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(inner_area);
+
+        Clear.render(popup_area, buf);
+        block.render(popup_area, buf);
+
+        let mut spans = vec![Span::raw(" :").bold()];
+        spans.extend(palette.query.to_spans());
+        Paragraph::new(Line::from(spans))
+            .fg(theme.text)
+            .render(query_area, buf);
+
+        if palette.matches.is_empty() {
+            Paragraph::new(" No matching commands...")
+                .style(theme.dimmed)
+                .render(commands_area, buf);
+            return;
+        }
+
+        let items: Vec<ListItem> = palette
+            .matches
+            .iter()
+            .map(|command_match| {
+                let spans: Vec<Span> = command_match
+                    .action
+                    .description()
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| {
+                        if command_match.positions.contains(&i) {
+                            Span::styled(c.to_string(), theme.matched)
+                        } else {
+                            Span::raw(c.to_string())
+                        }
+                    })
+                    .collect();
+                ListItem::new(Line::from(spans).fg(theme.text))
+            })
+            .collect();
+        let list = List::new(items)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, commands_area, buf, &mut palette.state);
+    }
+
+    /// Render the environment variables of a function in a popup centered over `area`.
+    fn render_environment(
+        environment: &mut EnvironmentView,
+        details: Option<&FunctionDetails<aws::lambda::FunctionConfiguration>>,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, ENVIRONMENT_WIDTH, ENVIRONMENT_HEIGHT);
+
+        let reveal = if environment.revealed {
+            "v to mask"
+        } else {
+            "v to reveal"
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(format!(" Environment of {} ", environment.function.name)).centered())
+            .title_bottom(Line::raw(format!(" ↓↑ to scroll, {reveal}, Esc to close ")).centered())
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        let lines: Vec<Line> = match details {
+            None | Some(FunctionDetails::Loading) => {
+                vec![Line::raw(format!("{spinner} Loading configuration..."))]
+            }
+            Some(FunctionDetails::Failed(error)) => {
+                vec![Line::styled(error.to_string(), theme.error)]
+            }
+            Some(FunctionDetails::Loaded(configuration))
+                if configuration.environment.is_empty() =>
+            {
+                vec![Line::raw("No environment variables...")]
+            }
+            Some(FunctionDetails::Loaded(configuration)) => configuration
+                .environment
+                .iter()
+                .map(|(name, value)| {
+                    let value = if environment.revealed {
+                        value.as_str()
+                    } else {
+                        MASKED_VALUE
+                    };
+                    Line::from(vec![
+                        Span::styled(name.as_str(), theme.matched),
+                        Span::raw("="),
+                        Span::raw(value),
+                    ])
+                })
+                .collect(),
+        };
+
+        // Stop scrolling once the last line is shown.
+        let inner_height = block.inner(popup_area).height as usize;
+        environment.scroll = environment
+            .scroll
+            .min(lines.len().saturating_sub(inner_height));
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(lines)
+            .block(block)
+            .fg(theme.text)
+            .scroll((environment.scroll as u16, 0))
+            .render(popup_area, buf);
+    }
+
+    /// Render the whole of a log event in a popup centered over `area`, wrapping its message
+    /// rather than cutting it off.
+    fn render_event(
+        event_view: &mut EventView,
+        timestamp_format: TimestampFormat,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, EVENT_WIDTH, EVENT_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(" Log Event ").centered())
+            .title_bottom(
+                Line::raw(if event_view.tree.is_some() {
+                    " ↓↑ to move, Enter to expand or collapse, Esc to close "
+                } else {
+                    " ↓↑ to scroll, Esc to close "
+                })
+                .centered(),
+            )
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+        let inner_width = usize::from(block.inner(popup_area).width);
+
+        // Times are shown in full, even while others are shown relative to now.
+        let timestamp_format = TimestampFormat {
+            style: TimestampStyle::Iso,
+            ..timestamp_format
+        };
+        let now = Utc::now();
+        let event = &event_view.event;
+        let field = |name: &'static str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<FUNCTION_FIELD_WIDTH$}"), theme.dimmed),
+                Span::raw(value),
+            ])
+        };
+        let mut lines = vec![
+            field("Timestamp", timestamp_format.format(event.timestamp, now)),
+            field(
+                "Ingested",
+                timestamp_format.format(event.ingestion_time, now),
+            ),
+            field(
+                "Log stream",
+                event
+                    .log_stream_name
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Line::default(),
+        ];
+        let inner_height = block.inner(popup_area).height as usize;
+        match &event_view.tree {
+            Some(tree) => {
+                if let Some((prefix, _)) = log_format::parse_json(&event.message)
+                    && !prefix.is_empty()
+                {
+                    lines.extend(
+                        log_format::wrap(prefix, inner_width)
+                            .into_iter()
+                            .map(|row| Line::raw(row.to_string())),
+                    );
+                }
+
+                // Keep the selected row in view.
+                let selected = lines.len() + tree.selected;
+                event_view.scroll = event_view
+                    .scroll
+                    .clamp((selected + 1).saturating_sub(inner_height.max(1)), selected);
+
+                lines.extend(tree.rows().into_iter().enumerate().map(|(i, row)| {
+                    let marker = match row.collapsed {
+                        Some(true) => "▸ ",
+                        Some(false) => "▾ ",
+                        None => "  ",
+                    };
+                    let mut spans = vec![Span::raw(format!("{}{marker}", "  ".repeat(row.depth)))];
+                    if let Some(key) = row.key {
+                        spans.push(Span::styled(format!("{key}: "), theme.header));
+                    }
+                    spans.push(if row.collapsed.is_some() {
+                        Span::styled(row.value, theme.dimmed)
+                    } else {
+                        Span::raw(row.value)
+                    });
+                    let line = Line::from(spans);
+                    if i == tree.selected {
+                        line.style(theme.selected)
+                    } else {
+                        line
+                    }
+                }));
+            }
+            None => {
+                lines.extend(
+                    event
+                        .message
+                        .trim_end()
+                        .lines()
+                        .flat_map(|line| log_format::wrap(line, inner_width))
+                        .map(|row| Line::raw(row.to_string())),
+                );
+            }
+        }
+
+        // Stop scrolling once the last line is shown.
+        event_view.scroll = event_view
+            .scroll
+            .min(lines.len().saturating_sub(inner_height));
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(lines)
+            .block(block)
+            .fg(theme.text)
+            .scroll((event_view.scroll as u16, 0))
+            .render(popup_area, buf);
+    }
+
+    /// Render the timeline of an X-Ray trace in a popup centered over `area`, with a bar for
+    /// when each segment ran.
+    fn render_trace(
+        trace_view: &mut TraceView,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, TRACE_WIDTH, TRACE_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(format!(" Trace {} ", trace_view.trace_id)).centered())
+            .title_bottom(Line::raw(" ↓↑ to scroll, Esc to close ").centered())
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+        let inner_width = usize::from(block.inner(popup_area).width);
+
+        let lines: Vec<Line> = match &trace_view.trace {
+            None => vec![Line::raw(format!("{spinner} Loading trace..."))],
+            Some(Err(error)) => vec![Line::styled(error.to_string(), theme.error)],
+            Some(Ok(None)) => vec![Line::raw(
+                "No such trace yet, X-Ray takes a few seconds to process traces...",
+            )],
+            Some(Ok(Some(trace))) => {
+                let start = trace.start_time().unwrap_or_default();
+                let end = trace
+                    .segments
+                    .iter()
+                    .filter_map(|segment| segment.end_time)
+                    .fold(start, f64::max);
+                let total = (end - start).max(f64::EPSILON);
+                let bar_width = inner_width.saturating_sub(TRACE_NAME_WIDTH + TRACE_DURATION_WIDTH);
+
+                trace
+                    .segments
+                    .iter()
+                    .map(|segment| {
+                        let name = format!("{}{}", "  ".repeat(segment.depth), segment.name);
+                        let name: String = name.chars().take(TRACE_NAME_WIDTH - 1).collect();
+                        let segment_end = segment.end_time.unwrap_or(end);
+                        // Columns of the bar, from the start of the trace.
+                        let column = |time: f64| {
+                            ((time - start) / total * bar_width as f64).round() as usize
+                        };
+                        let offset = column(segment.start_time).min(bar_width.saturating_sub(1));
+                        let len = column(segment_end).saturating_sub(offset).max(1);
+                        let duration = match segment.end_time {
+                            Some(end_time) => {
+                                format!("{:.0} ms", (end_time - segment.start_time) * 1000.0)
+                            }
+                            None => "running".to_string(),
+                        };
+                        let color = if segment.failed {
+                            theme.log_error
+                        } else {
+                            theme.log_info
+                        };
+
+                        Line::from(vec![
+                            Span::raw(format!("{name:<TRACE_NAME_WIDTH$}")),
+                            Span::raw(" ".repeat(offset)),
+                            Span::styled("█".repeat(len), color),
+                            Span::raw(" ".repeat(bar_width.saturating_sub(offset + len))),
+                            Span::styled(
+                                format!("{duration:>TRACE_DURATION_WIDTH$}"),
+                                theme.dimmed,
+                            ),
+                        ])
+                    })
+                    .collect()
+            }
+        };
+
+        // Stop scrolling once the last line is shown.
+        let inner_height = block.inner(popup_area).height as usize;
+        trace_view.scroll = trace_view
+            .scroll
+            .min(lines.len().saturating_sub(inner_height));
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(lines)
+            .block(block)
+            .fg(theme.text)
+            .scroll((trace_view.scroll as u16, 0))
+            .render(popup_area, buf);
+    }
+
+    /// Render the executions of a state machine, or the event history of one of them, in a
+    /// popup centered over `area`.
+    fn render_executions(
+        execution_view: &mut ExecutionView,
+        timestamp_format: TimestampFormat,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, EXECUTIONS_WIDTH, EXECUTIONS_HEIGHT);
+        let width = timestamp_format.width();
+        let now = Utc::now();
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+        Clear.render(popup_area, buf);
+
+        if let Some(execution_history) = &mut execution_view.history {
+            let block = block
+                .title(Line::raw(format!(" {} ", execution_history.execution.name)).centered())
+                .title_bottom(
+                    Line::raw(" ↓↑ to scroll, L for logs of functions, Esc to go back ").centered(),
+                )
+                .padding(Padding::horizontal(1));
+
+            let lines: Vec<Line> = match &execution_history.events {
+                None => vec![Line::raw(format!("{spinner} Loading event history..."))],
+                Some(Err(error)) => vec![Line::styled(error.to_string(), theme.error)],
+                Some(Ok(events)) => events
+                    .iter()
+                    .flat_map(|event| {
+                        let style = if event.failed() {
+                            theme.log_error
+                        } else {
+                            theme.text
+                        };
+                        let subject = event
+                            .state_name
+                            .as_deref()
+                            .or(event.function_name.as_deref())
+                            .unwrap_or_default();
+                        let line = Line::from(vec![
+                            Span::styled(
+                                format!(
+                                    "{:<width$}  ",
+                                    timestamp_format.format(event.timestamp, now)
+                                ),
+                                theme.dimmed,
+                            ),
+                            Span::styled(
+                                format!("{:<HISTORY_EVENT_TYPE_WIDTH$}  ", event.event_type),
+                                style,
+                            ),
+                            Span::styled(subject.to_string(), theme.text),
+                        ]);
+                        let error = event
+                            .error
+                            .as_ref()
+                            .map(|error| Line::styled(format!("  {error}"), theme.log_error));
+                        std::iter::once(line).chain(error)
+                    })
+                    .collect(),
+            };
+
+            // Stop scrolling once the last line is shown.
+            let inner_height = block.inner(popup_area).height as usize;
+            execution_history.scroll = execution_history
+                .scroll
+                .min(lines.len().saturating_sub(inner_height));
+
+            Paragraph::new(lines)
+                .block(block)
+                .fg(theme.text)
+                .scroll((execution_history.scroll as u16, 0))
+                .render(popup_area, buf);
+            return;
+        }
+
+        let block = block
+            .title(
+                Line::raw(format!(
+                    " Executions of {} ",
+                    execution_view.state_machine.name
+                ))
+                .centered(),
+            )
+            .title_bottom(
+                Line::raw(" Enter for history, L for logs of functions, Esc to close ").centered(),
+            );
+        let executions = match &execution_view.executions {
+            Some(Ok(executions)) if !executions.is_empty() => executions,
+            executions => {
+                let message = match executions {
+                    None => Line::raw(format!("{spinner} Loading executions...")),
+                    Some(Err(error)) => Line::styled(error.to_string(), theme.error),
+                    Some(Ok(_)) => Line::raw("No executions..."),
+                };
+                Paragraph::new(message)
+                    .block(block.padding(Padding::horizontal(1)))
+                    .fg(theme.text)
+                    .render(popup_area, buf);
+                return;
+            }
+        };
+
+        let items: Vec<ListItem> = executions
+            .iter()
+            .map(|execution| {
+                let status_style = if execution.failed() {
+                    theme.log_error
+                } else {
+                    theme.log_info
+                };
+                let duration = match execution.stop_date {
+                    Some(stop_date) => {
+                        format!(
+                            "{:.1} s",
+                            (stop_date - execution.start_date) as f64 / 1000.0
+                        )
+                    }
+                    None => "-".to_string(),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<EXECUTION_STATUS_WIDTH$}  ", execution.status),
+                        status_style,
+                    ),
+                    Span::styled(
+                        format!(
+                            "{:<width$}  ",
+                            timestamp_format.format(execution.start_date, now)
+                        ),
+                        theme.dimmed,
+                    ),
+                    Span::styled(
+                        format!("{duration:>EXECUTION_DURATION_WIDTH$}  "),
+                        theme.dimmed,
+                    ),
+                    Span::styled(execution.name.as_str(), theme.text),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, popup_area, buf, &mut execution_view.state);
+    }
+
+    /// Render the event source mappings of a function in a popup centered over `area`, with
+    /// disabled or failing mappings highlighted.
+    fn render_event_sources(
+        event_source_view: &mut EventSourceView,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, EVENT_SOURCES_WIDTH, EVENT_SOURCES_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .title(
+                Line::raw(format!(
+                    " Event sources of {} ",
+                    event_source_view.function.name
+                ))
+                .centered(),
+            )
+            .title_bottom(Line::raw(" Esc to close ").centered());
+        Clear.render(popup_area, buf);
+
+        let mappings = match &event_source_view.mappings {
+            Some(Ok(mappings)) if !mappings.is_empty() => mappings,
+            mappings => {
+                let message = match mappings {
+                    None => Line::raw(format!("{spinner} Loading event source mappings...")),
+                    Some(Err(error)) => Line::styled(error.to_string(), theme.error),
+                    Some(Ok(_)) => Line::raw("No event source mappings..."),
+                };
+                Paragraph::new(message)
+                    .block(block.padding(Padding::horizontal(1)))
+                    .fg(theme.text)
+                    .render(popup_area, buf);
+                return;
+            }
+        };
+
+        let name_width = mappings
+            .iter()
+            .map(|mapping| mapping.source().map_or(0, |(_, name)| name.chars().count()))
+            .max()
+            .unwrap_or_default();
+        let items: Vec<ListItem> = mappings
+            .iter()
+            .map(|mapping| {
+                let state_style = if mapping.is_failing() {
+                    theme.log_error
+                } else {
+                    theme.log_info
+                };
+                let state = mapping.state.as_deref().unwrap_or("-");
+                let (kind, name) = mapping.source().unwrap_or(("-", ""));
+                let batch_size = mapping
+                    .batch_size
+                    .map_or_else(String::new, |batch_size| format!("batch {batch_size}"));
+                // Only stream sources have a result, otherwise why the state last changed helps.
+                let result = match &mapping.last_processing_result {
+                    Some(result) => Span::styled(result.clone(), state_style),
+                    None => Span::styled(
+                        mapping.state_transition_reason.clone().unwrap_or_default(),
+                        theme.dimmed,
+                    ),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{state:<EVENT_SOURCE_STATE_WIDTH$}  "), state_style),
+                    Span::styled(format!("{kind:<EVENT_SOURCE_KIND_WIDTH$}  "), theme.dimmed),
+                    Span::styled(format!("{name:<name_width$}  "), theme.text),
+                    Span::styled(
+                        format!("{batch_size:<EVENT_SOURCE_BATCH_WIDTH$}  "),
+                        theme.dimmed,
+                    ),
+                    result,
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, popup_area, buf, &mut event_source_view.state);
+    }
+
+    /// Render the failed events of a function in a popup centered over `area`, with the error
+    /// each failed with, if told.
+    fn render_failed_events(
+        failed_event_view: &mut FailedEventView,
+        timestamp_format: TimestampFormat,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, FAILED_EVENTS_WIDTH, FAILED_EVENTS_HEIGHT);
+        let width = timestamp_format.width();
+        let now = Utc::now();
+        let queue_name = aws::sqs::parse_queue_arn(&failed_event_view.queue_arn)
+            .map_or(failed_event_view.queue_arn.as_str(), |(_, queue_name)| {
+                queue_name
+            });
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .title(
+                Line::raw(format!(
+                    " Failed events of {} in {queue_name} ",
+                    failed_event_view.function.name
+                ))
+                .centered(),
+            )
+            .title_bottom(
+                Line::raw(" Enter to show, l for logs of the invocation, Esc to close ").centered(),
+            );
+        Clear.render(popup_area, buf);
+
+        let messages = match &failed_event_view.messages {
+            Some(Ok(messages)) if !messages.is_empty() => messages,
+            messages => {
+                let message = match messages {
+                    None => Line::raw(format!("{spinner} Peeking at failed events...")),
+                    Some(Err(error)) => Line::styled(error.to_string(), theme.error),
+                    Some(Ok(_)) => Line::raw("No failed events..."),
+                };
+                Paragraph::new(message)
+                    .block(block.padding(Padding::horizontal(1)))
+                    .fg(theme.text)
+                    .render(popup_area, buf);
+                return;
+            }
+        };
+
+        let items: Vec<ListItem> = messages
+            .iter()
+            .map(|message| {
+                let sent = message.sent_timestamp.map_or_else(
+                    || "-".to_string(),
+                    |sent_timestamp| timestamp_format.format(sent_timestamp, now),
+                );
+                let request_id = message.request_id().unwrap_or_else(|| "-".to_string());
+                let error = match message.error_message() {
+                    Some(error_message) => Span::styled(error_message, theme.log_error),
+                    None => Span::styled(message.body.replace('\n', " "), theme.text),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{sent:<width$}  "), theme.dimmed),
+                    Span::styled(format!("{request_id:<REQUEST_ID_WIDTH$}  "), theme.text),
+                    error,
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, popup_area, buf, &mut failed_event_view.state);
+    }
+
+    /// Render the recent builds of a CodeBuild project in a popup centered over `area`.
+    fn render_builds(
+        build_view: &mut BuildView,
+        timestamp_format: TimestampFormat,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, BUILDS_WIDTH, BUILDS_HEIGHT);
+        let width = timestamp_format.width();
+        let now = Utc::now();
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .title(Line::raw(format!(" Builds of {} ", build_view.project.name)).centered())
+            .title_bottom(
+                Line::raw(" Enter for logs, tailed while running, Esc to close ").centered(),
+            );
+        Clear.render(popup_area, buf);
+
+        let builds = match &build_view.builds {
+            Some(Ok(builds)) if !builds.is_empty() => builds,
+            builds => {
+                let message = match builds {
+                    None => Line::raw(format!("{spinner} Loading builds...")),
+                    Some(Err(error)) => Line::styled(error.to_string(), theme.error),
+                    Some(Ok(_)) => Line::raw("No builds..."),
+                };
+                Paragraph::new(message)
+                    .block(block.padding(Padding::horizontal(1)))
+                    .fg(theme.text)
+                    .render(popup_area, buf);
+                return;
+            }
+        };
+
+        let items: Vec<ListItem> = builds
+            .iter()
+            .map(|build| {
+                let status_style = if build.failed() {
+                    theme.log_error
+                } else if build.in_progress() {
+                    theme.text
+                } else {
+                    theme.log_info
+                };
+                let number = build
+                    .number
+                    .map_or_else(|| "-".to_string(), |number| format!("#{number}"));
+                let duration = match build.end_time {
+                    Some(end_time) => {
+                        format!("{:.1} s", (end_time - build.start_time) as f64 / 1000.0)
+                    }
+                    None => build.current_phase.clone().unwrap_or_default(),
+                };
+                let source = [build.source_version.as_deref(), build.initiator.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" by ");
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<BUILD_STATUS_WIDTH$}  ", build.status),
+                        status_style,
+                    ),
+                    Span::styled(format!("{number:<BUILD_NUMBER_WIDTH$}  "), theme.text),
+                    Span::styled(
+                        format!(
+                            "{:<width$}  ",
+                            timestamp_format.format(build.start_time, now)
+                        ),
+                        theme.dimmed,
+                    ),
+                    Span::styled(format!("{duration:>BUILD_DURATION_WIDTH$}  "), theme.text),
+                    Span::styled(source, theme.dimmed),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, popup_area, buf, &mut build_view.state);
+    }
+
+    /// Render the CloudTrail events of a resource in a popup centered over `area`, with failed
+    /// calls highlighted and calls that only read resources dimmed.
+    fn render_cloudtrail(
+        cloudtrail: &mut CloudTrailView,
+        timestamp_format: TimestampFormat,
+        spinner: &str,
+        theme: &Theme,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let popup_area = centered_area(area, CLOUDTRAIL_WIDTH, CLOUDTRAIL_HEIGHT);
+        let width = timestamp_format.width();
+        let now = Utc::now();
+
+        let resource_name = match cloudtrail.resource_name.as_str() {
+            "" => "all resources",
+            resource_name => resource_name,
+        };
+        let title = match cloudtrail.event_name.as_str() {
+            "" => format!(" CloudTrail events of {resource_name} "),
+            event_name => {
+                format!(" CloudTrail events of {resource_name}, named like {event_name} ")
+            }
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(title).centered())
+            .title_bottom(
+                Line::raw(" f to filter by resource, / by event name, Esc to close ").centered(),
+            )
+            .bg(theme.row_bg);
+        Clear.render(popup_area, buf);
+
+        let events = match &cloudtrail.events {
+            Some(Ok(events)) if !events.is_empty() => events,
+            events => {
+                let message = match events {
+                    None => Line::raw(format!("{spinner} Loading CloudTrail events...")),
+                    Some(Err(error)) => Line::styled(error.to_string(), theme.error),
+                    Some(Ok(_)) => Line::raw("No CloudTrail events in the time range..."),
+                };
+                Paragraph::new(message)
+                    .block(block.padding(Padding::horizontal(1)))
+                    .fg(theme.text)
+                    .render(popup_area, buf);
+                return;
+            }
+        };
+
+        let items: Vec<ListItem> = events
+            .iter()
+            .map(|event| {
+                let style = if event.details.error_code.is_some() {
+                    theme.log_error.into()
+                } else if event.details.read_only == Some(true) {
+                    theme.dimmed
+                } else {
+                    theme.text.into()
+                };
+                let username = event.username.as_deref().unwrap_or("-");
+                let username: String = username.chars().take(TRAIL_USERNAME_WIDTH).collect();
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:<width$}  ", timestamp_format.format(event.time, now)),
+                        theme.dimmed,
+                    ),
+                    Span::styled(format!("{:<TRAIL_EVENT_NAME_WIDTH$}  ", event.name), style),
+                    Span::styled(format!("{username:<TRAIL_USERNAME_WIDTH$}  "), theme.text),
+                    Span::styled(event.resources.join(", "), theme.dimmed),
+                ];
+                if let Some(error_code) = &event.details.error_code {
+                    spans.push(Span::styled(format!("  {error_code}"), theme.log_error));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.selected)
+            .highlight_symbol(">")
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(list, popup_area, buf, &mut cloudtrail.state);
+    }
+
+    /// Render the keybindings of every mode in a popup centered over `area`.
+    ///
+    /// The mode the help was opened from is listed first.
+    fn render_help(help: &mut HelpView, theme: &Theme, area: Rect, buf: &mut Buffer) {
+        let popup_area = centered_area(area, HELP_WIDTH, HELP_HEIGHT);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(theme.header)
+            .title(Line::raw(" Keybindings ").centered())
+            .title_bottom(Line::raw(" ↓↑ to scroll, Esc to close ").centered())
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+
+        let modes = std::iter::once(help.mode)
+            .chain(Mode::ALL.into_iter().filter(|&mode| mode != help.mode));
+        let mut lines = Vec::new();
+        for mode in modes {
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::styled(mode.title(), theme.header));
+            for binding in mode.bindings() {
+                let keys = binding
+                    .keys
+                    .iter()
+                    .map(|key| key.label())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{keys:<HELP_KEYS_WIDTH$}"), theme.matched),
+                    Span::raw(binding.action.description()),
+                ]));
+            }
+        }
+
+        // Stop scrolling once the last line is shown.
+        let inner_height = block.inner(popup_area).height as usize;
+        help.scroll = help.scroll.min(lines.len().saturating_sub(inner_height));
+
+        Clear.render(popup_area, buf);
+        Paragraph::new(lines)
+            .block(block)
+            .fg(theme.text)
+            .scroll((help.scroll as u16, 0))
+            .render(popup_area, buf);
+    }
+
+    fn render_insights_view(&mut self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        let spinner = self.spinner();
+        let tab = &mut self.tabs[self.tab];
+        let Some(insights_view) = &mut tab.insights_view else {
+            return;
+        };
+
+        let [query_area, results_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(area);
+
+        let query_block = Block::new()
+            .title(
+                Line::raw(format!(
+                    "Logs Insights: {} ({})",
+                    insights_view.log_group_name,
+                    tab.time_range.label()
+                ))
+                .centered(),
+            )
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg)
+            .padding(Padding::horizontal(1));
+        let query = if insights_view.editing {
+            Line::from(insights_view.query.to_spans())
+        } else {
+            Line::raw(insights_view.query.value.as_str())
+        };
+        Paragraph::new(query)
+            .block(query_block)
+            .fg(theme.text)
+            .render(query_area, buf);
+
+        let results_block = Block::new()
+            .title(
+                Line::raw(match &insights_view.results {
+                    Some(results) => {
+                        format!("Results ({}, {} rows)", results.status, results.rows.len())
+                    }
+                    None => "Results".to_string(),
+                })
+                .centered(),
+            )
+            .borders(Borders::TOP)
+            .border_set(symbols::border::EMPTY)
+            .border_style(theme.header)
+            .bg(theme.row_bg);
+
+        let results = match &insights_view.results {
+            Some(results) if !insights_view.running => results,
+            _ => {
+                let message = if insights_view.running {
+                    format!("{spinner} Running query...")
+                } else {
+                    "Press Enter to run the query...".to_string()
+                };
+                Paragraph::new(message)
+                    .block(results_block.padding(Padding::horizontal(1)))
+                    .fg(theme.text)
+                    .render(results_area, buf);
+                return;
+            }
+        };
+
+        // Size columns to their content, letting the last (usually `@message`) fill the rest.
+        let widths = results.columns.iter().enumerate().map(|(column, name)| {
+            if column + 1 == results.columns.len() {
+                return Constraint::Fill(1);
+            }
+            let width = results
+                .rows
+                .iter()
+                .map(|row| row.values[column].chars().count())
+                // Room for the marker of the sorted column.
+                .chain([name.chars().count() + 2])
+                .max()
+                .unwrap_or_default();
+            Constraint::Length(
+                u16::try_from(width)
+                    .unwrap_or(u16::MAX)
+                    .min(MAX_INSIGHTS_COLUMN_WIDTH),
+            )
+        });
+
+        let header = Row::new(results.columns.iter().map(|name| {
+            Cell::from(match &insights_view.sort {
+                Some((sorted, descending)) if sorted == name => {
+                    format!("{name} {}", if *descending { "▼" } else { "▲" })
+                }
+                _ => name.clone(),
+            })
+        }))
+        .style(theme.header);
+        let rows = insights_view.shown.iter().enumerate().map(|(i, &row)| {
+            Row::new(
+                results.rows[row]
+                    .values
+                    .iter()
+                    .map(|value| Cell::from(value.trim_end())),
+            )
+            .bg(theme.alternate_bg(i))
+            .fg(theme.text)
+        });
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(results_block)
+            .row_highlight_style(theme.selected)
+            .highlight_spacing(HighlightSpacing::Always);
+
+        StatefulWidget::render(table, results_area, buf, &mut insights_view.state);
+    }
+}
+
+/// The size of `bytes` in the largest unit it has one of, such as `1.2 GB`.
+fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1000 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1000.0;
+    let mut unit = 0;
+    while size >= 1000.0 && unit + 1 < UNITS.len() {
+        size /= 1000.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Format milliseconds since the Unix epoch as a UTC date and time.
+/// An area of at most `width` by `height`, centered in `area`.
+fn centered_area(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// A function, with the characters of its name at the given positions highlighted.
+struct ListItemFunction<'a> {
+    function: &'a aws::lambda::Function,
+    positions: &'a [usize],
+    // Whether to prefix the function with its region, when listing several regions.
+    show_region: bool,
+    // Whether the function is a favorite, if any function is, to mark favorites with a star.
+    favorite: Option<bool>,
+    // Whether the function is marked to tail, if any function is.
+    marked: Option<bool>,
+    // When the function last logged, once loaded.
+    last_activity: Option<String>,
+    // What the functions are sorted by, such as when the function was last modified, when not
+    // sorted by name.
+    sorted_by: Option<String>,
+    theme: &'a Theme,
+}
+
+impl<'a> From<ListItemFunction<'a>> for Line<'a> {
+    fn from(value: ListItemFunction<'a>) -> Self {
+        let ListItemFunction {
+            function,
+            positions,
+            show_region,
+            favorite,
+            marked,
+            last_activity,
+            sorted_by,
+            theme,
+        } = value;
+
+        let mut spans = Vec::new();
+        match marked {
+            Some(true) => spans.push(Span::styled("● ", theme.matched)),
+            Some(false) => spans.push(Span::raw("  ")),
+            None => {}
+        }
+        match favorite {
+            Some(true) => spans.push(Span::styled("★ ", theme.matched)),
+            Some(false) => spans.push(Span::raw("  ")),
+            None => {}
+        }
+        if show_region {
+            let region = function.region.as_deref().unwrap_or("-");
+            spans.push(Span::styled(
+                format!("{region:<REGION_COLUMN_WIDTH$}"),
+                theme.dimmed,
+            ));
+        }
+        if positions.is_empty() {
+            spans.push(Span::styled(function.name.as_str(), theme.text));
+        } else {
+            spans.extend(function.name.chars().enumerate().map(|(i, c)| {
+                if positions.contains(&i) {
+                    Span::styled(c.to_string(), theme.matched)
+                } else {
+                    Span::styled(c.to_string(), theme.text)
+                }
+            }));
+        }
+        if let Some(last_activity) = last_activity {
+            spans.push(Span::styled(format!("  {last_activity}"), theme.dimmed));
+        }
+        if let Some(sorted_by) = sorted_by {
+            spans.push(Span::styled(format!("  {sorted_by}"), theme.dimmed));
+        }
+        Line::from(spans)
+    }
+}
+
+impl<'a> From<ListItemFunction<'a>> for Text<'a> {
+    fn from(value: ListItemFunction<'a>) -> Self {
+        Text::from(Line::from(value))
+    }
+}
+
+/// The items of a list of `len` items that fit in `height` rows, given the height of each,
+/// scrolling `state` to keep the selected item shown like a [`List`] would.
+///
+/// Only the shown items are then built, and rendered with a [`window_state()`], so that lists
+/// of many thousands of items render as quickly as short ones.
+fn visible_items(
+    state: &mut ListState,
+    len: usize,
+    height: usize,
+    item_height: impl Fn(usize) -> usize,
+) -> Range<usize> {
+    if len == 0 {
+        *state.offset_mut() = 0;
+        return 0..0;
+    }
+    let item_height = |i| item_height(i).max(1);
+
+    let selected = state.selected().map(|selected| selected.min(len - 1));
+    state.select(selected);
+    let mut offset = state.offset().min(len - 1);
+    if let Some(selected) = selected {
+        // Every item takes a row at least, so items this far above the selected one can't fit.
+        offset = offset
+            .min(selected)
+            .max((selected + 1).saturating_sub(height));
+        let mut rows: usize = (offset..=selected).map(item_height).sum();
+        while rows > height && offset < selected {
+            rows -= item_height(offset);
+            offset += 1;
+        }
+    }
+    *state.offset_mut() = offset;
+
+    let mut end = offset;
+    let mut rows = 0;
+    while end < len && rows < height {
+        rows += item_height(end);
+        end += 1;
+    }
+    offset..end
+}
+
+/// The state of a list of only the `shown` items of a list with `state`.
+fn window_state(state: &ListState, shown: &Range<usize>) -> ListState {
+    ListState::default().with_selected(
+        state
+            .selected()
+            .filter(|selected| shown.contains(selected))
+            .map(|selected| selected - shown.start),
+    )
+}
+
+/// The namespace, pod, and container of a line logged by a Kubernetes container, as columns
+/// before the line.
+fn kubernetes_columns<'a>(
+    kubernetes_log: &aws::eks::KubernetesLog,
+    theme: &Theme,
+) -> Vec<Span<'a>> {
+    let column = |text: &str, width: usize| {
+        let text: String = text.chars().take(width).collect();
+        format!("{text:<width$} ")
+    };
+
+    vec![
+        Span::styled(
+            column(
+                &kubernetes_log.namespace,
+                log_format::KUBERNETES_NAMESPACE_WIDTH,
+            ),
+            theme.dimmed,
+        ),
+        Span::styled(
+            column(&kubernetes_log.pod, log_format::KUBERNETES_POD_WIDTH),
+            theme.text,
+        ),
+        Span::styled(
+            column(
+                kubernetes_log.container.as_deref().unwrap_or("-"),
+                log_format::KUBERNETES_CONTAINER_WIDTH,
+            ),
+            theme.dimmed,
+        ),
+    ]
+}
+
+/// The `text` without its first `count` characters.
+fn skip_chars(text: &str, count: usize) -> &str {
+    text.char_indices()
+        .nth(count)
+        .map_or("", |(start, _)| &text[start..])
+}
+
+/// The `line` in `color`, with the text matching `search` highlighted.
+fn highlight_matches<'a>(
+    line: &'a str,
+    search: Option<&str>,
+    color: Color,
+    theme: &Theme,
+) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let mut end = 0;
+    for range in log_format::find_matches(line, search.unwrap_or_default()) {
+        spans.push(Span::styled(&line[end..range.start], color));
+        end = range.end;
+        spans.push(Span::styled(&line[range], theme.search_match));
+    }
+    spans.push(Span::styled(&line[end..], color));
+
+    spans
+}
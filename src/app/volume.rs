@@ -0,0 +1,63 @@
+//! How many log events there are over time, to spot spikes in.
+
+// Buckets are whole minutes, in milliseconds.
+const MINUTE: i64 = 60_000;
+
+/// Counts of log events in consecutive buckets of whole minutes, from a start time.
+#[derive(Debug, Default)]
+pub struct Volume {
+    /// The start of the first bucket, in milliseconds since the Unix epoch.
+    pub start: i64,
+    /// How long each bucket is, in milliseconds.
+    pub bucket: i64,
+    pub counts: Vec<u64>,
+}
+
+impl Volume {
+    /// Count the `timestamps` from `start` to `end`, in at most `max_buckets` buckets of as few
+    /// minutes as fit.
+    pub fn new(
+        timestamps: impl Iterator<Item = i64>,
+        start: i64,
+        end: i64,
+        max_buckets: usize,
+    ) -> Self {
+        let duration = (end - start).max(1);
+        let minutes = (duration + MINUTE - 1) / MINUTE;
+        let max_buckets = max_buckets.max(1) as i64;
+        let bucket = (minutes + max_buckets - 1) / max_buckets * MINUTE;
+        let len = ((duration + bucket - 1) / bucket) as usize;
+
+        let mut counts = vec![0; len];
+        for timestamp in timestamps {
+            if let Some(count) = usize::try_from((timestamp - start).div_euclid(bucket))
+                .ok()
+                .and_then(|i| counts.get_mut(i))
+            {
+                *count += 1;
+            }
+        }
+
+        Self {
+            start,
+            bucket,
+            counts,
+        }
+    }
+
+    /// The start of the bucket `i`, in milliseconds since the Unix epoch.
+    pub fn bucket_start(&self, i: usize) -> i64 {
+        self.start + i as i64 * self.bucket
+    }
+
+    /// The bucket with the most log events, the earliest of them if tied.
+    pub fn peak(&self) -> Option<usize> {
+        let max = *self.counts.iter().max()?;
+        (max > 0).then(|| self.counts.iter().position(|&count| count == max))?
+    }
+
+    /// How long each bucket is, such as `1m` or `5m`.
+    pub fn bucket_label(&self) -> String {
+        format!("{}m", self.bucket / MINUTE)
+    }
+}
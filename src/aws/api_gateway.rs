@@ -0,0 +1,208 @@
+//! Client for Amazon API Gateway.
+//!
+//! Provides optimized methods for finding the access and execution logs of the stages of REST
+//! APIs.
+use aws_config::SdkConfig;
+use aws_sdk_apigateway;
+use serde_json::Value;
+
+use super::cloudwatch_logs;
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Method settings of every method of a stage, which execution logging is enabled in.
+const ALL_METHODS: &str = "*/*";
+const LOGGING_OFF: &str = "OFF";
+
+/// A stage of a REST API, such as `prod`.
+#[derive(Clone, Debug, Default, Ord, Eq, Hash, PartialOrd, PartialEq)]
+pub struct Stage {
+    pub api_name: String,
+    pub name: String,
+    pub api_id: String,
+    /// The log group that access logs are written to, if access logging is enabled.
+    pub access_log_group_name: Option<String>,
+    /// Whether execution logs are written for every method of the stage.
+    pub execution_logging: bool,
+}
+
+impl Stage {
+    /// Name of the log group that API Gateway writes the execution logs of the stage to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::api_gateway::Stage;
+    ///
+    /// let stage = Stage {
+    ///     api_id: "a1b2c3d4e5".to_string(),
+    ///     name: "prod".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(stage.execution_log_group_name(), "API-Gateway-Execution-Logs_a1b2c3d4e5/prod");
+    /// ```
+    pub fn execution_log_group_name(&self) -> String {
+        format!("API-Gateway-Execution-Logs_{}/{}", self.api_id, self.name)
+    }
+}
+
+/// A request logged in the common JSON format of API Gateway access logs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessLog {
+    pub status: u16,
+    /// How long the response took, in milliseconds.
+    pub latency: Option<f64>,
+    pub method: Option<String>,
+    /// The path, resource path, or route key of the request.
+    pub path: String,
+}
+
+impl AccessLog {
+    /// Parse the access log `message`, or `None` if it isn't JSON with a status and path.
+    ///
+    /// The values of `$context` variables may be logged as strings or as numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::api_gateway::AccessLog;
+    ///
+    /// let access_log = AccessLog::parse(
+    ///     r#"{"requestId":"c6af9ac6","httpMethod":"GET","path":"/orders/42","status":"502","responseLatency":"31"}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(access_log.status, 502);
+    /// assert_eq!(access_log.latency, Some(31.0));
+    /// assert_eq!(access_log.method.as_deref(), Some("GET"));
+    /// assert_eq!(access_log.path, "/orders/42");
+    /// assert_eq!(AccessLog::parse("START RequestId: c6af9ac6"), None);
+    /// ```
+    pub fn parse(message: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(message.trim()).ok()?;
+        let field = |names: &[&str]| names.iter().find_map(|&name| text(&value[name]));
+
+        let status = field(&["status"])?.parse().ok()?;
+        let latency = field(&["responseLatency", "integrationLatency"])
+            .and_then(|latency| latency.parse().ok());
+
+        Some(Self {
+            status,
+            latency,
+            method: field(&["httpMethod"]),
+            path: field(&["path", "resourcePath", "routeKey"])?,
+        })
+    }
+}
+
+/// The text of a string or number `value`.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) if !text.is_empty() && text != "-" => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    }
+}
+
+/// Client instance for Amazon API Gateway
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_apigateway::Client,
+}
+
+impl Client {
+    /// Create a new Amazon API Gateway client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{api_gateway, config};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let api_gateway_client = api_gateway::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_apigateway::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_apigateway::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get _all_ stages of every REST API, sorted by the name of the API then of the stage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of APIs, or the stages of any API, fail to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{api_gateway, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let api_gateway_client = api_gateway::Client::new(&sdk_config);
+    ///
+    /// for stage in api_gateway_client.get_all_stages().await? {
+    ///     println!("{}/{}", stage.api_name, stage.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_stages(&self) -> Result<Vec<Stage>, AwsError> {
+        let mut apis = Vec::new();
+        let mut pages = self.client.get_rest_apis().into_paginator().send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("GetRestApis", error))?;
+            apis.extend(page.items().iter().filter_map(|api| {
+                Some((
+                    api.id()?.to_string(),
+                    api.name().unwrap_or_default().to_string(),
+                ))
+            }));
+        }
+
+        let mut stages = Vec::new();
+        for (api_id, api_name) in apis {
+            let response = self
+                .client
+                .get_stages()
+                .rest_api_id(&api_id)
+                .send()
+                .await
+                .map_err(|error| AwsError::new("GetStages", error))?;
+            stages.extend(response.item().iter().filter_map(|stage| {
+                let access_log_group_name = stage
+                    .access_log_settings()
+                    .and_then(|settings| settings.destination_arn())
+                    .and_then(cloudwatch_logs::log_group_name_from_arn)
+                    .map(String::from);
+                let execution_logging = stage
+                    .method_settings()
+                    .and_then(|method_settings| method_settings.get(ALL_METHODS))
+                    .and_then(|method_setting| method_setting.logging_level())
+                    .is_some_and(|logging_level| logging_level != LOGGING_OFF);
+                Some(Stage {
+                    api_id: api_id.clone(),
+                    api_name: api_name.clone(),
+                    name: stage.stage_name()?.to_string(),
+                    access_log_group_name,
+                    execution_logging,
+                })
+            }));
+        }
+
+        stages.sort();
+
+        Ok(stages)
+    }
+}
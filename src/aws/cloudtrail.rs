@@ -0,0 +1,214 @@
+//! Client for AWS CloudTrail.
+//!
+//! Provides optimized methods for finding who changed a resource, such as the configuration of
+//! an AWS Lambda function, and when.
+use aws_config::SdkConfig;
+use aws_sdk_cloudtrail;
+use aws_sdk_cloudtrail::primitives::DateTime;
+use aws_sdk_cloudtrail::types::{LookupAttribute, LookupAttributeKey};
+use serde_json::Value;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+/// A call to AWS recorded by CloudTrail, such as `UpdateFunctionConfiguration`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TrailEvent {
+    pub id: String,
+    pub name: String,
+    /// When the call was made, in milliseconds since the Unix epoch.
+    pub time: i64,
+    /// Who made the call, such as an IAM user or role session.
+    pub username: Option<String>,
+    /// Names of the resources the call referenced.
+    pub resources: Vec<String>,
+    pub details: EventDetails,
+}
+
+/// Details of a [`TrailEvent`] that are only in its full record, which CloudTrail keeps as JSON.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventDetails {
+    /// Whether the call only read, rather than changed, resources, if known.
+    pub read_only: Option<bool>,
+    /// The error code of a failed call, such as `AccessDenied`.
+    pub error_code: Option<String>,
+}
+
+impl EventDetails {
+    /// Parse the full record `cloudtrail_event` of an event, or the default details if it
+    /// isn't valid JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::cloudtrail::EventDetails;
+    ///
+    /// let details = EventDetails::parse(
+    ///     r#"{"eventName": "UpdateFunctionConfiguration20150331v2", "readOnly": false,
+    ///         "errorCode": "AccessDenied"}"#,
+    /// );
+    ///
+    /// assert_eq!(details.read_only, Some(false));
+    /// assert_eq!(details.error_code.as_deref(), Some("AccessDenied"));
+    /// assert_eq!(EventDetails::parse("not json"), EventDetails::default());
+    /// ```
+    pub fn parse(cloudtrail_event: &str) -> Self {
+        let Ok(value) = serde_json::from_str::<Value>(cloudtrail_event) else {
+            return Self::default();
+        };
+        Self {
+            read_only: value["readOnly"].as_bool(),
+            error_code: value["errorCode"].as_str().map(String::from),
+        }
+    }
+}
+
+impl TrailEvent {
+    /// Whether the name of the event contains `event_name`, ignoring case.
+    ///
+    /// Calls to AWS Lambda are recorded with the version of the API, such as
+    /// `UpdateFunctionConfiguration20150331v2`, so whole names rarely match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::cloudtrail::TrailEvent;
+    ///
+    /// let event = TrailEvent {
+    ///     name: "UpdateFunctionConfiguration20150331v2".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(event.matches_name("updatefunctionconfiguration"));
+    /// assert!(!event.matches_name("DeleteFunction"));
+    /// ```
+    pub fn matches_name(&self, event_name: &str) -> bool {
+        self.name
+            .to_lowercase()
+            .contains(&event_name.to_lowercase())
+    }
+}
+
+/// Client instance for AWS CloudTrail
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_cloudtrail::Client,
+}
+
+impl Client {
+    /// Create a new AWS CloudTrail client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{cloudtrail, config};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let cloudtrail_client = cloudtrail::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_cloudtrail::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_cloudtrail::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get up to `limit` of the most recent management events from `start_time` to `end_time`,
+    /// in milliseconds since the Unix epoch, most recent first.
+    ///
+    /// Only events referencing the resource `resource_name` are looked up, when set. CloudTrail
+    /// looks events up by a single attribute, so events are only narrowed to those whose name
+    /// contains `event_name` after being looked up.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of events fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudtrail, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let cloudtrail_client = cloudtrail::Client::new(&sdk_config);
+    ///
+    /// let end_time = chrono::Utc::now().timestamp_millis();
+    /// let events = cloudtrail_client
+    ///     .lookup_events(Some("my-function"), None, end_time - 86_400_000, end_time, 100)
+    ///     .await?;
+    /// for event in events {
+    ///     println!("{} by {}", event.name, event.username.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lookup_events(
+        &self,
+        resource_name: Option<&str>,
+        event_name: Option<&str>,
+        start_time: i64,
+        end_time: i64,
+        limit: usize,
+    ) -> Result<Vec<TrailEvent>, AwsError> {
+        let attribute = resource_name.and_then(|resource_name| {
+            LookupAttribute::builder()
+                .attribute_key(LookupAttributeKey::ResourceName)
+                .attribute_value(resource_name)
+                .build()
+                .ok()
+        });
+        let mut pages = self
+            .client
+            .lookup_events()
+            .set_lookup_attributes(attribute.map(|attribute| vec![attribute]))
+            .start_time(DateTime::from_millis(start_time))
+            .end_time(DateTime::from_millis(end_time))
+            .into_paginator()
+            .send();
+
+        let mut events = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("LookupEvents", error))?;
+            events.extend(
+                page.events()
+                    .iter()
+                    .map(|event| TrailEvent {
+                        id: event.event_id().unwrap_or_default().to_string(),
+                        name: event.event_name().unwrap_or_default().to_string(),
+                        time: event
+                            .event_time()
+                            .and_then(|time| time.to_millis().ok())
+                            .unwrap_or_default(),
+                        username: event.username().map(String::from),
+                        resources: event
+                            .resources()
+                            .iter()
+                            .filter_map(|resource| resource.resource_name().map(String::from))
+                            .collect(),
+                        details: event
+                            .cloud_trail_event()
+                            .map(EventDetails::parse)
+                            .unwrap_or_default(),
+                    })
+                    .filter(|event| {
+                        event_name.is_none_or(|event_name| event.matches_name(event_name))
+                    }),
+            );
+            if events.len() >= limit {
+                events.truncate(limit);
+                break;
+            }
+        }
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,1480 @@
+//! Client for AWS CloudWatch Logs.
+//!
+//! Provides optimized methods for accessing AWS CloudWatch Logs.
+use std::time::{Duration, SystemTime};
+
+use aws_config::SdkConfig;
+use aws_sdk_cloudwatchlogs;
+use aws_sdk_cloudwatchlogs::primitives::event_stream::EventReceiver;
+use aws_sdk_cloudwatchlogs::types::error::StartLiveTailResponseStreamError;
+use aws_sdk_cloudwatchlogs::types::{
+    ExportTaskStatusCode, OrderBy, QueryStatus, StartLiveTailResponseStream,
+};
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Maximum results for `DescribeLogGroups` is 50, regardless of a larger configured size.
+const LOG_GROUPS_PAGINATION_SIZE: i32 = 50;
+
+// Maximum results for `DescribeLogStreams` is 50, regardless of a larger configured size.
+const LOG_STREAMS_PAGINATION_SIZE: i32 = 50;
+
+// Maximum results for `FilterLogEvents` is 10,000, regardless of a larger configured size.
+const LOG_EVENTS_PAGINATION_SIZE: i32 = 10_000;
+
+// The first, and shortest, time window searched for the most recent log events, in milliseconds.
+const RECENT_LOG_EVENTS_WINDOW: i64 = 60_000;
+
+// How long to wait between polls of `GetQueryResults` for a running query.
+const QUERY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// Logs Insights adds this field to every result row, it identifies the matching log event.
+const QUERY_PTR_FIELD: &str = "@ptr";
+
+/// Prefix of the log groups that AWS Lambda writes function logs to.
+pub const LAMBDA_LOG_GROUP_PREFIX: &str = "/aws/lambda/";
+
+/// Name of the default log group for an AWS Lambda function.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::cloudwatch_logs;
+///
+/// assert_eq!(
+///     cloudwatch_logs::lambda_log_group_name("my-function"),
+///     "/aws/lambda/my-function"
+/// );
+/// ```
+pub fn lambda_log_group_name(function_name: &str) -> String {
+    format!("{LAMBDA_LOG_GROUP_PREFIX}{function_name}")
+}
+
+/// Name of the log group of the ARN `arn`, with or without the `:*` that some services add.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::cloudwatch_logs;
+///
+/// assert_eq!(
+///     cloudwatch_logs::log_group_name_from_arn(
+///         "arn:aws:logs:us-east-1:123456789012:log-group:/aws/api/access:*"
+///     ),
+///     Some("/aws/api/access")
+/// );
+/// assert_eq!(cloudwatch_logs::log_group_name_from_arn("/aws/api/access"), None);
+/// ```
+pub fn log_group_name_from_arn(arn: &str) -> Option<&str> {
+    let (_, name) = arn.split_once(":log-group:")?;
+    Some(name.strip_suffix(":*").unwrap_or(name))
+}
+
+/// The numbers of days that CloudWatch Logs can keep the log events of a log group for.
+pub const RETENTION_DAYS: [i32; 22] = [
+    1, 3, 5, 7, 14, 30, 60, 90, 120, 150, 180, 365, 400, 545, 731, 1096, 1827, 2192, 2557, 2922,
+    3288, 3653,
+];
+
+/// How long log events are kept for, given the days of [`LogGroup::retention_in_days`].
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::cloudwatch_logs;
+///
+/// assert_eq!(cloudwatch_logs::retention_label(Some(1)), "1 day");
+/// assert_eq!(cloudwatch_logs::retention_label(Some(30)), "30 days");
+/// assert_eq!(cloudwatch_logs::retention_label(Some(731)), "731 days (2 years)");
+/// assert_eq!(cloudwatch_logs::retention_label(None), "Never expire");
+/// ```
+pub fn retention_label(retention_in_days: Option<i32>) -> String {
+    match retention_in_days {
+        None => "Never expire".to_string(),
+        Some(1) => "1 day".to_string(),
+        Some(days) if days >= 365 => {
+            let years = days / 365;
+            let unit = if years == 1 { "year" } else { "years" };
+            format!("{days} days ({years} {unit})")
+        }
+        Some(days) => format!("{days} days"),
+    }
+}
+
+#[derive(Clone, Debug, Ord, Eq, PartialOrd, PartialEq)]
+pub struct LogGroup {
+    pub name: String,
+    pub arn: Option<String>,
+    /// How many days log events are kept for, or `None` if they never expire.
+    pub retention_in_days: Option<i32>,
+    /// How many bytes of log events are stored, as billed for.
+    pub stored_bytes: Option<i64>,
+    /// When the log group was created, in milliseconds since the Unix epoch.
+    pub creation_time: Option<i64>,
+}
+
+#[derive(Clone, Debug, Ord, Eq, PartialOrd, PartialEq)]
+pub struct LogStream {
+    pub name: String,
+    pub creation_time: Option<i64>,
+    pub last_event_timestamp: Option<i64>,
+}
+
+/// A single log event, with timestamps in milliseconds since the Unix epoch.
+#[derive(Clone, Debug, Default, Ord, Eq, PartialOrd, PartialEq)]
+pub struct LogEvent {
+    pub timestamp: i64,
+    pub ingestion_time: i64,
+    pub log_group_name: Option<String>,
+    pub log_stream_name: Option<String>,
+    pub message: String,
+}
+
+/// Constraints applied when fetching log events.
+///
+/// Times are in milliseconds since the Unix epoch, unset values are unbounded.
+///
+/// The `pattern` uses the CloudWatch Logs [filter pattern syntax](https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/FilterAndPatternSyntax.html).
+///
+/// When `log_stream_names` is empty, log events from all log streams are included.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventFilter {
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub pattern: Option<String>,
+    pub log_stream_names: Vec<String>,
+}
+
+impl EventFilter {
+    fn log_stream_names(&self) -> Option<Vec<String>> {
+        (!self.log_stream_names.is_empty()).then(|| self.log_stream_names.clone())
+    }
+}
+
+/// A page of the log events matching an [`EventFilter`], and the token of the next page if
+/// more remain.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LogEventsPage {
+    pub log_events: Vec<LogEvent>,
+    pub next_token: Option<String>,
+}
+
+/// Results of a Logs Insights query.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryResults {
+    /// The final status of the query, such as `Complete` or `Failed`.
+    pub status: String,
+    /// Names of the result fields, in the order they first appear in the results.
+    pub columns: Vec<String>,
+    pub rows: Vec<QueryRow>,
+}
+
+/// A single row of Logs Insights query results.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryRow {
+    /// Field values, aligned with [`QueryResults::columns`]; missing fields are empty.
+    pub values: Vec<String>,
+    /// Identifies the log event the row was derived from, if any.
+    pub ptr: Option<String>,
+}
+
+/// A Logs Insights query saved in CloudWatch Logs, as a query definition.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryDefinition {
+    pub id: String,
+    pub name: String,
+    pub query: String,
+    /// The log groups the query is saved to run against, if any.
+    pub log_group_names: Vec<String>,
+}
+
+/// An export of the log events of a log group to an S3 bucket, run by CloudWatch Logs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExportTask {
+    pub id: String,
+    /// The status of the export, such as `RUNNING` or `COMPLETED`.
+    pub status: String,
+    /// Why the export has its status, such as why it failed.
+    pub message: Option<String>,
+}
+
+impl ExportTask {
+    /// Whether the export has ended, having completed, failed, or been cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::cloudwatch_logs::ExportTask;
+    ///
+    /// let mut export_task = ExportTask {
+    ///     status: "RUNNING".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(!export_task.is_finished());
+    ///
+    /// export_task.status = "COMPLETED".to_string();
+    /// assert!(export_task.is_finished());
+    /// assert!(export_task.is_completed());
+    /// ```
+    pub fn is_finished(&self) -> bool {
+        !matches!(
+            ExportTaskStatusCode::from(self.status.as_str()),
+            ExportTaskStatusCode::Pending
+                | ExportTaskStatusCode::PendingCancel
+                | ExportTaskStatusCode::Running
+        )
+    }
+
+    /// Whether every log event was exported.
+    pub fn is_completed(&self) -> bool {
+        ExportTaskStatusCode::from(self.status.as_str()) == ExportTaskStatusCode::Completed
+    }
+}
+
+/// The bucket, and key prefix if any, of an S3 destination such as `my-bucket/logs/`, with
+/// or without `s3://`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::cloudwatch_logs;
+///
+/// assert_eq!(
+///     cloudwatch_logs::parse_s3_destination("s3://my-bucket/logs/lambda/"),
+///     Some(("my-bucket", Some("logs/lambda")))
+/// );
+/// assert_eq!(
+///     cloudwatch_logs::parse_s3_destination("my-bucket"),
+///     Some(("my-bucket", None))
+/// );
+/// assert_eq!(cloudwatch_logs::parse_s3_destination("s3://"), None);
+/// ```
+pub fn parse_s3_destination(destination: &str) -> Option<(&str, Option<&str>)> {
+    let destination = destination.trim();
+    let destination = destination.strip_prefix("s3://").unwrap_or(destination);
+    let (bucket, prefix) = match destination.split_once('/') {
+        Some((bucket, prefix)) => (bucket, Some(prefix.trim_matches('/'))),
+        None => (destination, None),
+    };
+    if bucket.is_empty() {
+        return None;
+    }
+    Some((bucket, prefix.filter(|prefix| !prefix.is_empty())))
+}
+
+/// An active Live Tail session, streaming log events as they are ingested.
+///
+/// The session is stopped when the `LiveTail` is dropped.
+pub struct LiveTail {
+    response_stream: EventReceiver<StartLiveTailResponseStream, StartLiveTailResponseStreamError>,
+}
+
+impl LiveTail {
+    /// Wait for the next batch of log events, in chronological order.
+    ///
+    /// Returns `None` once the session has ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the session fails, such as when its credentials expire.
+    pub async fn next_events(&mut self) -> Result<Option<Vec<LogEvent>>, AwsError> {
+        loop {
+            let Some(message) = self
+                .response_stream
+                .recv()
+                .await
+                .map_err(|error| AwsError::new("StartLiveTail", error))?
+            else {
+                return Ok(None);
+            };
+            let update = match message {
+                StartLiveTailResponseStream::SessionUpdate(update) => update,
+                // The session start and any unknown messages carry no log events.
+                _ => continue,
+            };
+
+            let mut log_events: Vec<LogEvent> = update
+                .session_results()
+                .iter()
+                .map(|log_event| LogEvent {
+                    timestamp: log_event.timestamp.unwrap_or_default(),
+                    ingestion_time: log_event.ingestion_time.unwrap_or_default(),
+                    log_group_name: log_event.log_group_identifier.as_deref().map(|identifier| {
+                        log_group_name_from_arn(identifier)
+                            .unwrap_or(identifier)
+                            .to_string()
+                    }),
+                    log_stream_name: log_event.log_stream_name.clone(),
+                    message: log_event.message.clone().unwrap_or_default(),
+                })
+                .collect();
+            log_events.sort();
+
+            return Ok(Some(log_events));
+        }
+    }
+}
+
+/// Client instance for AWS CloudWatch Logs
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_cloudwatchlogs::Client,
+}
+
+impl Client {
+    /// Create a new AWS CloudWatch Logs client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{cloudwatch_logs, config};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_cloudwatchlogs::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_cloudwatchlogs::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get _all_ log groups, in sorted order.
+    ///
+    /// The paginated results from AWS CloudWatch Logs are automatically iterated
+    /// to collect all log groups as a single, complete list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of log groups fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_groups = logs_client.get_all_log_groups().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_log_groups(&self) -> Result<Vec<LogGroup>, AwsError> {
+        let mut log_groups = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let mut describe_log_groups_request = self
+                .client
+                .describe_log_groups()
+                .limit(LOG_GROUPS_PAGINATION_SIZE);
+            if let Some(token) = next_token {
+                describe_log_groups_request = describe_log_groups_request.next_token(token);
+            }
+
+            let describe_log_groups_response = describe_log_groups_request
+                .send()
+                .await
+                .map_err(|error| AwsError::new("DescribeLogGroups", error))?;
+            for log_group in describe_log_groups_response.log_groups() {
+                if let Some(name) = &log_group.log_group_name {
+                    log_groups.push(LogGroup {
+                        name: name.clone(),
+                        arn: log_group.log_group_arn.clone(),
+                        retention_in_days: log_group.retention_in_days,
+                        stored_bytes: log_group.stored_bytes,
+                        creation_time: log_group.creation_time,
+                    })
+                }
+            }
+
+            next_token = describe_log_groups_response.next_token().map(String::from);
+
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        log_groups.sort();
+
+        Ok(log_groups)
+    }
+
+    /// Get the log group with exactly the name `log_group_name`, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_group = logs_client.get_log_group("/aws/lambda/my-function").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_log_group(&self, log_group_name: &str) -> Result<Option<LogGroup>, AwsError> {
+        let describe_log_groups_response = self
+            .client
+            .describe_log_groups()
+            .log_group_name_prefix(log_group_name)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("DescribeLogGroups", error))?;
+
+        Ok(describe_log_groups_response
+            .log_groups()
+            .iter()
+            .find(|log_group| log_group.log_group_name() == Some(log_group_name))
+            .map(|log_group| LogGroup {
+                name: log_group_name.to_string(),
+                arn: log_group.log_group_arn().map(String::from),
+                retention_in_days: log_group.retention_in_days(),
+                stored_bytes: log_group.stored_bytes(),
+                creation_time: log_group.creation_time(),
+            }))
+    }
+
+    /// Get the log group of the AWS Lambda function `function_name`, if it exists.
+    ///
+    /// A function's log group is only created when the function first logs,
+    /// so a missing log group usually means the function has never been invoked.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_group = logs_client.get_function_log_group("my-function").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_function_log_group(
+        &self,
+        function_name: &str,
+    ) -> Result<Option<LogGroup>, AwsError> {
+        self.get_log_group(&lambda_log_group_name(function_name))
+            .await
+    }
+
+    /// Get _all_ log streams of the log group `log_group_name`, in sorted order.
+    ///
+    /// The paginated results from AWS CloudWatch Logs are automatically iterated
+    /// to collect all log streams as a single, complete list.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_streams = logs_client.get_all_log_streams("/aws/lambda/my-function").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_log_streams(
+        &self,
+        log_group_name: &str,
+    ) -> Result<Vec<LogStream>, AwsError> {
+        let mut log_streams = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let mut describe_log_streams_request = self
+                .client
+                .describe_log_streams()
+                .log_group_name(log_group_name)
+                .limit(LOG_STREAMS_PAGINATION_SIZE);
+            if let Some(token) = next_token {
+                describe_log_streams_request = describe_log_streams_request.next_token(token);
+            }
+
+            let describe_log_streams_response = describe_log_streams_request
+                .send()
+                .await
+                .map_err(|error| AwsError::new("DescribeLogStreams", error))?;
+            for log_stream in describe_log_streams_response.log_streams() {
+                if let Some(name) = &log_stream.log_stream_name {
+                    log_streams.push(LogStream {
+                        name: name.clone(),
+                        creation_time: log_stream.creation_time,
+                        last_event_timestamp: log_stream.last_event_timestamp,
+                    })
+                }
+            }
+
+            next_token = describe_log_streams_response.next_token().map(String::from);
+
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        log_streams.sort();
+
+        Ok(log_streams)
+    }
+
+    /// Get up to `limit` log streams of the log group `log_group_name`, most recently active first.
+    ///
+    /// The paginated results from AWS CloudWatch Logs are automatically iterated
+    /// until `limit` log streams are collected or no log streams remain.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_streams = logs_client
+    ///     .get_recent_log_streams("/aws/lambda/my-function", 100)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recent_log_streams(
+        &self,
+        log_group_name: &str,
+        limit: usize,
+    ) -> Result<Vec<LogStream>, AwsError> {
+        let mut log_streams = Vec::new();
+        let mut next_token = None;
+
+        while log_streams.len() < limit {
+            let mut describe_log_streams_request = self
+                .client
+                .describe_log_streams()
+                .log_group_name(log_group_name)
+                .order_by(OrderBy::LastEventTime)
+                .descending(true)
+                .limit(LOG_STREAMS_PAGINATION_SIZE);
+            if let Some(token) = next_token {
+                describe_log_streams_request = describe_log_streams_request.next_token(token);
+            }
+
+            let describe_log_streams_response = describe_log_streams_request
+                .send()
+                .await
+                .map_err(|error| AwsError::new("DescribeLogStreams", error))?;
+            for log_stream in describe_log_streams_response.log_streams() {
+                if let Some(name) = &log_stream.log_stream_name {
+                    log_streams.push(LogStream {
+                        name: name.clone(),
+                        creation_time: log_stream.creation_time,
+                        last_event_timestamp: log_stream.last_event_timestamp,
+                    })
+                }
+            }
+
+            next_token = describe_log_streams_response.next_token().map(String::from);
+
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        log_streams.truncate(limit);
+
+        Ok(log_streams)
+    }
+
+    /// Get when the log group `log_group_name` last had a log event, in milliseconds since the
+    /// Unix epoch, unless it never had one.
+    ///
+    /// A log group that doesn't exist never had a log event, like that of an AWS Lambda function
+    /// never invoked. CloudWatch Logs only updates when log streams last had a log event every
+    /// so often, so the time can be behind by up to an hour.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the log streams of the log group fail to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// match logs_client.get_last_event_time("/aws/lambda/my-function").await? {
+    ///     Some(timestamp) => println!("Last logged at {timestamp}"),
+    ///     None => println!("Never logged"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_last_event_time(&self, log_group_name: &str) -> Result<Option<i64>, AwsError> {
+        let response = self
+            .client
+            .describe_log_streams()
+            .log_group_name(log_group_name)
+            .order_by(OrderBy::LastEventTime)
+            .descending(true)
+            .limit(1)
+            .send()
+            .await;
+
+        match response {
+            Ok(response) => Ok(response
+                .log_streams()
+                .first()
+                .and_then(|log_stream| log_stream.last_event_timestamp)),
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_resource_not_found_exception()) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(AwsError::new("DescribeLogStreams", error)),
+        }
+    }
+
+    /// Get up to `limit` log events of the log group `log_group_name`, in chronological order.
+    ///
+    /// The paginated results from AWS CloudWatch Logs are automatically iterated
+    /// until `limit` events are collected or no events remain.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// use aws_logs_tui::aws::cloudwatch_logs::EventFilter;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_events = logs_client
+    ///     .get_log_events("/aws/lambda/my-function", &EventFilter::default(), 1000)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_log_events(
+        &self,
+        log_group_name: &str,
+        filter: &EventFilter,
+        limit: usize,
+    ) -> Result<Vec<LogEvent>, AwsError> {
+        let mut log_events = Vec::new();
+        let mut next_token = None;
+
+        while log_events.len() < limit {
+            let remaining = i32::try_from(limit - log_events.len()).unwrap_or(i32::MAX);
+            let page = self
+                .get_log_events_page(log_group_name, filter, remaining, next_token)
+                .await?;
+            log_events.extend(page.log_events);
+            next_token = page.next_token;
+
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // The log events of several log streams are interleaved, though not always in the order
+        // they were logged in.
+        log_events.sort_by_key(|log_event| log_event.timestamp);
+        log_events.truncate(limit);
+
+        Ok(log_events)
+    }
+
+    /// Get a page of up to `limit` log events of the log group `log_group_name`, starting from
+    /// the page of `next_token`, or the first page if `None`.
+    ///
+    /// Log events of a page are roughly, but not strictly, in chronological order. Go through
+    /// more log events than fit in memory a page at a time, until no next page remains.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// use aws_logs_tui::aws::cloudwatch_logs::EventFilter;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let mut next_token = None;
+    /// loop {
+    ///     let page = logs_client
+    ///         .get_log_events_page(
+    ///             "/aws/lambda/my-function",
+    ///             &EventFilter::default(),
+    ///             10_000,
+    ///             next_token,
+    ///         )
+    ///         .await?;
+    ///     for log_event in &page.log_events {
+    ///         println!("{}", log_event.message);
+    ///     }
+    ///     next_token = page.next_token;
+    ///     if next_token.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_log_events_page(
+        &self,
+        log_group_name: &str,
+        filter: &EventFilter,
+        limit: i32,
+        next_token: Option<String>,
+    ) -> Result<LogEventsPage, AwsError> {
+        let filter_log_events_response = self
+            .client
+            .filter_log_events()
+            .log_group_name(log_group_name)
+            .set_start_time(filter.start_time)
+            .set_end_time(filter.end_time)
+            .set_filter_pattern(filter.pattern.clone())
+            .set_log_stream_names(filter.log_stream_names())
+            .limit(limit.min(LOG_EVENTS_PAGINATION_SIZE))
+            .set_next_token(next_token)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("FilterLogEvents", error))?;
+
+        Ok(LogEventsPage {
+            log_events: filter_log_events_response
+                .events()
+                .iter()
+                .map(|log_event| LogEvent {
+                    timestamp: log_event.timestamp.unwrap_or_default(),
+                    ingestion_time: log_event.ingestion_time.unwrap_or_default(),
+                    log_group_name: Some(log_group_name.to_string()),
+                    log_stream_name: log_event.log_stream_name.clone(),
+                    message: log_event.message.clone().unwrap_or_default(),
+                })
+                .collect(),
+            next_token: filter_log_events_response.next_token().map(String::from),
+        })
+    }
+
+    /// Get up to `limit` of the most recent log events of the log group `log_group_name`,
+    /// in chronological order.
+    ///
+    /// Log events are searched backwards from the end time of the `filter`, or from now when
+    /// unset, in time windows that double in length until `limit` would be exceeded or the
+    /// start time of the `filter` is reached. Fewer than `limit` events may be returned even
+    /// when older events remain, these are found by searching again before the oldest event.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// use aws_logs_tui::aws::cloudwatch_logs::EventFilter;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_events = logs_client
+    ///     .get_recent_log_events("/aws/lambda/my-function", &EventFilter::default(), 1000)
+    ///     .await?;
+    ///
+    /// // Page backwards through the older log events.
+    /// if let Some(oldest) = log_events.first() {
+    ///     let older = EventFilter {
+    ///         end_time: Some(oldest.timestamp - 1),
+    ///         ..EventFilter::default()
+    ///     };
+    ///     let older_log_events = logs_client
+    ///         .get_recent_log_events("/aws/lambda/my-function", &older, 1000)
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recent_log_events(
+        &self,
+        log_group_name: &str,
+        filter: &EventFilter,
+        limit: usize,
+    ) -> Result<Vec<LogEvent>, AwsError> {
+        let end_time = filter.end_time.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |elapsed| {
+                    i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX)
+                })
+        });
+        let start_time = filter.start_time.unwrap_or(0);
+        if end_time < start_time {
+            return Ok(Vec::new());
+        }
+
+        // All log events of windows of up to `fits` milliseconds were collected, while windows
+        // of `overflows` milliseconds have too many, so the window is narrowed between them.
+        let mut log_events = Vec::new();
+        let mut fits = 0;
+        let mut overflows = None;
+        let mut window = RECENT_LOG_EVENTS_WINDOW;
+
+        loop {
+            let window_start_time = end_time.saturating_sub(window - 1).max(start_time);
+            let window_filter = EventFilter {
+                start_time: Some(window_start_time),
+                end_time: Some(end_time),
+                ..filter.clone()
+            };
+            let mut window_log_events = self
+                .get_log_events(log_group_name, &window_filter, limit + 1)
+                .await?;
+
+            if window_log_events.len() > limit {
+                if log_events.is_empty() && window - fits <= 1 {
+                    // Too many log events share a single millisecond, settle for the first of them.
+                    window_log_events.truncate(limit);
+                    return Ok(window_log_events);
+                }
+                overflows = Some(window);
+            } else {
+                fits = window;
+                log_events = window_log_events;
+                if window_start_time == start_time {
+                    break;
+                }
+            }
+
+            window = match overflows {
+                Some(_) if !log_events.is_empty() => break,
+                Some(overflows) => fits + (overflows - fits + 1) / 2,
+                None => window.saturating_mul(2),
+            };
+        }
+
+        Ok(log_events)
+    }
+
+    /// Get up to `limit` of the most recent log events of the log group `log_group_name`
+    /// logged from the first to the last log event that has the request ID `request_id`, in
+    /// chronological order.
+    ///
+    /// Not every log event of a request has its ID, such as what an AWS Lambda function
+    /// prints, so the log events that do, such as the `START` and `REPORT` lines of Lambda, are
+    /// searched for first, and then all the log events between them got. When none have it
+    /// yet, the most recent log events of the `filter` are got instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if searching the log group fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// use aws_logs_tui::aws::cloudwatch_logs::EventFilter;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let log_events = logs_client
+    ///     .get_request_log_events(
+    ///         "/aws/lambda/my-function",
+    ///         "8f5a3c1e-5b9a-4a8e-9c1d-2f3e4a5b6c7d",
+    ///         &EventFilter::default(),
+    ///         1000,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_request_log_events(
+        &self,
+        log_group_name: &str,
+        request_id: &str,
+        filter: &EventFilter,
+        limit: usize,
+    ) -> Result<Vec<LogEvent>, AwsError> {
+        let request_filter = EventFilter {
+            pattern: Some(format!("\"{request_id}\"")),
+            ..filter.clone()
+        };
+        let request_log_events = self
+            .get_recent_log_events(log_group_name, &request_filter, limit)
+            .await?;
+
+        let filter = match (request_log_events.first(), request_log_events.last()) {
+            (Some(first), Some(last)) => EventFilter {
+                start_time: Some(first.timestamp),
+                end_time: Some(last.timestamp),
+                ..filter.clone()
+            },
+            _ => filter.clone(),
+        };
+        self.get_recent_log_events(log_group_name, &filter, limit)
+            .await
+    }
+
+    /// Get up to `limit` of the most recent log events of all the log groups `log_group_names`
+    /// together, in chronological order, each with the log group it came from.
+    ///
+    /// `FilterLogEvents` only searches a single log group, so every log group is searched at
+    /// once, for up to `limit` log events of each, and their log events merged. Log groups that
+    /// don't exist are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if searching any log group fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// use aws_logs_tui::aws::cloudwatch_logs::EventFilter;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let filter = EventFilter {
+    ///     pattern: Some("ERROR".to_string()),
+    ///     ..EventFilter::default()
+    /// };
+    /// let log_events = logs_client
+    ///     .search_log_groups(
+    ///         &[
+    ///             "/aws/lambda/orders".to_string(),
+    ///             "/aws/lambda/payments".to_string(),
+    ///         ],
+    ///         &filter,
+    ///         1000,
+    ///     )
+    ///     .await?;
+    /// for log_event in log_events {
+    ///     println!("{:?}: {}", log_event.log_group_name, log_event.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_log_groups(
+        &self,
+        log_group_names: &[String],
+        filter: &EventFilter,
+        limit: usize,
+    ) -> Result<Vec<LogEvent>, AwsError> {
+        let searches = log_group_names
+            .iter()
+            .map(|log_group_name| self.get_recent_log_events(log_group_name, filter, limit));
+
+        let mut log_events = Vec::new();
+        for result in futures::future::join_all(searches).await {
+            match result {
+                Ok(found) => log_events.extend(found),
+                Err(error) if error.code() == Some("ResourceNotFoundException") => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        log_events.sort_by_key(|log_event| log_event.timestamp);
+        let excess = log_events.len().saturating_sub(limit);
+        log_events.drain(..excess);
+
+        Ok(log_events)
+    }
+
+    /// Start a Live Tail session for the log groups with the ARNs `log_group_arns`, streaming
+    /// the log events of them all, in the order they were logged.
+    ///
+    /// Live Tail requires the log group ARNs (see [`LogGroup::arn`]), not their names, and tails
+    /// up to 10 log groups at once.
+    ///
+    /// Only log events matching the `filter` pattern and log streams are streamed,
+    /// its time range is ignored as Live Tail only streams new log events. Log streams can only
+    /// be filtered by when tailing a single log group.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// use aws_logs_tui::aws::cloudwatch_logs::EventFilter;
+    ///
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// if let Some(log_group) = logs_client.get_function_log_group("my-function").await? {
+    ///     let log_group_arn = log_group.arn.unwrap_or_default();
+    ///     let mut live_tail = logs_client
+    ///         .start_live_tail(&[log_group_arn], &EventFilter::default())
+    ///         .await?;
+    ///
+    ///     while let Some(log_events) = live_tail.next_events().await? {
+    ///         for log_event in log_events {
+    ///             println!("{}", log_event.message);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn start_live_tail(
+        &self,
+        log_group_arns: &[String],
+        filter: &EventFilter,
+    ) -> Result<LiveTail, AwsError> {
+        let start_live_tail_response = self
+            .client
+            .start_live_tail()
+            .set_log_group_identifiers(Some(log_group_arns.to_vec()))
+            .set_log_event_filter_pattern(filter.pattern.clone())
+            .set_log_stream_names(filter.log_stream_names())
+            .send()
+            .await
+            .map_err(|error| AwsError::new("StartLiveTail", error))?;
+
+        Ok(LiveTail {
+            response_stream: start_live_tail_response.response_stream,
+        })
+    }
+
+    /// Run the Logs Insights query `query` against the log groups `log_group_names`.
+    ///
+    /// Times are in seconds since the Unix epoch, as required by Logs Insights.
+    /// The query is polled until it completes, fails, or times out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let query_results = logs_client
+    ///     .run_query(
+    ///         &["/aws/lambda/my-function".to_string()],
+    ///         "fields @timestamp, @message | limit 20",
+    ///         1_700_000_000,
+    ///         1_700_003_600,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_query(
+        &self,
+        log_group_names: &[String],
+        query: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<QueryResults, AwsError> {
+        let start_query_response = self
+            .client
+            .start_query()
+            .set_log_group_names(Some(log_group_names.to_vec()))
+            .query_string(query)
+            .start_time(start_time)
+            .end_time(end_time)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("StartQuery", error))?;
+        let query_id = start_query_response.query_id().unwrap_or_default();
+
+        loop {
+            let get_query_results_response = self
+                .client
+                .get_query_results()
+                .query_id(query_id)
+                .send()
+                .await
+                .map_err(|error| AwsError::new("GetQueryResults", error))?;
+
+            match get_query_results_response.status() {
+                Some(QueryStatus::Running | QueryStatus::Scheduled) => {
+                    tokio::time::sleep(QUERY_POLL_INTERVAL).await;
+                    continue;
+                }
+                status => {
+                    let mut query_results = QueryResults {
+                        status: status.map_or("Unknown", QueryStatus::as_str).to_string(),
+                        ..Default::default()
+                    };
+                    for result in get_query_results_response.results() {
+                        query_results.push_row(result);
+                    }
+
+                    return Ok(query_results);
+                }
+            }
+        }
+    }
+
+    /// Export the log events of the log group `log_group_name` from `from` until `to`, in
+    /// milliseconds since the Unix epoch, to the S3 bucket `bucket`, under `prefix` if given,
+    /// returning the ID of the export task.
+    ///
+    /// The export runs in the background, see [`Client::get_export_task`] for how it is going.
+    /// The bucket must allow CloudWatch Logs to write to it, and only one export task of an
+    /// account can run at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the export task fails to be created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let task_id = logs_client
+    ///     .create_export_task(
+    ///         "/aws/lambda/my-function",
+    ///         1_700_000_000_000,
+    ///         1_700_003_600_000,
+    ///         "my-log-exports",
+    ///         Some("lambda"),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_export_task(
+        &self,
+        log_group_name: &str,
+        from: i64,
+        to: i64,
+        bucket: &str,
+        prefix: Option<&str>,
+    ) -> Result<String, AwsError> {
+        let response = self
+            .client
+            .create_export_task()
+            .log_group_name(log_group_name)
+            .from(from)
+            .to(to)
+            .destination(bucket)
+            .set_destination_prefix(prefix.map(String::from))
+            .send()
+            .await
+            .map_err(|error| AwsError::new("CreateExportTask", error))?;
+
+        Ok(response.task_id().unwrap_or_default().to_string())
+    }
+
+    /// Get the export task with the ID `task_id`, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// if let Some(export_task) = logs_client.get_export_task("my-task-id").await? {
+    ///     println!("{}", export_task.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_export_task(&self, task_id: &str) -> Result<Option<ExportTask>, AwsError> {
+        let response = self
+            .client
+            .describe_export_tasks()
+            .task_id(task_id)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("DescribeExportTasks", error))?;
+
+        Ok(response.export_tasks().first().map(|export_task| {
+            let status = export_task.status();
+            ExportTask {
+                id: task_id.to_string(),
+                status: status
+                    .and_then(|status| status.code())
+                    .map_or("UNKNOWN", ExportTaskStatusCode::as_str)
+                    .to_string(),
+                message: status.and_then(|status| status.message()).map(String::from),
+            }
+        }))
+    }
+
+    /// Get _all_ Logs Insights queries saved in CloudWatch Logs, sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of query definitions fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// for query_definition in logs_client.get_all_query_definitions().await? {
+    ///     println!("{}: {}", query_definition.name, query_definition.query);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_query_definitions(&self) -> Result<Vec<QueryDefinition>, AwsError> {
+        let mut query_definitions = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let response = self
+                .client
+                .describe_query_definitions()
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|error| AwsError::new("DescribeQueryDefinitions", error))?;
+            query_definitions.extend(response.query_definitions().iter().map(|definition| {
+                QueryDefinition {
+                    id: definition
+                        .query_definition_id()
+                        .unwrap_or_default()
+                        .to_string(),
+                    name: definition.name().unwrap_or_default().to_string(),
+                    query: definition.query_string().unwrap_or_default().to_string(),
+                    log_group_names: definition.log_group_names().to_vec(),
+                }
+            }));
+
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        query_definitions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(query_definitions)
+    }
+
+    /// Keep the log events of the log group `log_group_name` for `retention_in_days`, or never
+    /// expire them if `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the retention fails to change, such as for a number of days
+    /// not in [`RETENTION_DAYS`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// logs_client
+    ///     .set_retention("/aws/lambda/my-function", Some(30))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_retention(
+        &self,
+        log_group_name: &str,
+        retention_in_days: Option<i32>,
+    ) -> Result<(), AwsError> {
+        match retention_in_days {
+            Some(days) => {
+                self.client
+                    .put_retention_policy()
+                    .log_group_name(log_group_name)
+                    .retention_in_days(days)
+                    .send()
+                    .await
+                    .map_err(|error| AwsError::new("PutRetentionPolicy", error))?;
+            }
+            None => {
+                self.client
+                    .delete_retention_policy()
+                    .log_group_name(log_group_name)
+                    .send()
+                    .await
+                    .map_err(|error| AwsError::new("DeleteRetentionPolicy", error))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Save the Logs Insights query `query` in CloudWatch Logs as `name`, to run against the
+    /// log groups `log_group_names`, returning the ID of its query definition.
+    ///
+    /// The query definition with the ID `id` is updated instead, when given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the query fails to save.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// let id = logs_client
+    ///     .put_query_definition(
+    ///         None,
+    ///         "Errors",
+    ///         "fields @timestamp, @message | filter @message like /ERROR/",
+    ///         &["/aws/lambda/my-function".to_string()],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put_query_definition(
+        &self,
+        id: Option<&str>,
+        name: &str,
+        query: &str,
+        log_group_names: &[String],
+    ) -> Result<String, AwsError> {
+        let response = self
+            .client
+            .put_query_definition()
+            .set_query_definition_id(id.map(String::from))
+            .name(name)
+            .query_string(query)
+            .set_log_group_names(Some(log_group_names.to_vec()))
+            .send()
+            .await
+            .map_err(|error| AwsError::new("PutQueryDefinition", error))?;
+
+        Ok(response
+            .query_definition_id()
+            .unwrap_or_default()
+            .to_string())
+    }
+
+    /// Get the log event a Logs Insights query result was derived from, by its `ptr` (see
+    /// [`QueryRow::ptr`]), or `None` if it no longer exists.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_logs, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// # let results = cloudwatch_logs::QueryResults::default();
+    /// let logs_client = cloudwatch_logs::Client::new(&sdk_config);
+    ///
+    /// if let Some(ptr) = results.rows.first().and_then(|row| row.ptr.as_deref()) {
+    ///     if let Some(log_event) = logs_client.get_log_record(ptr).await? {
+    ///         println!("{}", log_event.message);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_log_record(&self, ptr: &str) -> Result<Option<LogEvent>, AwsError> {
+        let response = self
+            .client
+            .get_log_record()
+            .log_record_pointer(ptr)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("GetLogRecord", error))?;
+
+        Ok(response.log_record().map(|record| {
+            let field = |name: &str| record.get(name).cloned();
+            let millis = |name: &str| {
+                record
+                    .get(name)
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or_default()
+            };
+            LogEvent {
+                timestamp: millis("@timestamp"),
+                ingestion_time: millis("@ingestionTime"),
+                // Log groups are prefixed with their account ID, such as `123456789012:/aws/…`.
+                log_group_name: field("@log").map(|log| match log.split_once(':') {
+                    Some((_, log_group_name)) => log_group_name.to_string(),
+                    None => log,
+                }),
+                log_stream_name: field("@logStream"),
+                message: field("@message").unwrap_or_default(),
+            }
+        }))
+    }
+}
+
+impl QueryResults {
+    fn push_row(&mut self, result: &[aws_sdk_cloudwatchlogs::types::ResultField]) {
+        let mut row = QueryRow::default();
+
+        for result_field in result {
+            let (Some(field), Some(value)) = (result_field.field(), result_field.value()) else {
+                continue;
+            };
+            if field == QUERY_PTR_FIELD {
+                row.ptr = Some(value.to_string());
+                continue;
+            }
+
+            let column = match self.columns.iter().position(|column| column == field) {
+                Some(column) => column,
+                None => {
+                    self.columns.push(field.to_string());
+                    // Earlier rows are missing the new field.
+                    for earlier_row in &mut self.rows {
+                        earlier_row.values.push(String::new());
+                    }
+                    self.columns.len() - 1
+                }
+            };
+            row.values.resize(self.columns.len(), String::new());
+            row.values[column] = value.to_string();
+        }
+
+        row.values.resize(self.columns.len(), String::new());
+        self.rows.push(row);
+    }
+}
@@ -0,0 +1,180 @@
+//! Client for AWS CloudWatch Metrics.
+//!
+//! Provides optimized methods for accessing the metrics of AWS Lambda functions.
+use aws_config::SdkConfig;
+use aws_sdk_cloudwatch;
+use aws_sdk_cloudwatch::primitives::DateTime;
+use aws_sdk_cloudwatch::types::{Dimension, Metric, MetricDataQuery, MetricStat};
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Namespace of the metrics AWS Lambda publishes for every function.
+const LAMBDA_NAMESPACE: &str = "AWS/Lambda";
+
+// Periods of metrics are multiples of a minute, in seconds.
+const PERIOD_STEP: i64 = 60;
+
+/// Invocations, errors, throttles, and durations of an AWS Lambda function over a time range.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FunctionMetrics {
+    pub invocations: u64,
+    pub errors: u64,
+    pub throttles: u64,
+    /// Average duration of the invocations, in milliseconds, if there were any.
+    pub average_duration: Option<f64>,
+    /// Longest duration of the invocations, in milliseconds, if there were any.
+    pub maximum_duration: Option<f64>,
+}
+
+impl FunctionMetrics {
+    /// The percentage of invocations that failed, if there were any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::cloudwatch_metrics::FunctionMetrics;
+    ///
+    /// let metrics = FunctionMetrics {
+    ///     invocations: 200,
+    ///     errors: 5,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(metrics.error_rate(), Some(2.5));
+    /// assert_eq!(FunctionMetrics::default().error_rate(), None);
+    /// ```
+    pub fn error_rate(&self) -> Option<f64> {
+        (self.invocations > 0).then(|| self.errors as f64 * 100.0 / self.invocations as f64)
+    }
+}
+
+/// Client instance for AWS CloudWatch Metrics
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_cloudwatch::Client,
+}
+
+impl Client {
+    /// Create a new AWS CloudWatch Metrics client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{cloudwatch_metrics, config};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let metrics_client = cloudwatch_metrics::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_cloudwatch::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_cloudwatch::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get the metrics of the AWS Lambda function `function_name` from `start_time` to
+    /// `end_time`, in milliseconds since the Unix epoch.
+    ///
+    /// The metrics are totalled over the whole time range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of metric data fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{cloudwatch_metrics, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let metrics_client = cloudwatch_metrics::Client::new(&sdk_config);
+    ///
+    /// let end_time = chrono::Utc::now().timestamp_millis();
+    /// let metrics = metrics_client
+    ///     .get_function_metrics("my-function", end_time - 3_600_000, end_time)
+    ///     .await?;
+    /// println!("{} invocations, {} errors", metrics.invocations, metrics.errors);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_function_metrics(
+        &self,
+        function_name: &str,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<FunctionMetrics, AwsError> {
+        let seconds = ((end_time - start_time) / 1000).max(1);
+        let period = (seconds + PERIOD_STEP - 1) / PERIOD_STEP * PERIOD_STEP;
+        let query = |id: &str, metric_name: &str, statistic: &str| {
+            let metric = Metric::builder()
+                .namespace(LAMBDA_NAMESPACE)
+                .metric_name(metric_name)
+                .dimensions(
+                    Dimension::builder()
+                        .name("FunctionName")
+                        .value(function_name)
+                        .build(),
+                )
+                .build();
+            let metric_stat = MetricStat::builder()
+                .metric(metric)
+                .period(period as i32)
+                .stat(statistic)
+                .build();
+            MetricDataQuery::builder()
+                .id(id)
+                .metric_stat(metric_stat)
+                .build()
+        };
+
+        let mut pages = self
+            .client
+            .get_metric_data()
+            .start_time(DateTime::from_millis(start_time))
+            .end_time(DateTime::from_millis(end_time))
+            .metric_data_queries(query("invocations", "Invocations", "Sum"))
+            .metric_data_queries(query("errors", "Errors", "Sum"))
+            .metric_data_queries(query("throttles", "Throttles", "Sum"))
+            .metric_data_queries(query("total_duration", "Duration", "Sum"))
+            .metric_data_queries(query("durations", "Duration", "SampleCount"))
+            .metric_data_queries(query("maximum_duration", "Duration", "Maximum"))
+            .into_paginator()
+            .send();
+
+        let mut metrics = FunctionMetrics::default();
+        // The time range may straddle two periods, so the values of each are combined.
+        let (mut total_duration, mut durations) = (0.0, 0.0);
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("GetMetricData", error))?;
+            for result in page.metric_data_results() {
+                for &value in result.values() {
+                    match result.id() {
+                        Some("invocations") => metrics.invocations += value as u64,
+                        Some("errors") => metrics.errors += value as u64,
+                        Some("throttles") => metrics.throttles += value as u64,
+                        Some("total_duration") => total_duration += value,
+                        Some("durations") => durations += value,
+                        Some("maximum_duration") => {
+                            metrics.maximum_duration =
+                                Some(metrics.maximum_duration.map_or(value, |max| max.max(value)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        metrics.average_duration = (durations > 0.0).then(|| total_duration / durations);
+
+        Ok(metrics)
+    }
+}
@@ -0,0 +1,275 @@
+//! Client for AWS CodeBuild.
+//!
+//! Provides optimized methods for finding the recent builds of projects, and the log streams
+//! they log to.
+use aws_config::SdkConfig;
+use aws_sdk_codebuild;
+use aws_sdk_codebuild::primitives::DateTime;
+use aws_sdk_codebuild::types::{LogsConfigStatusType, SortOrderType};
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Most projects, and builds, that can be got at once.
+const BATCH_SIZE: usize = 100;
+
+/// A build project.
+#[derive(Clone, Debug, Default, Ord, Eq, Hash, PartialOrd, PartialEq)]
+pub struct Project {
+    pub name: String,
+    /// The log group that builds log to, unless logging to CloudWatch Logs is disabled.
+    pub log_group_name: Option<String>,
+}
+
+impl Project {
+    /// Name of the log group that builds of the project `name` log to, unless configured
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::codebuild::Project;
+    ///
+    /// assert_eq!(Project::default_log_group_name("deploy"), "/aws/codebuild/deploy");
+    /// ```
+    pub fn default_log_group_name(name: &str) -> String {
+        format!("/aws/codebuild/{name}")
+    }
+}
+
+/// A build of a project.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Build {
+    pub id: String,
+    /// The number of the build, counting the builds of its project.
+    pub number: Option<i64>,
+    /// Such as `IN_PROGRESS`, `SUCCEEDED`, or `FAILED`.
+    pub status: String,
+    /// The phase the build is in, such as `BUILD`, or `COMPLETED` once it finished.
+    pub current_phase: Option<String>,
+    /// When the build started, and ended if it has, in milliseconds since the Unix epoch.
+    pub start_time: i64,
+    pub end_time: Option<i64>,
+    /// The version of the source built, such as a commit ID or branch.
+    pub source_version: Option<String>,
+    /// Who started the build, such as an IAM user or a CodePipeline pipeline.
+    pub initiator: Option<String>,
+    /// The log stream the build logs to, and its log group, once it started logging.
+    pub log_group_name: Option<String>,
+    pub log_stream_name: Option<String>,
+}
+
+impl Build {
+    /// Whether the build is still running, so its logs are still growing.
+    pub fn in_progress(&self) -> bool {
+        self.status == "IN_PROGRESS"
+    }
+
+    /// Whether the build failed, faulted, timed out, or was stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::codebuild::Build;
+    ///
+    /// let build = Build {
+    ///     status: "TIMED_OUT".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(build.failed());
+    /// assert!(!build.in_progress());
+    /// ```
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "FAILED" | "FAULT" | "TIMED_OUT" | "STOPPED"
+        )
+    }
+}
+
+fn to_millis(date: &DateTime) -> i64 {
+    date.to_millis().unwrap_or_default()
+}
+
+/// Client instance for AWS CodeBuild
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_codebuild::Client,
+}
+
+impl Client {
+    /// Create a new AWS CodeBuild client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{codebuild, config};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let codebuild_client = codebuild::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_codebuild::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_codebuild::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get _all_ build projects, sorted by name, with the log groups their builds log to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of projects, or the details of any of them, fail to
+    /// load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{codebuild, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let codebuild_client = codebuild::Client::new(&sdk_config);
+    ///
+    /// for project in codebuild_client.get_all_projects().await? {
+    ///     println!("{} logs to {:?}", project.name, project.log_group_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_projects(&self) -> Result<Vec<Project>, AwsError> {
+        let mut names = Vec::new();
+        let mut pages = self.client.list_projects().into_paginator().send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("ListProjects", error))?;
+            names.extend(page.projects().iter().cloned());
+        }
+
+        let mut projects = Vec::new();
+        for names in names.chunks(BATCH_SIZE) {
+            let response = self
+                .client
+                .batch_get_projects()
+                .set_names(Some(names.to_vec()))
+                .send()
+                .await
+                .map_err(|error| AwsError::new("BatchGetProjects", error))?;
+            projects.extend(response.projects().iter().filter_map(|project| {
+                let name = project.name()?.to_string();
+                let cloudwatch_logs = project
+                    .logs_config()
+                    .and_then(|logs_config| logs_config.cloud_watch_logs());
+                let log_group_name = match cloudwatch_logs {
+                    Some(config) if config.status() == &LogsConfigStatusType::Disabled => None,
+                    Some(config) => Some(
+                        config
+                            .group_name()
+                            .map_or_else(|| Project::default_log_group_name(&name), String::from),
+                    ),
+                    None => Some(Project::default_log_group_name(&name)),
+                };
+                Some(Project {
+                    name,
+                    log_group_name,
+                })
+            }));
+        }
+
+        projects.sort();
+
+        Ok(projects)
+    }
+
+    /// Get up to `limit` of the most recent builds of the project `project_name`, most recent
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the builds fail to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{codebuild, config};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let codebuild_client = codebuild::Client::new(&sdk_config);
+    ///
+    /// for build in codebuild_client.get_recent_builds("deploy", 50).await? {
+    ///     println!("{} {}", build.id, build.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recent_builds(
+        &self,
+        project_name: &str,
+        limit: usize,
+    ) -> Result<Vec<Build>, AwsError> {
+        let response = self
+            .client
+            .list_builds_for_project()
+            .project_name(project_name)
+            .sort_order(SortOrderType::Descending)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("ListBuildsForProject", error))?;
+        let ids: Vec<String> = response
+            .ids()
+            .iter()
+            .take(limit.min(BATCH_SIZE))
+            .cloned()
+            .collect();
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response = self
+            .client
+            .batch_get_builds()
+            .set_ids(Some(ids.clone()))
+            .send()
+            .await
+            .map_err(|error| AwsError::new("BatchGetBuilds", error))?;
+
+        // Builds are returned in any order, so are put back in the order they were listed.
+        let mut builds: Vec<Build> = response
+            .builds()
+            .iter()
+            .map(|build| {
+                let logs = build.logs();
+                Build {
+                    id: build.id().unwrap_or_default().to_string(),
+                    number: build.build_number(),
+                    status: build
+                        .build_status()
+                        .map(|status| status.as_str().to_string())
+                        .unwrap_or_default(),
+                    current_phase: build.current_phase().map(String::from),
+                    start_time: build.start_time().map(to_millis).unwrap_or_default(),
+                    end_time: build.end_time().map(to_millis),
+                    source_version: build
+                        .resolved_source_version()
+                        .or(build.source_version())
+                        .map(String::from),
+                    initiator: build.initiator().map(String::from),
+                    log_group_name: logs.and_then(|logs| logs.group_name()).map(String::from),
+                    log_stream_name: logs.and_then(|logs| logs.stream_name()).map(String::from),
+                }
+            })
+            .collect();
+        builds.sort_by_key(|build| ids.iter().position(|id| id == &build.id));
+
+        Ok(builds)
+    }
+}
@@ -1,15 +1,70 @@
 //! Customize `aws_config` with optional Profile and Region values.
 //!
 //! If Profile or Region are provided, use those to override the values
-//! inferred from the environment or AWS configuration.
+//! inferred from the environment or AWS configuration. A role, such as one in
+//! another account, may also be assumed on top of the inferred credentials.
 //!
 //! Uses the latest AWS SDK behavior.
-use aws_config::{BehaviorVersion, Region, SdkConfig};
+use std::{env, fs, path::PathBuf};
+
+use aws_config::{BehaviorVersion, Region, SdkConfig, sts::AssumeRoleProvider};
+use aws_credential_types::provider::SharedCredentialsProvider;
+
+use super::retry::retry_config;
+
+const DEFAULT_PROFILE_NAME: &str = "default";
+
+// Name of the sessions of assumed roles, to tell the app's calls apart in CloudTrail.
+const ROLE_SESSION_NAME: &str = "aws-logs-tui";
+
+// Stands in for every Region in a list of Regions.
+const ALL_REGIONS: &str = "all";
+
+/// The AWS Regions of the standard `aws` partition, in sorted order.
+///
+/// The AWS SDK doesn't list the Regions it knows about, so they are listed here.
+/// Regions that aren't enabled for an account fail to authenticate when used.
+pub const REGIONS: &[&str] = &[
+    "af-south-1",
+    "ap-east-1",
+    "ap-east-2",
+    "ap-northeast-1",
+    "ap-northeast-2",
+    "ap-northeast-3",
+    "ap-south-1",
+    "ap-south-2",
+    "ap-southeast-1",
+    "ap-southeast-2",
+    "ap-southeast-3",
+    "ap-southeast-4",
+    "ap-southeast-5",
+    "ap-southeast-7",
+    "ca-central-1",
+    "ca-west-1",
+    "eu-central-1",
+    "eu-central-2",
+    "eu-north-1",
+    "eu-south-1",
+    "eu-south-2",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "il-central-1",
+    "me-central-1",
+    "me-south-1",
+    "mx-central-1",
+    "sa-east-1",
+    "us-east-1",
+    "us-east-2",
+    "us-west-1",
+    "us-west-2",
+];
 
 /// Load the AWS configuration for use with AWS clients.
 ///
 /// Apply the optional `profile` and `region` values to override
 /// the inferred defaults from the environment or AWS configuration.
+/// Throttled calls are retried as configured by [`retry_config()`].
 ///
 /// # Examples
 ///
@@ -38,7 +93,7 @@ use aws_config::{BehaviorVersion, Region, SdkConfig};
 /// # }
 /// ```
 pub async fn load_config(profile: Option<String>, region: Option<String>) -> SdkConfig {
-    let mut config = aws_config::defaults(BehaviorVersion::latest());
+    let mut config = aws_config::defaults(BehaviorVersion::latest()).retry_config(retry_config());
     if let Some(profile_name) = profile {
         config = config.profile_name(profile_name);
     }
@@ -48,3 +103,165 @@ pub async fn load_config(profile: Option<String>, region: Option<String>) -> Sdk
 
     config.load().await
 }
+
+/// Derive the AWS configuration that assumes the role `role_arn` from `config`, whose
+/// credentials are used to assume it.
+///
+/// The role is only assumed once credentials are first needed, and assumed again whenever
+/// its session is about to expire, so long-running sessions keep working.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use aws_logs_tui::aws::config;
+///
+/// let sdk_config = config::load_config(None, Some(String::from("us-east-1"))).await;
+/// let sdk_config =
+///     config::assume_role(&sdk_config, "arn:aws:iam::123456789012:role/logs-reader").await;
+///
+/// assert!(sdk_config.credentials_provider().is_some());
+/// # }
+/// ```
+pub async fn assume_role(config: &SdkConfig, role_arn: &str) -> SdkConfig {
+    let provider = AssumeRoleProvider::builder(role_arn)
+        .session_name(ROLE_SESSION_NAME)
+        .configure(config)
+        .build()
+        .await;
+
+    config
+        .to_builder()
+        .credentials_provider(SharedCredentialsProvider::new(provider))
+        .build()
+}
+
+/// Parse a comma-separated list of AWS Regions, where `all` is every one of [`REGIONS`].
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::config;
+///
+/// assert_eq!(config::parse_regions("us-east-1, eu-west-1"), ["us-east-1", "eu-west-1"]);
+/// assert_eq!(config::parse_regions("all").len(), config::REGIONS.len());
+/// ```
+pub fn parse_regions(regions: &str) -> Vec<String> {
+    if regions.trim() == ALL_REGIONS {
+        return REGIONS.iter().map(ToString::to_string).collect();
+    }
+
+    regions
+        .split(',')
+        .map(str::trim)
+        .filter(|region| !region.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Derive the AWS configuration for another `region` from `config`, keeping its credentials.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use aws_logs_tui::aws::config;
+///
+/// let sdk_config = config::load_config(None, Some(String::from("us-east-1"))).await;
+/// let eu_sdk_config = config::with_region(&sdk_config, "eu-west-1");
+///
+/// assert_eq!(eu_sdk_config.region().unwrap().as_ref(), "eu-west-1");
+/// # }
+/// ```
+pub fn with_region(config: &SdkConfig, region: &str) -> SdkConfig {
+    config
+        .to_builder()
+        .region(Region::new(region.to_string()))
+        .build()
+}
+
+/// List the names of the profiles in the shared AWS config and credentials files, in sorted order.
+///
+/// The files are found the same way as the AWS SDK, honoring `AWS_CONFIG_FILE` and
+/// `AWS_SHARED_CREDENTIALS_FILE`. Missing or unreadable files have no profiles.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::config;
+///
+/// for profile_name in config::list_profiles() {
+///     println!("{profile_name}");
+/// }
+/// ```
+pub fn list_profiles() -> Vec<String> {
+    let mut profile_names = Vec::new();
+    for (path, is_config_file) in [(config_file_path(), true), (credentials_file_path(), false)] {
+        if let Some(contents) = path.and_then(|path| fs::read_to_string(path).ok()) {
+            profile_names.extend(parse_profile_names(&contents, is_config_file));
+        }
+    }
+
+    profile_names.sort();
+    profile_names.dedup();
+
+    profile_names
+}
+
+/// Parse the names of the profiles in the contents of a shared AWS config or credentials file.
+///
+/// Profiles are declared as `[profile name]` in the config file, except for `[default]`,
+/// and as `[name]` in the credentials file. Other sections, such as `[sso-session name]`,
+/// are not profiles.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::config;
+///
+/// let contents = "
+/// [default]
+/// region = us-west-2
+///
+/// [profile dev]
+/// sso_session = my-sso
+///
+/// [sso-session my-sso]
+/// sso_region = us-east-1
+/// ";
+///
+/// assert_eq!(config::parse_profile_names(contents, true), ["default", "dev"]);
+/// assert_eq!(config::parse_profile_names("[prod]", false), ["prod"]);
+/// ```
+pub fn parse_profile_names(contents: &str, is_config_file: bool) -> Vec<String> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let section = line.trim().strip_prefix('[')?.strip_suffix(']')?.trim();
+            if !is_config_file || section == DEFAULT_PROFILE_NAME {
+                return Some(section);
+            }
+            section
+                .strip_prefix("profile")
+                .filter(|name| name.starts_with(char::is_whitespace))
+        })
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn config_file_path() -> Option<PathBuf> {
+    match env::var_os("AWS_CONFIG_FILE") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(dirs::home_dir()?.join(".aws").join("config")),
+    }
+}
+
+fn credentials_file_path() -> Option<PathBuf> {
+    match env::var_os("AWS_SHARED_CREDENTIALS_FILE") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => Some(dirs::home_dir()?.join(".aws").join("credentials")),
+    }
+}
@@ -0,0 +1,259 @@
+//! Links to the AWS Management Console.
+//!
+//! The CloudWatch console takes its own escaping in the fragment of its URLs, which is URL
+//! encoding with `$` in place of `%`, after URL encoding names once already.
+
+/// URL of the AWS Lambda console page of the function `function_name`, in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::lambda_function_url("us-east-1", "my-function"),
+///     "https://us-east-1.console.aws.amazon.com/lambda/home?region=us-east-1#/functions/my-function"
+/// );
+/// ```
+pub fn lambda_function_url(region: &str, function_name: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/lambda/home?region={region}#/functions/{}",
+        url_encode(function_name)
+    )
+}
+
+/// URL of the Amazon ECS console page of the service `service_name`, of the cluster
+/// `cluster_name`, in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::ecs_service_url("us-east-1", "my-cluster", "my-service"),
+///     "https://us-east-1.console.aws.amazon.com/ecs/v2/clusters/my-cluster/services/my-service\
+///      ?region=us-east-1"
+/// );
+/// ```
+pub fn ecs_service_url(region: &str, cluster_name: &str, service_name: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/ecs/v2/clusters/{}/services/{}?region={region}",
+        url_encode(cluster_name),
+        url_encode(service_name)
+    )
+}
+
+/// URL of the AWS Step Functions console page of the state machine `state_machine_arn`, in
+/// `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::state_machine_url(
+///         "us-east-1",
+///         "arn:aws:states:us-east-1:123456789012:stateMachine:my-machine",
+///     ),
+///     "https://us-east-1.console.aws.amazon.com/states/home?region=us-east-1\
+///      #/statemachines/view/arn%3Aaws%3Astates%3Aus-east-1%3A123456789012%3AstateMachine%3Amy-machine"
+/// );
+/// ```
+pub fn state_machine_url(region: &str, state_machine_arn: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/states/home?region={region}#/statemachines/view/{}",
+        url_encode(state_machine_arn)
+    )
+}
+
+/// URL of the AWS Step Functions console page of the execution `execution_arn`, in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::execution_url(
+///         "us-east-1",
+///         "arn:aws:states:us-east-1:123456789012:execution:my-machine:my-run",
+///     ),
+///     "https://us-east-1.console.aws.amazon.com/states/home?region=us-east-1\
+///      #/v2/executions/details/arn%3Aaws%3Astates%3Aus-east-1%3A123456789012%3Aexecution%3Amy-machine%3Amy-run"
+/// );
+/// ```
+pub fn execution_url(region: &str, execution_arn: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/states/home?region={region}#/v2/executions/details/{}",
+        url_encode(execution_arn)
+    )
+}
+
+/// URL of the Amazon API Gateway console page of the stage `stage_name`, of the REST API
+/// `api_id`, in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::api_stage_url("us-east-1", "a1b2c3d4e5", "prod"),
+///     "https://us-east-1.console.aws.amazon.com/apigateway/main/apis/a1b2c3d4e5/stages\
+///      ?api=a1b2c3d4e5&region=us-east-1#stage=prod"
+/// );
+/// ```
+pub fn api_stage_url(region: &str, api_id: &str, stage_name: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/apigateway/main/apis/{api_id}/stages\
+         ?api={api_id}&region={region}#stage={}",
+        url_encode(stage_name)
+    )
+}
+
+/// URL of the Amazon RDS console page of the database `identifier`, a DB cluster if `cluster`
+/// or else a DB instance, in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::rds_database_url("us-east-1", "orders", true),
+///     "https://us-east-1.console.aws.amazon.com/rds/home?region=us-east-1\
+///      #database:id=orders;is-cluster=true"
+/// );
+/// ```
+pub fn rds_database_url(region: &str, identifier: &str, cluster: bool) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/rds/home?region={region}\
+         #database:id={};is-cluster={cluster}",
+        url_encode(identifier)
+    )
+}
+
+/// URL of the AWS CodeBuild console page of the build history of the project `project_name`,
+/// in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::codebuild_project_url("us-east-1", "deploy"),
+///     "https://us-east-1.console.aws.amazon.com/codesuite/codebuild/projects/deploy/history\
+///      ?region=us-east-1"
+/// );
+/// ```
+pub fn codebuild_project_url(region: &str, project_name: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/codesuite/codebuild/projects/{}/history?region={region}",
+        url_encode(project_name)
+    )
+}
+
+/// URL of the AWS CodeBuild console page of the build `build_id`, of the project
+/// `project_name`, in `region`.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::codebuild_build_url(
+///         "us-east-1",
+///         "deploy",
+///         "deploy:0f9e8d7c-6b5a-4321-9876-543210fedcba",
+///     ),
+///     "https://us-east-1.console.aws.amazon.com/codesuite/codebuild/projects/deploy\
+///      /build/deploy%3A0f9e8d7c-6b5a-4321-9876-543210fedcba/?region=us-east-1"
+/// );
+/// ```
+pub fn codebuild_build_url(region: &str, project_name: &str, build_id: &str) -> String {
+    format!(
+        "https://{region}.console.aws.amazon.com/codesuite/codebuild/projects/{}/build/{}/?region={region}",
+        url_encode(project_name),
+        url_encode(build_id)
+    )
+}
+
+/// URL of the AWS CloudWatch Logs console page of the log events of the log group
+/// `log_group_name`, in `region`.
+///
+/// Only log events of `log_stream_name` are shown, when set, and only those between
+/// `start_time` and `end_time`, in milliseconds since the Unix epoch, when set.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::console;
+///
+/// assert_eq!(
+///     console::log_events_url("us-east-1", "/aws/lambda/my-function", None, None, None),
+///     "https://us-east-1.console.aws.amazon.com/cloudwatch/home?region=us-east-1\
+///      #logsV2:log-groups/log-group/$252Faws$252Flambda$252Fmy-function/log-events"
+/// );
+/// assert_eq!(
+///     console::log_events_url(
+///         "us-east-1",
+///         "/aws/lambda/my-function",
+///         Some("2024/06/01/[$LATEST]0123"),
+///         Some(1_717_200_000_000),
+///         None,
+///     ),
+///     "https://us-east-1.console.aws.amazon.com/cloudwatch/home?region=us-east-1\
+///      #logsV2:log-groups/log-group/$252Faws$252Flambda$252Fmy-function\
+///      /log-events/2024$252F06$252F01$252F$255B$2524LATEST$255D0123$3Fstart$3D1717200000000"
+/// );
+/// ```
+pub fn log_events_url(
+    region: &str,
+    log_group_name: &str,
+    log_stream_name: Option<&str>,
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+) -> String {
+    let mut url = format!(
+        "https://{region}.console.aws.amazon.com/cloudwatch/home?region={region}\
+         #logsV2:log-groups/log-group/{}/log-events",
+        console_encode(log_group_name)
+    );
+    if let Some(log_stream_name) = log_stream_name {
+        url.push('/');
+        url.push_str(&console_encode(log_stream_name));
+    }
+
+    let params: Vec<String> = [("start", start_time), ("end", end_time)]
+        .into_iter()
+        .filter_map(|(name, time)| Some(format!("{name}={}", time?)))
+        .collect();
+    if !params.is_empty() {
+        url.push_str(&url_encode(&format!("?{}", params.join("&"))).replace('%', "$"));
+    }
+
+    url
+}
+
+/// `value` with every character but the unreserved characters of RFC 3986 percent-encoded.
+fn url_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(char::from(byte));
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// The name `value` escaped as the CloudWatch console escapes names in its URLs.
+fn console_encode(value: &str) -> String {
+    url_encode(&url_encode(value)).replace('%', "$")
+}
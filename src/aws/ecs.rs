@@ -0,0 +1,272 @@
+//! Client for AWS Elastic Container Service.
+//!
+//! Provides optimized methods for finding the logs of ECS services.
+use aws_config::SdkConfig;
+use aws_sdk_ecs;
+use aws_sdk_ecs::types::LogDriver;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Options of the `awslogs` log driver, naming where containers log to.
+const AWSLOGS_GROUP: &str = "awslogs-group";
+const AWSLOGS_STREAM_PREFIX: &str = "awslogs-stream-prefix";
+
+/// The resource name at the end of the ARN `arn`, such as the ID of a task.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::ecs;
+///
+/// assert_eq!(
+///     ecs::resource_name("arn:aws:ecs:us-east-1:123456789012:task/my-cluster/0123456789abcdef"),
+///     "0123456789abcdef"
+/// );
+/// assert_eq!(ecs::resource_name("my-cluster"), "my-cluster");
+/// ```
+pub fn resource_name(arn: &str) -> &str {
+    arn.rsplit('/').next().unwrap_or(arn)
+}
+
+#[derive(Clone, Debug, Ord, Eq, Hash, PartialOrd, PartialEq)]
+pub struct Service {
+    /// The name of the cluster the service runs in.
+    pub cluster: String,
+    pub name: String,
+}
+
+/// Where a container of a service logs to, with the `awslogs` log driver.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContainerLogs {
+    pub container_name: String,
+    pub log_group_name: String,
+    /// Prefix of the log streams of the container, which are only named by task ID without one.
+    pub stream_prefix: Option<String>,
+}
+
+impl ContainerLogs {
+    /// The log stream of the container in the task `task_id`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::ecs::ContainerLogs;
+    ///
+    /// let container_logs = ContainerLogs {
+    ///     container_name: "web".to_string(),
+    ///     log_group_name: "/ecs/my-service".to_string(),
+    ///     stream_prefix: Some("ecs".to_string()),
+    /// };
+    /// assert_eq!(container_logs.log_stream_name("0123456789abcdef"), "ecs/web/0123456789abcdef");
+    /// ```
+    pub fn log_stream_name(&self, task_id: &str) -> String {
+        match &self.stream_prefix {
+            Some(prefix) => format!("{prefix}/{}/{task_id}", self.container_name),
+            None => task_id.to_string(),
+        }
+    }
+
+    /// The prefix of the log streams of the container, in any task, if it has a stream prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::ecs::ContainerLogs;
+    ///
+    /// let container_logs = ContainerLogs {
+    ///     container_name: "web".to_string(),
+    ///     log_group_name: "/ecs/my-service".to_string(),
+    ///     stream_prefix: Some("ecs".to_string()),
+    /// };
+    /// assert_eq!(container_logs.log_stream_prefix().as_deref(), Some("ecs/web/"));
+    /// ```
+    pub fn log_stream_prefix(&self) -> Option<String> {
+        let prefix = self.stream_prefix.as_ref()?;
+        Some(format!("{prefix}/{}/", self.container_name))
+    }
+}
+
+/// Where the containers of a service log to, and the IDs of its running tasks.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ServiceLogs {
+    /// Containers that log with the `awslogs` log driver, others can't be viewed.
+    pub containers: Vec<ContainerLogs>,
+    pub task_ids: Vec<String>,
+}
+
+/// Client instance for AWS Elastic Container Service
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_ecs::Client,
+}
+
+impl Client {
+    /// Create a new AWS Elastic Container Service client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{config, ecs};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let ecs_client = ecs::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_ecs::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_ecs::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get _all_ services of every cluster, in sorted order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of clusters, or of services, fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, ecs};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let ecs_client = ecs::Client::new(&sdk_config);
+    ///
+    /// for service in ecs_client.get_all_services().await? {
+    ///     println!("{}/{}", service.cluster, service.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_services(&self) -> Result<Vec<Service>, AwsError> {
+        let mut cluster_arns = Vec::new();
+        let mut pages = self.client.list_clusters().into_paginator().send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("ListClusters", error))?;
+            cluster_arns.extend(page.cluster_arns().iter().cloned());
+        }
+
+        let mut services = Vec::new();
+        for cluster_arn in &cluster_arns {
+            let mut pages = self
+                .client
+                .list_services()
+                .cluster(cluster_arn)
+                .into_paginator()
+                .send();
+            while let Some(page) = pages.next().await {
+                let page = page.map_err(|error| AwsError::new("ListServices", error))?;
+                services.extend(page.service_arns().iter().map(|service_arn| Service {
+                    cluster: resource_name(cluster_arn).to_string(),
+                    name: resource_name(service_arn).to_string(),
+                }));
+            }
+        }
+
+        services.sort();
+
+        Ok(services)
+    }
+
+    /// Get where the containers of `service` log to, from its task definition, and its
+    /// running tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the service, its task definition, or its tasks fail to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, ecs};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let ecs_client = ecs::Client::new(&sdk_config);
+    ///
+    /// let service = ecs::Service {
+    ///     cluster: "my-cluster".to_string(),
+    ///     name: "my-service".to_string(),
+    /// };
+    /// let service_logs = ecs_client.get_service_logs(&service).await?;
+    /// for container_logs in &service_logs.containers {
+    ///     println!("{} logs to {}", container_logs.container_name, container_logs.log_group_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_service_logs(&self, service: &Service) -> Result<ServiceLogs, AwsError> {
+        let response = self
+            .client
+            .describe_services()
+            .cluster(&service.cluster)
+            .services(&service.name)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("DescribeServices", error))?;
+        let task_definition = response
+            .services()
+            .first()
+            .and_then(|service| service.task_definition());
+
+        let mut containers = Vec::new();
+        if let Some(task_definition) = task_definition {
+            let response = self
+                .client
+                .describe_task_definition()
+                .task_definition(task_definition)
+                .send()
+                .await
+                .map_err(|error| AwsError::new("DescribeTaskDefinition", error))?;
+            let container_definitions = response
+                .task_definition()
+                .map(|task_definition| task_definition.container_definitions())
+                .unwrap_or_default();
+            containers.extend(container_definitions.iter().filter_map(|container| {
+                let log_configuration = container.log_configuration()?;
+                if *log_configuration.log_driver() != LogDriver::Awslogs {
+                    return None;
+                }
+                let options = log_configuration.options()?;
+                Some(ContainerLogs {
+                    container_name: container.name()?.to_string(),
+                    log_group_name: options.get(AWSLOGS_GROUP)?.clone(),
+                    stream_prefix: options.get(AWSLOGS_STREAM_PREFIX).cloned(),
+                })
+            }));
+        }
+
+        let mut task_ids = Vec::new();
+        let mut pages = self
+            .client
+            .list_tasks()
+            .cluster(&service.cluster)
+            .service_name(&service.name)
+            .into_paginator()
+            .send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("ListTasks", error))?;
+            task_ids.extend(
+                page.task_arns()
+                    .iter()
+                    .map(|task_arn| resource_name(task_arn).to_string()),
+            );
+        }
+
+        Ok(ServiceLogs {
+            containers,
+            task_ids,
+        })
+    }
+}
@@ -0,0 +1,128 @@
+//! Logs of Kubernetes clusters in Amazon Elastic Kubernetes Service.
+//!
+//! Container Insights, and Fluent Bit, ship the logs of containers to CloudWatch Logs as JSON
+//! records naming the namespace, pod, and container that logged them.
+use serde_json::Value;
+
+// Prefix of the log groups Container Insights ships the logs of a cluster to.
+const CONTAINER_INSIGHTS_PREFIX: &str = "/aws/containerinsights/";
+// Prefix, and suffix, of the log group of the control plane of a cluster.
+const EKS_PREFIX: &str = "/aws/eks/";
+const CONTROL_PLANE_SUFFIX: &str = "/cluster";
+
+/// A log group with the logs of a Kubernetes cluster, found by its name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClusterLogGroup {
+    /// The name of the cluster, unless the log group doesn't name it.
+    pub cluster: Option<String>,
+    /// Which logs of the cluster the log group has, such as `application` or `control plane`.
+    pub kind: String,
+}
+
+impl ClusterLogGroup {
+    /// The cluster logs in the log group `log_group_name`, or `None` if it isn't one of the
+    /// log groups of Container Insights, of the control plane of a cluster, or of Fluent Bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::eks::ClusterLogGroup;
+    ///
+    /// let log_group = ClusterLogGroup::parse("/aws/containerinsights/prod/application").unwrap();
+    /// assert_eq!(log_group.cluster.as_deref(), Some("prod"));
+    /// assert_eq!(log_group.kind, "application");
+    ///
+    /// let log_group = ClusterLogGroup::parse("/aws/eks/prod/cluster").unwrap();
+    /// assert_eq!(log_group.kind, "control plane");
+    ///
+    /// let log_group = ClusterLogGroup::parse("/aws/eks/fluentbit-cloudwatch/logs").unwrap();
+    /// assert_eq!(log_group.cluster, None);
+    /// assert_eq!(log_group.kind, "fluent-bit");
+    ///
+    /// assert_eq!(ClusterLogGroup::parse("/aws/lambda/my-function"), None);
+    /// ```
+    pub fn parse(log_group_name: &str) -> Option<Self> {
+        if let Some((cluster, kind)) = log_group_name
+            .strip_prefix(CONTAINER_INSIGHTS_PREFIX)
+            .and_then(|rest| rest.split_once('/'))
+        {
+            return Some(Self {
+                cluster: Some(cluster.to_string()),
+                kind: kind.to_string(),
+            });
+        }
+
+        if let Some(cluster) = log_group_name
+            .strip_prefix(EKS_PREFIX)
+            .and_then(|rest| rest.strip_suffix(CONTROL_PLANE_SUFFIX))
+            .filter(|cluster| !cluster.contains('/'))
+        {
+            return Some(Self {
+                cluster: Some(cluster.to_string()),
+                kind: "control plane".to_string(),
+            });
+        }
+
+        let name = log_group_name.to_lowercase();
+        (name.contains("fluent-bit") || name.contains("fluentbit")).then(|| Self {
+            cluster: None,
+            kind: "fluent-bit".to_string(),
+        })
+    }
+
+    /// A short label of the log group, such as `EKS prod · application`.
+    pub fn label(&self) -> String {
+        match &self.cluster {
+            Some(cluster) => format!("EKS {cluster} · {}", self.kind),
+            None => format!("EKS · {}", self.kind),
+        }
+    }
+}
+
+/// A line logged by a container in a Kubernetes pod, from the record Fluent Bit shipped it in.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct KubernetesLog {
+    pub namespace: String,
+    pub pod: String,
+    pub container: Option<String>,
+    /// The logged line, without its trailing newline.
+    pub log: String,
+}
+
+impl KubernetesLog {
+    /// Parse the Fluent Bit record `message`, or `None` if it isn't JSON with the logged line
+    /// and the namespace and pod that logged it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::eks::KubernetesLog;
+    ///
+    /// let kubernetes_log = KubernetesLog::parse(
+    ///     r#"{"log": "GET /health 200\n", "stream": "stdout",
+    ///         "kubernetes": {"namespace_name": "shop", "pod_name": "web-7c9d5-x2k4q", "container_name": "web"}}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(kubernetes_log.namespace, "shop");
+    /// assert_eq!(kubernetes_log.pod, "web-7c9d5-x2k4q");
+    /// assert_eq!(kubernetes_log.container.as_deref(), Some("web"));
+    /// assert_eq!(kubernetes_log.log, "GET /health 200");
+    /// assert_eq!(KubernetesLog::parse(r#"{"log": "GET /health 200"}"#), None);
+    /// ```
+    pub fn parse(message: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(message.trim()).ok()?;
+        let kubernetes = value.get("kubernetes")?;
+        let text = |value: &Value, name: &str| value[name].as_str().map(String::from);
+
+        // Fluent Bit keeps the line in `log`, unless configured to name it otherwise.
+        let log = text(&value, "log").or_else(|| text(&value, "message"))?;
+
+        Some(Self {
+            namespace: text(kubernetes, "namespace_name")?,
+            pod: text(kubernetes, "pod_name")?,
+            container: text(kubernetes, "container_name"),
+            log: log.trim_end().to_string(),
+        })
+    }
+}
@@ -0,0 +1,202 @@
+//! Errors from calling AWS.
+//!
+//! Wraps the errors of every AWS SDK client in a single type, keeping the AWS error code
+//! so callers can react to specific failures such as expired credentials or throttling.
+use std::error::Error;
+use std::fmt;
+
+use aws_smithy_runtime_api::client::result::SdkError;
+use aws_smithy_types::error::display::DisplayErrorContext;
+use aws_smithy_types::error::metadata::ProvideErrorMetadata;
+
+/// A failed call to AWS.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AwsError {
+    operation: String,
+    code: Option<String>,
+    message: String,
+}
+
+impl AwsError {
+    /// Create an error for a failed call to the AWS API `operation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::error::AwsError;
+    /// use aws_smithy_runtime_api::client::result::SdkError;
+    /// use aws_sdk_lambda::operation::list_functions::ListFunctionsError;
+    ///
+    /// let sdk_error: SdkError<ListFunctionsError, ()> =
+    ///     SdkError::construction_failure("missing region");
+    /// let error = AwsError::new("ListFunctions", sdk_error);
+    ///
+    /// assert_eq!(error.operation(), "ListFunctions");
+    /// assert_eq!(error.code(), None);
+    /// assert!(error.message().contains("missing region"));
+    /// ```
+    pub fn new<E, R>(operation: &str, error: SdkError<E, R>) -> Self
+    where
+        E: ProvideErrorMetadata + Error + 'static,
+        R: fmt::Debug,
+    {
+        let code = error.code().map(String::from);
+        // Service errors carry a message, other failures (such as missing credentials)
+        // are only described by their chain of sources.
+        let message = match error.message() {
+            Some(message) => message.to_string(),
+            None => DisplayErrorContext(&error).to_string(),
+        };
+
+        Self {
+            operation: operation.to_string(),
+            code,
+            message,
+        }
+    }
+
+    /// Create an error for a call to the AWS API `operation` that couldn't be made, or whose
+    /// response couldn't be used, described by `message`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::error::AwsError;
+    ///
+    /// let error = AwsError::from_message("GetQueueUrl", "my-topic is not the ARN of an SQS queue");
+    ///
+    /// assert_eq!(error.operation(), "GetQueueUrl");
+    /// assert_eq!(error.code(), None);
+    /// assert_eq!(error.message(), "my-topic is not the ARN of an SQS queue");
+    /// ```
+    pub fn from_message(operation: &str, message: impl Into<String>) -> Self {
+        Self {
+            operation: operation.to_string(),
+            code: None,
+            message: message.into(),
+        }
+    }
+
+    /// The AWS API operation that failed, such as `ListFunctions`.
+    pub fn operation(&self) -> &str {
+        &self.operation
+    }
+
+    /// The AWS error code, such as `AccessDeniedException`, if AWS responded with one.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// A description of the failure.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Whether the call failed because the temporary credentials it was signed with expired,
+    /// such as those of an assumed role, so loading the credentials again fixes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::error::AwsError;
+    /// use aws_smithy_runtime_api::client::result::SdkError;
+    /// use aws_sdk_lambda::operation::list_functions::ListFunctionsError;
+    ///
+    /// let sdk_error: SdkError<ListFunctionsError, ()> = SdkError::construction_failure(
+    ///     "The security token included in the request is expired",
+    /// );
+    /// assert!(AwsError::new("ListFunctions", sdk_error).is_credentials_expired());
+    /// ```
+    pub fn is_credentials_expired(&self) -> bool {
+        if self
+            .code
+            .as_deref()
+            .is_some_and(|code| matches!(code, "ExpiredToken" | "ExpiredTokenException"))
+        {
+            return true;
+        }
+
+        let message = self.message.to_lowercase();
+        message.contains("security token included in the request is expired")
+            || message.contains("expiredtoken")
+    }
+
+    /// Whether the call failed because the AWS IAM Identity Center (SSO) session of the
+    /// profile expired, so signing in again with `aws sso login` fixes it.
+    ///
+    /// Expired sessions fail while loading credentials, before AWS responds, so they are
+    /// mostly recognized by their message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::error::AwsError;
+    /// use aws_smithy_runtime_api::client::result::SdkError;
+    /// use aws_sdk_lambda::operation::list_functions::ListFunctionsError;
+    ///
+    /// let sdk_error: SdkError<ListFunctionsError, ()> = SdkError::construction_failure(
+    ///     "failed to load credentials: the SSO token has expired and cannot be refreshed",
+    /// );
+    /// assert!(AwsError::new("ListFunctions", sdk_error).is_sso_session_expired());
+    ///
+    /// let sdk_error: SdkError<ListFunctionsError, ()> =
+    ///     SdkError::construction_failure("missing region");
+    /// assert!(!AwsError::new("ListFunctions", sdk_error).is_sso_session_expired());
+    /// ```
+    pub fn is_sso_session_expired(&self) -> bool {
+        if self
+            .code
+            .as_deref()
+            .is_some_and(|code| code.starts_with("UnauthorizedSSOToken"))
+        {
+            return true;
+        }
+
+        let message = self.message.to_lowercase();
+        message.contains("sso")
+            && ["expired", "token not found or invalid", "unauthorized"]
+                .iter()
+                .any(|reason| message.contains(reason))
+    }
+
+    /// Whether the call was denied by IAM, so retrying it won't help without other permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::error::AwsError;
+    /// use aws_smithy_runtime_api::client::result::SdkError;
+    /// use aws_sdk_lambda::error::ErrorMetadata;
+    /// use aws_sdk_lambda::operation::list_functions::ListFunctionsError;
+    ///
+    /// let metadata = ErrorMetadata::builder()
+    ///     .code("AccessDeniedException")
+    ///     .message("User is not authorized to perform: lambda:ListFunctions")
+    ///     .build();
+    /// let sdk_error = SdkError::service_error(ListFunctionsError::generic(metadata), ());
+    /// assert!(AwsError::new("ListFunctions", sdk_error).is_access_denied());
+    ///
+    /// let sdk_error: SdkError<ListFunctionsError, ()> =
+    ///     SdkError::construction_failure("missing region");
+    /// assert!(!AwsError::new("ListFunctions", sdk_error).is_access_denied());
+    /// ```
+    pub fn is_access_denied(&self) -> bool {
+        self.code.as_deref().is_some_and(|code| {
+            matches!(
+                code,
+                "AccessDenied" | "AccessDeniedException" | "UnauthorizedOperation"
+            )
+        })
+    }
+}
+
+impl fmt::Display for AwsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.code {
+            Some(code) => write!(f, "{} failed ({code}): {}", self.operation, self.message),
+            None => write!(f, "{} failed: {}", self.operation, self.message),
+        }
+    }
+}
+
+impl Error for AwsError {}
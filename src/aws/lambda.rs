@@ -1,28 +1,562 @@
 //! Client for AWS Lambda.
 //!
 //! Provides optimized methods for accessing AWS Lambda.
+use std::collections::HashMap;
+
 use aws_config::SdkConfig;
 use aws_sdk_lambda;
+use aws_sdk_lambda::operation::RequestId;
+use aws_sdk_lambda::primitives::Blob;
+use aws_sdk_lambda::types::InvocationType;
+
+use super::cloudwatch_logs::LogEvent;
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+use super::sqs;
 
 // Maximum results for `ListFunctions` is 50, regardless of a larger configured size.
 const PAGINATION_SIZE: i32 = 50;
 
-#[derive(Clone, Debug, Ord, Eq, PartialOrd, PartialEq)]
+// Tag that CloudFormation, and so SAM, tags the resources of a stack with.
+const STACK_NAME_TAG: &str = "aws:cloudformation:stack-name";
+
+#[derive(Clone, Debug, Ord, Eq, Hash, PartialOrd, PartialEq)]
 pub struct Function {
     pub name: String,
+    /// The AWS Region of the function, if the client was configured with one.
+    pub region: Option<String>,
 }
 
 impl Function {
-    fn new(name: &str) -> Self {
+    fn new(name: &str, region: Option<&str>) -> Self {
         Self {
             name: name.to_string(),
+            region: region.map(String::from),
+        }
+    }
+}
+
+/// What listing AWS Lambda functions tells of each of them, beyond its name.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FunctionSummary {
+    /// Such as `python3.12`, unless the function is deployed as a container image.
+    pub runtime: Option<String>,
+    /// Such as `x86_64` or `arm64`.
+    pub architectures: Vec<String>,
+    /// When the function was last updated, in ISO-8601 format.
+    pub last_modified: Option<String>,
+    /// Size of the deployment package of the function, in bytes.
+    pub code_size: i64,
+}
+
+/// A single page of AWS Lambda functions.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FunctionPage {
+    pub functions: Vec<Function>,
+    /// Summaries of the functions of the page.
+    pub summaries: HashMap<Function, FunctionSummary>,
+    /// Marker to get the next page with, if there are more functions.
+    pub next_marker: Option<String>,
+}
+
+/// Configuration of an AWS Lambda function.
+///
+/// Functions deployed as container images have no runtime, or handler.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FunctionConfiguration {
+    pub arn: Option<String>,
+    pub runtime: Option<String>,
+    pub handler: Option<String>,
+    /// Memory available to the function, in MB.
+    pub memory_size: Option<i32>,
+    /// How long the function can run for, in seconds.
+    pub timeout: Option<i32>,
+    pub architectures: Vec<String>,
+    /// When the function was last updated, in ISO-8601 format.
+    pub last_modified: Option<String>,
+    /// Names and values of the environment variables of the function, sorted by name.
+    pub environment: Vec<(String, String)>,
+    /// Layers of the function, in the order they're applied.
+    pub layers: Vec<Layer>,
+    /// The Function URL of the function, if it has one.
+    pub url: Option<FunctionUrl>,
+    /// Why the Function URL failed to load, such as when not permitted, leaving `url` unknown.
+    pub url_error: Option<AwsError>,
+    /// ARN of the SQS queue, or SNS topic, that events of failed asynchronous invocations are
+    /// sent to, if any.
+    pub dead_letter_target: Option<String>,
+    /// ARN of the destination that records of failed asynchronous invocations are sent to, if
+    /// any.
+    pub on_failure_destination: Option<String>,
+    /// Why the on-failure destination failed to load, such as when not permitted, leaving
+    /// `on_failure_destination` unknown.
+    pub on_failure_error: Option<AwsError>,
+}
+
+impl FunctionConfiguration {
+    /// The SQS queue that failed events of the function are sent to, either its on-failure
+    /// destination or its dead-letter queue, if either is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::lambda::FunctionConfiguration;
+    ///
+    /// let configuration = FunctionConfiguration {
+    ///     dead_letter_target: Some("arn:aws:sns:us-east-1:123456789012:my-topic".to_string()),
+    ///     on_failure_destination: Some("arn:aws:sqs:us-east-1:123456789012:my-dlq".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     configuration.failure_queue(),
+    ///     Some("arn:aws:sqs:us-east-1:123456789012:my-dlq")
+    /// );
+    /// assert_eq!(FunctionConfiguration::default().failure_queue(), None);
+    /// ```
+    pub fn failure_queue(&self) -> Option<&str> {
+        [&self.on_failure_destination, &self.dead_letter_target]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .find(|arn| sqs::parse_queue_arn(arn).is_some())
+    }
+}
+
+/// The HTTPS endpoint of an AWS Lambda function.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FunctionUrl {
+    pub url: String,
+    /// How requests are authorized, either `AWS_IAM` or `NONE`.
+    pub auth_type: String,
+}
+
+impl FunctionUrl {
+    /// A `curl` command to request the URL with, signing the request with the credentials in
+    /// the environment when the URL needs IAM authorization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::lambda::FunctionUrl;
+    ///
+    /// let url = FunctionUrl {
+    ///     url: "https://abc123.lambda-url.us-east-1.on.aws/".to_string(),
+    ///     auth_type: "NONE".to_string(),
+    /// };
+    /// assert_eq!(url.curl_command(), "curl 'https://abc123.lambda-url.us-east-1.on.aws/'");
+    ///
+    /// let url = FunctionUrl {
+    ///     auth_type: "AWS_IAM".to_string(),
+    ///     ..url
+    /// };
+    /// assert!(url.curl_command().contains("--aws-sigv4 'aws:amz:us-east-1:lambda'"));
+    /// ```
+    pub fn curl_command(&self) -> String {
+        if self.auth_type != "AWS_IAM" {
+            return format!("curl '{}'", self.url);
+        }
+        // Function URLs look like `https://<id>.lambda-url.<region>.on.aws/`.
+        let region = self
+            .url
+            .split_once(".lambda-url.")
+            .and_then(|(_, rest)| rest.split_once('.'))
+            .map_or("us-east-1", |(region, _)| region);
+        format!(
+            "curl --aws-sigv4 'aws:amz:{region}:lambda' \\\n  \
+             --user \"$AWS_ACCESS_KEY_ID:$AWS_SECRET_ACCESS_KEY\" \\\n  \
+             -H \"x-amz-security-token: $AWS_SESSION_TOKEN\" \\\n  \
+             '{}'",
+            self.url
+        )
+    }
+}
+
+/// A version of an AWS Lambda layer attached to a function.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Layer {
+    pub arn: String,
+    pub name: String,
+    /// The version of the layer, `None` if the ARN has none.
+    pub version: Option<String>,
+}
+
+impl Layer {
+    /// The layer of the layer version `arn`, such as
+    /// `arn:aws:lambda:us-east-1:123456789012:layer:my-layer:3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::lambda::Layer;
+    ///
+    /// let layer = Layer::from_arn("arn:aws:lambda:us-east-1:123456789012:layer:my-layer:3");
+    /// assert_eq!(layer.name, "my-layer");
+    /// assert_eq!(layer.version.as_deref(), Some("3"));
+    ///
+    /// let layer = Layer::from_arn("arn:aws:lambda:us-east-1:123456789012:layer:my-layer");
+    /// assert_eq!(layer.name, "my-layer");
+    /// assert_eq!(layer.version, None);
+    /// ```
+    pub fn from_arn(arn: &str) -> Self {
+        let resource = arn
+            .split_once(":layer:")
+            .map_or(arn, |(_, resource)| resource);
+        let (name, version) = match resource.split_once(':') {
+            Some((name, version)) => (name, Some(version.to_string())),
+            None => (resource, None),
+        };
+        Self {
+            arn: arn.to_string(),
+            name: name.to_string(),
+            version,
+        }
+    }
+}
+
+/// A published version of an AWS Lambda function, or `$LATEST`, with the aliases pointing to it.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FunctionVersion {
+    pub version: String,
+    pub description: Option<String>,
+    /// Names of the aliases of the function that point to this version, sorted.
+    pub aliases: Vec<String>,
+}
+
+/// A mapping of an event source, such as an SQS queue, or a Kinesis or DynamoDB stream, that
+/// AWS Lambda reads records from to invoke a function with.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventSourceMapping {
+    pub uuid: String,
+    pub event_source_arn: Option<String>,
+    /// Such as `Enabled`, `Disabled`, or `Creating`.
+    pub state: Option<String>,
+    /// Why the state last changed, such as `USER_INITIATED`.
+    pub state_transition_reason: Option<String>,
+    /// Most records to invoke the function with at once.
+    pub batch_size: Option<i32>,
+    /// The result of the last invocation, such as `OK` or `PROBLEM: Function call failed`, for
+    /// stream sources only.
+    pub last_processing_result: Option<String>,
+    /// When the mapping was last updated, in milliseconds since the epoch.
+    pub last_modified: Option<i64>,
+}
+
+impl EventSourceMapping {
+    /// The kind of the event source and its name, from its ARN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::lambda::EventSourceMapping;
+    ///
+    /// let source = |arn: &str| EventSourceMapping {
+    ///     event_source_arn: Some(arn.to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     source("arn:aws:sqs:us-east-1:123456789012:my-queue").source(),
+    ///     Some(("SQS", "my-queue"))
+    /// );
+    /// assert_eq!(
+    ///     source("arn:aws:kinesis:us-east-1:123456789012:stream/my-stream").source(),
+    ///     Some(("Kinesis", "my-stream"))
+    /// );
+    /// assert_eq!(
+    ///     source("arn:aws:dynamodb:us-east-1:123456789012:table/my-table/stream/2024-06-01T00:00:00.000")
+    ///         .source(),
+    ///     Some(("DynamoDB", "my-table"))
+    /// );
+    /// assert_eq!(EventSourceMapping::default().source(), None);
+    /// ```
+    pub fn source(&self) -> Option<(&str, &str)> {
+        let mut parts = self.event_source_arn.as_deref()?.splitn(6, ':');
+        let service = parts.nth(2)?;
+        let resource = parts.nth(2)?;
+        let name = match resource.split_once('/') {
+            Some((_, path)) => path.split('/').next().unwrap_or(path),
+            None => resource,
+        };
+        let kind = match service {
+            "sqs" => "SQS",
+            "kinesis" => "Kinesis",
+            "dynamodb" => "DynamoDB",
+            "kafka" => "MSK",
+            "mq" => "Amazon MQ",
+            "docdb" => "DocumentDB",
+            service => service,
+        };
+        Some((kind, name))
+    }
+
+    /// Whether the mapping is disabled, or its last invocation failed, either of which stops
+    /// records reaching the function.
+    pub fn is_failing(&self) -> bool {
+        self.state.as_deref() == Some("Disabled")
+            || self
+                .last_processing_result
+                .as_deref()
+                .is_some_and(|result| result.starts_with("PROBLEM"))
+    }
+}
+
+/// The result of invoking an AWS Lambda function.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Invocation {
+    /// The ID of the request, which the function logs its invocation with.
+    pub request_id: Option<String>,
+    pub status_code: i32,
+    /// The kind of error the function failed with, such as `Unhandled`, if it failed.
+    pub function_error: Option<String>,
+    /// The response of the function, empty for a dry run.
+    pub payload: String,
+}
+
+/// The statistics AWS Lambda logs at the end of every invocation, in a `REPORT` line.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InvocationReport {
+    pub request_id: String,
+    /// How long the invocation ran for, in milliseconds.
+    pub duration: f64,
+    /// How long the invocation was billed for, in milliseconds.
+    pub billed_duration: f64,
+    /// Memory available to the function, in MB.
+    pub memory_size: u64,
+    /// Most memory used by the invocation, in MB.
+    pub max_memory_used: u64,
+    /// How long the function took to start, in milliseconds, for a cold start.
+    pub init_duration: Option<f64>,
+}
+
+impl InvocationReport {
+    /// Parse the `REPORT` line `message` that AWS Lambda logs at the end of an invocation.
+    ///
+    /// Returns `None` for any other log message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::lambda::InvocationReport;
+    ///
+    /// let report = InvocationReport::parse(
+    ///     "REPORT RequestId: 8f5a3c1e\tDuration: 12.34 ms\tBilled Duration: 13 ms\t\
+    ///      Memory Size: 128 MB\tMax Memory Used: 70 MB\tInit Duration: 150.21 ms\t\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(report.request_id, "8f5a3c1e");
+    /// assert_eq!(report.duration, 12.34);
+    /// assert_eq!(report.billed_duration, 13.0);
+    /// assert_eq!(report.memory_size, 128);
+    /// assert_eq!(report.max_memory_used, 70);
+    /// assert_eq!(report.init_duration, Some(150.21));
+    ///
+    /// assert_eq!(InvocationReport::parse("START RequestId: 8f5a3c1e Version: $LATEST"), None);
+    /// ```
+    pub fn parse(message: &str) -> Option<Self> {
+        let fields = message.strip_prefix("REPORT ")?;
+
+        let mut request_id = None;
+        let (mut duration, mut billed_duration) = (None, None);
+        let (mut memory_size, mut max_memory_used) = (None, None);
+        let mut init_duration = None;
+        for field in fields.split('\t') {
+            let Some((name, value)) = field.trim().split_once(": ") else {
+                continue;
+            };
+            // Values are followed by their unit, such as `ms` or `MB`.
+            let number = value.split_whitespace().next().unwrap_or_default();
+            match name {
+                "RequestId" => request_id = Some(value.to_string()),
+                "Duration" => duration = number.parse().ok(),
+                "Billed Duration" => billed_duration = number.parse().ok(),
+                "Memory Size" => memory_size = number.parse().ok(),
+                "Max Memory Used" => max_memory_used = number.parse().ok(),
+                "Init Duration" => init_duration = number.parse().ok(),
+                _ => {}
+            }
         }
+
+        Some(Self {
+            request_id: request_id?,
+            duration: duration?,
+            billed_duration: billed_duration?,
+            memory_size: memory_size?,
+            max_memory_used: max_memory_used?,
+            init_duration,
+        })
     }
 }
 
+/// The log events of a single invocation of an AWS Lambda function.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InvocationLog {
+    pub request_id: String,
+    /// Indexes of the log events of the invocation, in order.
+    pub events: Vec<usize>,
+    /// The statistics of the invocation, once it has ended.
+    pub report: Option<InvocationReport>,
+}
+
+/// Group the log events of AWS Lambda functions by the invocation that logged them, in the
+/// order the invocations started.
+///
+/// Invocations are delimited by the `START` and `REPORT` lines Lambda logs around them, in
+/// each log stream. Log events before a `START` line, such as those of initializing the
+/// function, belong to the invocation after them.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::{cloudwatch_logs::LogEvent, lambda};
+///
+/// let events: Vec<LogEvent> = [
+///     "START RequestId: 1 Version: $LATEST",
+///     "hello",
+///     "END RequestId: 1",
+///     "REPORT RequestId: 1\tDuration: 2.00 ms\tBilled Duration: 2 ms\t\
+///      Memory Size: 128 MB\tMax Memory Used: 64 MB\t",
+///     "START RequestId: 2 Version: $LATEST",
+///     "world",
+/// ]
+/// .into_iter()
+/// .map(|message| LogEvent {
+///     log_stream_name: Some("stream".to_string()),
+///     message: message.to_string(),
+///     ..Default::default()
+/// })
+/// .collect();
+///
+/// let invocations = lambda::group_invocations(&events);
+/// assert_eq!(invocations.len(), 2);
+/// assert_eq!(invocations[0].request_id, "1");
+/// assert_eq!(invocations[0].events, [0, 1, 2, 3]);
+/// assert!(invocations[0].report.is_some());
+/// assert_eq!(invocations[1].events, [4, 5]);
+/// assert_eq!(invocations[1].report, None);
+/// ```
+pub fn group_invocations(events: &[LogEvent]) -> Vec<InvocationLog> {
+    let mut invocations: Vec<InvocationLog> = Vec::new();
+    // The invocation running in each log stream, and the log events before it started.
+    let mut running: HashMap<Option<&str>, usize> = HashMap::new();
+    let mut pending: HashMap<Option<&str>, Vec<usize>> = HashMap::new();
+
+    for (i, event) in events.iter().enumerate() {
+        let log_stream_name = event.log_stream_name.as_deref();
+        if let Some(request_id) = request_id(&event.message, "START") {
+            let mut events = pending.remove(&log_stream_name).unwrap_or_default();
+            events.push(i);
+            running.insert(log_stream_name, invocations.len());
+            invocations.push(InvocationLog {
+                request_id: request_id.to_string(),
+                events,
+                report: None,
+            });
+        } else if let Some(&invocation) = running.get(&log_stream_name) {
+            invocations[invocation].events.push(i);
+            if let Some(report) = InvocationReport::parse(&event.message) {
+                invocations[invocation].report = Some(report);
+                running.remove(&log_stream_name);
+            }
+        } else if let Some(report) = InvocationReport::parse(&event.message) {
+            // The invocation started before the first of the log events.
+            let mut events = pending.remove(&log_stream_name).unwrap_or_default();
+            events.push(i);
+            invocations.push(InvocationLog {
+                request_id: report.request_id.clone(),
+                events,
+                report: Some(report),
+            });
+        } else {
+            pending.entry(log_stream_name).or_default().push(i);
+        }
+    }
+
+    invocations
+}
+
+/// The request ID of a line that Lambda logs for an invocation, such as `START RequestId: ...`,
+/// if `message` is of the kind `kind`.
+fn request_id<'a>(message: &'a str, kind: &str) -> Option<&'a str> {
+    let rest = message.strip_prefix(kind)?.strip_prefix(" RequestId: ")?;
+    rest.split_whitespace().next()
+}
+
+/// The version of the AWS Lambda function that wrote to the log stream `log_stream_name`.
+///
+/// Lambda names log streams with the date, the version in brackets, and an instance ID.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::lambda;
+///
+/// assert_eq!(
+///     lambda::log_stream_version("2024/06/01/[$LATEST]0123456789abcdef"),
+///     Some("$LATEST")
+/// );
+/// assert_eq!(lambda::log_stream_version("2024/06/01/[42]0123456789abcdef"), Some("42"));
+/// assert_eq!(lambda::log_stream_version("my-stream"), None);
+/// ```
+pub fn log_stream_version(log_stream_name: &str) -> Option<&str> {
+    let (_, rest) = log_stream_name.split_once('[')?;
+    let (version, _) = rest.split_once(']')?;
+    Some(version)
+}
+
+/// Whether the `tags` of a function match every whitespace-separated term of `filter`, either
+/// `key=value` to match a tag, or `key` to match any value of it, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::lambda;
+///
+/// let tags = vec![
+///     ("team".to_string(), "payments".to_string()),
+///     ("env".to_string(), "prod".to_string()),
+/// ];
+/// assert!(lambda::tags_match(&tags, "team=payments"));
+/// assert!(lambda::tags_match(&tags, "Team=Payments env"));
+/// assert!(!lambda::tags_match(&tags, "team=checkout"));
+/// assert!(!lambda::tags_match(&tags, "owner"));
+/// ```
+pub fn tags_match(tags: &[(String, String)], filter: &str) -> bool {
+    filter.split_whitespace().all(|term| {
+        let (key, value) = match term.split_once('=') {
+            Some((key, value)) => (key, Some(value)),
+            None => (term, None),
+        };
+        tags.iter().any(|(tag_key, tag_value)| {
+            tag_key.eq_ignore_ascii_case(key)
+                && value.is_none_or(|value| tag_value.eq_ignore_ascii_case(value))
+        })
+    })
+}
+
+/// The CloudFormation stack a function was deployed with, from its `tags`, if it was.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::lambda;
+///
+/// let tags = vec![
+///     ("aws:cloudformation:stack-name".to_string(), "payments-api".to_string()),
+///     ("team".to_string(), "payments".to_string()),
+/// ];
+/// assert_eq!(lambda::stack_name(&tags), Some("payments-api"));
+/// assert_eq!(lambda::stack_name(&tags[1..]), None);
+/// ```
+pub fn stack_name(tags: &[(String, String)]) -> Option<&str> {
+    tags.iter()
+        .find(|(key, _)| key == STACK_NAME_TAG)
+        .map(|(_, value)| value.as_str())
+}
+
 /// Client instance for AWS Lambda
+#[derive(Clone, Debug)]
 pub struct Client {
     client: aws_sdk_lambda::Client,
+    region: Option<String>,
 }
 
 impl Client {
@@ -44,9 +578,13 @@ impl Client {
     /// # }
     /// ```
     pub fn new(config: &SdkConfig) -> Self {
-        let client = aws_sdk_lambda::Client::new(config);
+        let client_config = aws_sdk_lambda::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_lambda::Client::from_conf(client_config);
+        let region = config.region().map(ToString::to_string);
 
-        Self { client }
+        Self { client, region }
     }
 
     /// Get _all_ AWS Lambda function names, in sorted order.
@@ -54,49 +592,547 @@ impl Client {
     /// The paginated results from AWS Lambda are automatically iterated
     /// to collect all function names as a single, complete list.
     ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of functions fails to load.
+    ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// let lambda_function_names = lambda_client.get_all_functions().await?;
+    /// # Ok(())
+    /// # }
     /// ```
+    pub async fn get_all_functions(&self) -> Result<Vec<Function>, AwsError> {
+        Ok(self.get_all_functions_page().await?.functions)
+    }
+
+    /// Get _all_ AWS Lambda functions, in sorted order, with their summaries, as a single page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of functions fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
     /// # use aws_logs_tui::aws::{config, lambda};
     /// # let sdk_config = config::load_config(None, None).await;
     /// let lambda_client = lambda::Client::new(&sdk_config);
     ///
-    /// let lambda_function_names = lambda_client.get_all_function_names().await;
+    /// let page = lambda_client.get_all_functions_page().await?;
+    /// for function in &page.functions {
+    ///     println!("{} runs {:?}", function.name, page.summaries[function].runtime);
+    /// }
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_all_functions(&self) -> Vec<Function> {
-        let mut function_names = Vec::new();
+    pub async fn get_all_functions_page(&self) -> Result<FunctionPage, AwsError> {
+        let mut all = FunctionPage::default();
         let mut next_marker = None;
 
         loop {
-            let mut list_functions_request =
-                self.client.list_functions().max_items(PAGINATION_SIZE);
-            if let Some(marker) = next_marker {
-                list_functions_request = list_functions_request.marker(marker);
+            let page = self.get_functions_page(next_marker).await?;
+            all.functions.extend(page.functions);
+            all.summaries.extend(page.summaries);
+
+            next_marker = page.next_marker;
+
+            if next_marker.is_none() {
+                break;
             }
+        }
+
+        all.functions.sort();
+
+        Ok(all)
+    }
 
-            let list_functions_response = list_functions_request
+    /// Get a single page of AWS Lambda function names, starting from `marker`.
+    ///
+    /// The first page is returned when `marker` is `None`, and the page's
+    /// [`FunctionPage::next_marker`] is used to get the following page. Use this
+    /// instead of [`Client::get_all_functions`] to show functions as they arrive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the page fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// let mut marker = None;
+    /// loop {
+    ///     let page = lambda_client.get_functions_page(marker).await?;
+    ///     println!("Loaded [{}] more functions", page.functions.len());
+    ///
+    ///     marker = page.next_marker;
+    ///     if marker.is_none() {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_functions_page(
+        &self,
+        marker: Option<String>,
+    ) -> Result<FunctionPage, AwsError> {
+        let list_functions_response = self
+            .client
+            .list_functions()
+            .max_items(PAGINATION_SIZE)
+            .set_marker(marker)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("ListFunctions", error))?;
+
+        let mut functions = Vec::new();
+        let mut summaries = HashMap::new();
+        for configuration in list_functions_response.functions() {
+            let Some(name) = configuration.function_name() else {
+                continue;
+            };
+            let function = Function::new(name, self.region.as_deref());
+            summaries.insert(
+                function.clone(),
+                FunctionSummary {
+                    runtime: configuration
+                        .runtime()
+                        .map(|runtime| runtime.as_str().to_string()),
+                    architectures: configuration
+                        .architectures()
+                        .iter()
+                        .map(|architecture| architecture.as_str().to_string())
+                        .collect(),
+                    last_modified: configuration.last_modified().map(String::from),
+                    code_size: configuration.code_size(),
+                },
+            );
+            functions.push(function);
+        }
+
+        Ok(FunctionPage {
+            functions,
+            summaries,
+            next_marker: list_functions_response.next_marker().map(String::from),
+        })
+    }
+
+    /// Get the configuration of the AWS Lambda function `function_name`, with its Function URL
+    /// and where its failed asynchronous invocations are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the function doesn't exist, or its configuration fails to load.
+    /// A Function URL, or on-failure destination, that fails to load is left unknown instead,
+    /// with its error kept in [`FunctionConfiguration::url_error`], or
+    /// [`FunctionConfiguration::on_failure_error`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// let configuration = lambda_client.get_function_configuration("my-function").await?;
+    /// println!("Runs {:?} with {:?} MB", configuration.runtime, configuration.memory_size);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_function_configuration(
+        &self,
+        function_name: &str,
+    ) -> Result<FunctionConfiguration, AwsError> {
+        let response = self
+            .client
+            .get_function_configuration()
+            .function_name(function_name)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("GetFunctionConfiguration", error))?;
+
+        let mut environment: Vec<(String, String)> = response
+            .environment()
+            .and_then(|environment| environment.variables())
+            .map(|variables| {
+                variables
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        environment.sort();
+
+        // The configuration is still of use without its Function URL, such as when not
+        // permitted to get it.
+        let (url, url_error) = match self
+            .client
+            .get_function_url_config()
+            .function_name(function_name)
+            .send()
+            .await
+        {
+            Ok(url_config) => (
+                Some(FunctionUrl {
+                    url: url_config.function_url().to_string(),
+                    auth_type: url_config.auth_type().as_str().to_string(),
+                }),
+                None,
+            ),
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_resource_not_found_exception()) =>
+            {
+                (None, None)
+            }
+            Err(error) => (None, Some(AwsError::new("GetFunctionUrlConfig", error))),
+        };
+
+        let (on_failure_destination, on_failure_error) = match self
+            .client
+            .get_function_event_invoke_config()
+            .function_name(function_name)
+            .send()
+            .await
+        {
+            Ok(invoke_config) => (
+                invoke_config
+                    .destination_config()
+                    .and_then(|destination_config| destination_config.on_failure())
+                    .and_then(|on_failure| on_failure.destination())
+                    .map(String::from),
+                None,
+            ),
+            Err(error)
+                if error
+                    .as_service_error()
+                    .is_some_and(|error| error.is_resource_not_found_exception()) =>
+            {
+                (None, None)
+            }
+            Err(error) => (
+                None,
+                Some(AwsError::new("GetFunctionEventInvokeConfig", error)),
+            ),
+        };
+
+        Ok(FunctionConfiguration {
+            arn: response.function_arn().map(String::from),
+            runtime: response
+                .runtime()
+                .map(|runtime| runtime.as_str().to_string()),
+            handler: response.handler().map(String::from),
+            memory_size: response.memory_size(),
+            timeout: response.timeout(),
+            architectures: response
+                .architectures()
+                .iter()
+                .map(|architecture| architecture.as_str().to_string())
+                .collect(),
+            last_modified: response.last_modified().map(String::from),
+            environment,
+            layers: response
+                .layers()
+                .iter()
+                .filter_map(|layer| layer.arn())
+                .map(Layer::from_arn)
+                .collect(),
+            url,
+            url_error,
+            dead_letter_target: response
+                .dead_letter_config()
+                .and_then(|dead_letter_config| dead_letter_config.target_arn())
+                .map(String::from),
+            on_failure_destination,
+            on_failure_error,
+        })
+    }
+
+    /// Get the tags of the AWS Lambda function `function_name`, sorted by key.
+    ///
+    /// Tags come with the function from `GetFunction`, which, unlike `ListTags`, needs only the
+    /// name of the function rather than its ARN.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the function doesn't exist, or fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// for (key, value) in lambda_client.get_function_tags("my-function").await? {
+    ///     println!("{key}={value}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_function_tags(
+        &self,
+        function_name: &str,
+    ) -> Result<Vec<(String, String)>, AwsError> {
+        let response = self
+            .client
+            .get_function()
+            .function_name(function_name)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("GetFunction", error))?;
+
+        let mut tags: Vec<(String, String)> = response
+            .tags()
+            .map(|tags| {
+                tags.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        tags.sort();
+
+        Ok(tags)
+    }
+
+    /// Invoke the AWS Lambda function `function_name` with the JSON `payload`, waiting for its
+    /// response.
+    ///
+    /// A dry run only checks that the caller is allowed to invoke the function, and that the
+    /// payload is valid, without running the function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the function could not be invoked. A function that fails
+    /// while running is still invoked, with [`Invocation::function_error`] set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// let invocation = lambda_client
+    ///     .invoke("my-function", r#"{"name": "world"}"#, false)
+    ///     .await?;
+    /// println!("{:?} returned {}", invocation.request_id, invocation.payload);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn invoke(
+        &self,
+        function_name: &str,
+        payload: &str,
+        dry_run: bool,
+    ) -> Result<Invocation, AwsError> {
+        let invocation_type = if dry_run {
+            InvocationType::DryRun
+        } else {
+            InvocationType::RequestResponse
+        };
+
+        let response = self
+            .client
+            .invoke()
+            .function_name(function_name)
+            .invocation_type(invocation_type)
+            .payload(Blob::new(payload))
+            .send()
+            .await
+            .map_err(|error| AwsError::new("Invoke", error))?;
+
+        Ok(Invocation {
+            request_id: response.request_id().map(String::from),
+            status_code: response.status_code(),
+            function_error: response.function_error().map(String::from),
+            payload: response
+                .payload()
+                .map(|payload| String::from_utf8_lossy(payload.as_ref()).into_owned())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Get _all_ versions of the AWS Lambda function `function_name`, newest first, with the
+    /// aliases pointing to each version.
+    ///
+    /// `$LATEST` is always first, as the unpublished version being edited.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of versions, or of aliases, fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// for function_version in lambda_client.get_function_versions("my-function").await? {
+    ///     println!("{} {:?}", function_version.version, function_version.aliases);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_function_versions(
+        &self,
+        function_name: &str,
+    ) -> Result<Vec<FunctionVersion>, AwsError> {
+        let mut function_versions = Vec::new();
+        let mut marker = None;
+        loop {
+            let response = self
+                .client
+                .list_versions_by_function()
+                .function_name(function_name)
+                .max_items(PAGINATION_SIZE)
+                .set_marker(marker)
                 .send()
                 .await
-                .expect("Failed to list lambda functions");
-            let functions = list_functions_response.functions();
-            for function in functions {
-                if let Some(name) = &function.function_name {
-                    function_names.push(Function::new(&name.clone()))
-                }
+                .map_err(|error| AwsError::new("ListVersionsByFunction", error))?;
+            function_versions.extend(response.versions().iter().filter_map(|version| {
+                Some(FunctionVersion {
+                    version: version.version()?.to_string(),
+                    description: version
+                        .description()
+                        .filter(|description| !description.is_empty())
+                        .map(String::from),
+                    aliases: Vec::new(),
+                })
+            }));
+
+            marker = response.next_marker().map(String::from);
+            if marker.is_none() {
+                break;
             }
+        }
 
-            next_marker = list_functions_response.next_marker().map(String::from);
+        let mut marker = None;
+        loop {
+            let response = self
+                .client
+                .list_aliases()
+                .function_name(function_name)
+                .max_items(PAGINATION_SIZE)
+                .set_marker(marker)
+                .send()
+                .await
+                .map_err(|error| AwsError::new("ListAliases", error))?;
+            for alias in response.aliases() {
+                let (Some(name), Some(version)) = (alias.name(), alias.function_version()) else {
+                    continue;
+                };
+                if let Some(function_version) = function_versions
+                    .iter_mut()
+                    .find(|function_version| function_version.version == version)
+                {
+                    function_version.aliases.push(name.to_string());
+                }
+            }
 
-            if next_marker.is_none() {
+            marker = response.next_marker().map(String::from);
+            if marker.is_none() {
                 break;
             }
         }
 
-        function_names.sort();
+        // Published versions are numbered, and listed oldest first.
+        function_versions.sort_by_key(|function_version| {
+            std::cmp::Reverse(function_version.version.parse::<u64>().unwrap_or(u64::MAX))
+        });
+        for function_version in &mut function_versions {
+            function_version.aliases.sort();
+        }
+
+        Ok(function_versions)
+    }
+
+    /// Get _all_ event source mappings of the AWS Lambda function `function_name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of event source mappings fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, lambda};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let lambda_client = lambda::Client::new(&sdk_config);
+    ///
+    /// for mapping in lambda_client.get_event_source_mappings("my-function").await? {
+    ///     println!("{:?} {:?}", mapping.source(), mapping.state);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_event_source_mappings(
+        &self,
+        function_name: &str,
+    ) -> Result<Vec<EventSourceMapping>, AwsError> {
+        let mut mappings = Vec::new();
+        let mut marker = None;
+        loop {
+            let response = self
+                .client
+                .list_event_source_mappings()
+                .function_name(function_name)
+                .max_items(PAGINATION_SIZE)
+                .set_marker(marker)
+                .send()
+                .await
+                .map_err(|error| AwsError::new("ListEventSourceMappings", error))?;
+            mappings.extend(response.event_source_mappings().iter().map(|mapping| {
+                EventSourceMapping {
+                    uuid: mapping.uuid().unwrap_or_default().to_string(),
+                    event_source_arn: mapping.event_source_arn().map(String::from),
+                    state: mapping.state().map(String::from),
+                    state_transition_reason: mapping.state_transition_reason().map(String::from),
+                    batch_size: mapping.batch_size(),
+                    last_processing_result: mapping
+                        .last_processing_result()
+                        .filter(|result| !result.is_empty())
+                        .map(String::from),
+                    last_modified: mapping
+                        .last_modified()
+                        .and_then(|time| time.to_millis().ok()),
+                }
+            }));
+
+            marker = response.next_marker().map(String::from);
+            if marker.is_none() {
+                break;
+            }
+        }
 
-        function_names
+        Ok(mappings)
     }
 }
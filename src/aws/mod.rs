@@ -1,2 +1,18 @@
+pub mod api_gateway;
+pub mod cloudtrail;
+pub mod cloudwatch_logs;
+pub mod cloudwatch_metrics;
+pub mod codebuild;
 pub mod config;
+pub mod console;
+pub mod ecs;
+pub mod eks;
+pub mod error;
 pub mod lambda;
+pub mod rds;
+pub mod retry;
+pub mod sqs;
+pub mod step_functions;
+pub mod sts;
+pub mod vpc;
+pub mod xray;
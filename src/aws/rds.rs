@@ -0,0 +1,254 @@
+//! Client for Amazon Relational Database Service.
+//!
+//! Provides optimized methods for finding the logs that DB instances, and Aurora DB clusters,
+//! export to CloudWatch Logs, and for parsing their slow queries.
+use aws_config::SdkConfig;
+use aws_sdk_rds;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+/// A DB instance, or DB cluster, such as an Aurora cluster.
+#[derive(Clone, Debug, Default, Ord, Eq, Hash, PartialOrd, PartialEq)]
+pub struct Database {
+    pub identifier: String,
+    /// Whether the database is a DB cluster, which exports the logs of its instances.
+    pub cluster: bool,
+    /// The database engine, such as `aurora-postgresql` or `mysql`.
+    pub engine: String,
+    /// The logs exported to CloudWatch Logs, such as `error`, `slowquery`, or `postgresql`.
+    pub log_exports: Vec<String>,
+}
+
+impl Database {
+    /// Name of the log group that the database exports the log `log` to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::rds::Database;
+    ///
+    /// let database = Database {
+    ///     identifier: "orders".to_string(),
+    ///     cluster: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(database.log_group_name("slowquery"), "/aws/rds/cluster/orders/slowquery");
+    /// ```
+    pub fn log_group_name(&self, log: &str) -> String {
+        let kind = if self.cluster { "cluster" } else { "instance" };
+        format!("/aws/rds/{kind}/{}/{log}", self.identifier)
+    }
+}
+
+/// A query logged for taking long, by MySQL in its slow query log or by PostgreSQL with
+/// `log_min_duration_statement`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SlowQuery {
+    /// How long the query took, in milliseconds.
+    pub duration: f64,
+    pub rows_sent: Option<u64>,
+    pub rows_examined: Option<u64>,
+    /// The user that ran the query, if logged.
+    pub user: Option<String>,
+    /// The statement run, on a single line.
+    pub statement: String,
+}
+
+impl SlowQuery {
+    /// Parse the slow query `message`, or `None` if it isn't a MySQL slow query log entry, or a
+    /// PostgreSQL log line with the duration of a statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::rds::SlowQuery;
+    ///
+    /// let slow_query = SlowQuery::parse(
+    ///     "# Time: 2024-06-01T12:00:00.000000Z\n\
+    ///      ## User@Host: admin[admin] @  [10.0.0.1]  Id:    12\n\
+    ///      ## Query_time: 2.500000  Lock_time: 0.000100 Rows_sent: 1  Rows_examined: 48213\n\
+    ///      SET timestamp=1717243200;\n\
+    ///      SELECT *\n  FROM orders WHERE note LIKE '%late%';",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(slow_query.duration, 2500.0);
+    /// assert_eq!(slow_query.rows_examined, Some(48213));
+    /// assert_eq!(slow_query.user.as_deref(), Some("admin"));
+    /// assert_eq!(slow_query.statement, "SELECT * FROM orders WHERE note LIKE '%late%';");
+    ///
+    /// let slow_query = SlowQuery::parse(
+    ///     "2024-06-01 12:00:00 UTC:10.0.0.1(51234):admin@orders:[4242]:LOG:  \
+    ///      duration: 1834.250 ms  statement: SELECT count(*) FROM orders;",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(slow_query.duration, 1834.25);
+    /// assert_eq!(slow_query.statement, "SELECT count(*) FROM orders;");
+    ///
+    /// assert_eq!(SlowQuery::parse("2024-06-01 12:00:00 UTC::@:[1]:LOG:  checkpoint starting"), None);
+    /// ```
+    pub fn parse(message: &str) -> Option<Self> {
+        Self::parse_mysql(message).or_else(|| Self::parse_postgresql(message))
+    }
+
+    fn parse_mysql(message: &str) -> Option<Self> {
+        let mut duration = None;
+        let mut rows_sent = None;
+        let mut rows_examined = None;
+        let mut user = None;
+        let mut statement = Vec::new();
+        for line in message.lines().map(str::trim) {
+            if let Some(statistics) = line.strip_prefix("# ")
+                && statistics.starts_with("Query_time:")
+            {
+                // Names are followed by their values, such as `Rows_sent: 1`.
+                let fields: Vec<&str> = statistics.split_whitespace().collect();
+                for field in fields.chunks_exact(2) {
+                    match field[0] {
+                        "Query_time:" => {
+                            duration = field[1].parse::<f64>().ok().map(|seconds| seconds * 1000.0);
+                        }
+                        "Rows_sent:" => rows_sent = field[1].parse().ok(),
+                        "Rows_examined:" => rows_examined = field[1].parse().ok(),
+                        _ => {}
+                    }
+                }
+            } else if let Some(user_host) = line.strip_prefix("# User@Host: ") {
+                user = user_host
+                    .split('[')
+                    .next()
+                    .map(str::trim)
+                    .filter(|user| !user.is_empty())
+                    .map(String::from);
+            } else if !(line.is_empty()
+                || line.starts_with('#')
+                || line.starts_with("SET timestamp=")
+                || line.starts_with("use "))
+            {
+                statement.push(line);
+            }
+        }
+
+        Some(Self {
+            duration: duration?,
+            rows_sent,
+            rows_examined,
+            user,
+            statement: statement.join(" "),
+        })
+    }
+
+    fn parse_postgresql(message: &str) -> Option<Self> {
+        let (_, rest) = message.split_once("duration: ")?;
+        let (duration, rest) = rest.split_once(" ms")?;
+        // The statement follows `statement: `, or `execute <name>: ` if it was prepared.
+        let statement = rest
+            .split_once(": ")
+            .map(|(_, statement)| statement)
+            .unwrap_or_default();
+
+        Some(Self {
+            duration: duration.trim().parse().ok()?,
+            rows_sent: None,
+            rows_examined: None,
+            user: None,
+            statement: statement.split_whitespace().collect::<Vec<_>>().join(" "),
+        })
+    }
+}
+
+/// Client instance for Amazon Relational Database Service
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_rds::Client,
+}
+
+impl Client {
+    /// Create a new Amazon Relational Database Service client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{config, rds};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let rds_client = rds::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_rds::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_rds::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get _all_ DB clusters, and the DB instances that aren't in one, sorted by identifier.
+    ///
+    /// The instances of a DB cluster are left out, as the cluster exports their logs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of DB clusters, or of DB instances, fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, rds};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let rds_client = rds::Client::new(&sdk_config);
+    ///
+    /// for database in rds_client.get_all_databases().await? {
+    ///     println!("{} exports {:?}", database.identifier, database.log_exports);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_databases(&self) -> Result<Vec<Database>, AwsError> {
+        let mut databases = Vec::new();
+
+        let mut pages = self.client.describe_db_clusters().into_paginator().send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("DescribeDBClusters", error))?;
+            databases.extend(page.db_clusters().iter().filter_map(|cluster| {
+                Some(Database {
+                    identifier: cluster.db_cluster_identifier()?.to_string(),
+                    cluster: true,
+                    engine: cluster.engine().unwrap_or_default().to_string(),
+                    log_exports: cluster.enabled_cloudwatch_logs_exports().to_vec(),
+                })
+            }));
+        }
+
+        let mut pages = self.client.describe_db_instances().into_paginator().send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("DescribeDBInstances", error))?;
+            databases.extend(
+                page.db_instances()
+                    .iter()
+                    .filter(|instance| instance.db_cluster_identifier().is_none())
+                    .filter_map(|instance| {
+                        Some(Database {
+                            identifier: instance.db_instance_identifier()?.to_string(),
+                            cluster: false,
+                            engine: instance.engine().unwrap_or_default().to_string(),
+                            log_exports: instance.enabled_cloudwatch_logs_exports().to_vec(),
+                        })
+                    }),
+            );
+        }
+
+        databases.sort();
+
+        Ok(databases)
+    }
+}
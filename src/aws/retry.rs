@@ -0,0 +1,137 @@
+//! Retries of throttled calls to AWS.
+//!
+//! Throttled calls are retried by the AWS SDK with exponential backoff and jitter, as
+//! configured by [`retry_config()`]. Clients notice throttled attempts with a
+//! [`ThrottlingInterceptor`], so the app can show that it is rate limited while it retries.
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aws_config::retry::RetryConfig;
+use aws_smithy_runtime_api::box_error::BoxError;
+use aws_smithy_runtime_api::client::interceptors::Intercept;
+use aws_smithy_runtime_api::client::interceptors::context::FinalizerInterceptorContextRef;
+use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+use aws_smithy_runtime_api::client::runtime_components::RuntimeComponents;
+use aws_smithy_types::config_bag::ConfigBag;
+
+// Attempts at a call before failing, and the backoff between them, which doubles after each
+// attempt up to the maximum.
+const MAX_ATTEMPTS: u32 = 8;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(20);
+
+// How long after an attempt was throttled the app is still rate limited.
+const RATE_LIMITED_MILLIS: i64 = 3_000;
+
+// Parts of the error codes AWS throttles calls with, such as `ThrottlingException`.
+const THROTTLING_CODES: &[&str] = &[
+    "Throttling",
+    "Throttled",
+    "TooManyRequests",
+    "RequestLimitExceeded",
+    "SlowDown",
+];
+
+// When an attempt at a call was last throttled, in milliseconds since the Unix epoch.
+static LAST_THROTTLED: AtomicI64 = AtomicI64::new(0);
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX)
+        })
+}
+
+/// How calls to AWS are retried, patiently enough to ride out throttling.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::retry;
+///
+/// assert_eq!(retry::retry_config().max_attempts(), 8);
+/// ```
+pub fn retry_config() -> RetryConfig {
+    RetryConfig::standard()
+        .with_max_attempts(MAX_ATTEMPTS)
+        .with_initial_backoff(INITIAL_BACKOFF)
+        .with_max_backoff(MAX_BACKOFF)
+}
+
+/// Whether `response` throttled the call, by its status or the error code it has.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::retry;
+/// use aws_smithy_runtime_api::client::orchestrator::HttpResponse;
+/// use aws_smithy_types::body::SdkBody;
+///
+/// let response = HttpResponse::new(429.try_into().unwrap(), SdkBody::empty());
+/// assert!(retry::is_throttled(&response));
+///
+/// let response = HttpResponse::new(
+///     400.try_into().unwrap(),
+///     SdkBody::from(r#"{"__type":"ThrottlingException","message":"Rate exceeded"}"#),
+/// );
+/// assert!(retry::is_throttled(&response));
+///
+/// let response = HttpResponse::new(
+///     400.try_into().unwrap(),
+///     SdkBody::from(r#"{"__type":"ResourceNotFoundException"}"#),
+/// );
+/// assert!(!retry::is_throttled(&response));
+/// ```
+pub fn is_throttled(response: &HttpResponse) -> bool {
+    let status = response.status().as_u16();
+    if status == 429 {
+        return true;
+    }
+    if !matches!(status, 400 | 503) {
+        return false;
+    }
+
+    // The error code is in a header for some protocols, and only in the body for others.
+    let error_type = response
+        .headers()
+        .get("x-amzn-ErrorType")
+        .unwrap_or_default();
+    let body = response
+        .body()
+        .bytes()
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default();
+    THROTTLING_CODES
+        .iter()
+        .any(|code| error_type.contains(code) || body.contains(code))
+}
+
+/// Whether an attempt at a call to AWS was throttled in the last few seconds, so calls are
+/// likely being retried.
+pub fn is_rate_limited() -> bool {
+    now_millis() - LAST_THROTTLED.load(Ordering::Relaxed) < RATE_LIMITED_MILLIS
+}
+
+/// Notes when attempts at calls are throttled, for [`is_rate_limited()`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThrottlingInterceptor;
+
+impl Intercept for ThrottlingInterceptor {
+    fn name(&self) -> &'static str {
+        "ThrottlingInterceptor"
+    }
+
+    fn read_after_attempt(
+        &self,
+        context: &FinalizerInterceptorContextRef<'_>,
+        _runtime_components: &RuntimeComponents,
+        _cfg: &mut ConfigBag,
+    ) -> Result<(), BoxError> {
+        if context.response().is_some_and(is_throttled) {
+            LAST_THROTTLED.store(now_millis(), Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+//! Client for Amazon SQS.
+//!
+//! Provides methods for peeking at the failed events that AWS Lambda sends to dead-letter
+//! queues and on-failure destinations.
+use std::collections::HashSet;
+
+use aws_config::SdkConfig;
+use aws_sdk_sqs;
+use aws_sdk_sqs::types::MessageSystemAttributeName;
+use serde_json::Value;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Maximum messages for `ReceiveMessage` is 10, regardless of a larger configured size.
+const RECEIVE_SIZE: i32 = 10;
+
+/// The account ID and name of the SQS queue `arn`, or `None` if it isn't the ARN of a queue.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::sqs;
+///
+/// assert_eq!(
+///     sqs::parse_queue_arn("arn:aws:sqs:us-east-1:123456789012:my-dlq"),
+///     Some(("123456789012", "my-dlq"))
+/// );
+/// assert_eq!(sqs::parse_queue_arn("arn:aws:sns:us-east-1:123456789012:my-topic"), None);
+/// ```
+pub fn parse_queue_arn(arn: &str) -> Option<(&str, &str)> {
+    let mut parts = arn.splitn(6, ':');
+    if parts.nth(2)? != "sqs" {
+        return None;
+    }
+    let account_id = parts.nth(1)?;
+    let queue_name = parts.next()?;
+    Some((account_id, queue_name))
+}
+
+/// A message of an SQS queue.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Message {
+    pub message_id: String,
+    pub body: String,
+    /// When the message was sent, in milliseconds since the epoch.
+    pub sent_timestamp: Option<i64>,
+    /// How many times the message was received, including when peeked at.
+    pub receive_count: Option<u32>,
+    /// Names and string values of the message attributes, sorted by name.
+    pub attributes: Vec<(String, String)>,
+}
+
+impl Message {
+    /// The ID of the request of the failed invocation the message is of.
+    ///
+    /// Dead-letter queues get it as the `RequestID` message attribute, while on-failure
+    /// destinations get it in the `requestContext` of the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::sqs::Message;
+    ///
+    /// let message = Message {
+    ///     attributes: vec![("RequestID".to_string(), "8f5a3c1e".to_string())],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(message.request_id().as_deref(), Some("8f5a3c1e"));
+    ///
+    /// let message = Message {
+    ///     body: r#"{"requestContext": {"requestId": "8f5a3c1e", "condition": "RetriesExhausted"}}"#
+    ///         .to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(message.request_id().as_deref(), Some("8f5a3c1e"));
+    ///
+    /// assert_eq!(Message::default().request_id(), None);
+    /// ```
+    pub fn request_id(&self) -> Option<String> {
+        if let Some((_, request_id)) = self.attributes.iter().find(|(name, _)| name == "RequestID")
+        {
+            return Some(request_id.clone());
+        }
+        let body: Value = serde_json::from_str(&self.body).ok()?;
+        body["requestContext"]["requestId"]
+            .as_str()
+            .map(String::from)
+    }
+
+    /// The error the invocation the message is of failed with, if told.
+    ///
+    /// Dead-letter queues get it as the `ErrorMessage` message attribute, while on-failure
+    /// destinations get the response of the function in the body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::sqs::Message;
+    ///
+    /// let message = Message {
+    ///     body: r#"{"responsePayload": {"errorMessage": "Task timed out after 3.00 seconds"}}"#
+    ///         .to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     message.error_message().as_deref(),
+    ///     Some("Task timed out after 3.00 seconds")
+    /// );
+    /// ```
+    pub fn error_message(&self) -> Option<String> {
+        if let Some((_, error_message)) = self
+            .attributes
+            .iter()
+            .find(|(name, _)| name == "ErrorMessage")
+        {
+            return Some(error_message.clone());
+        }
+        let body: Value = serde_json::from_str(&self.body).ok()?;
+        body["responsePayload"]["errorMessage"]
+            .as_str()
+            .map(String::from)
+    }
+}
+
+/// Client instance for Amazon SQS
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_sqs::Client,
+}
+
+impl Client {
+    /// Create a new Amazon SQS client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{config, sqs};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let sqs_client = sqs::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_sqs::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_sqs::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Peek at up to `limit` messages of the SQS queue `queue_arn`, oldest first.
+    ///
+    /// Messages are received without hiding them from other consumers, and are left in the
+    /// queue. Receiving them still counts towards their `maxReceiveCount`, so peeking often at a
+    /// queue that has a dead-letter queue of its own may move messages there.
+    ///
+    /// SQS returns a sample of the messages, so a queue of many may not have all of them peeked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if `queue_arn` isn't the ARN of a queue, the queue doesn't exist,
+    /// or its messages fail to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, sqs};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let sqs_client = sqs::Client::new(&sdk_config);
+    ///
+    /// let queue_arn = "arn:aws:sqs:us-east-1:123456789012:my-dlq";
+    /// for message in sqs_client.peek_messages(queue_arn, 50).await? {
+    ///     println!("{:?} {}", message.request_id(), message.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn peek_messages(
+        &self,
+        queue_arn: &str,
+        limit: usize,
+    ) -> Result<Vec<Message>, AwsError> {
+        let (account_id, queue_name) = parse_queue_arn(queue_arn).ok_or_else(|| {
+            AwsError::from_message(
+                "GetQueueUrl",
+                format!("{queue_arn} is not the ARN of an SQS queue"),
+            )
+        })?;
+        let queue_url = self
+            .client
+            .get_queue_url()
+            .queue_name(queue_name)
+            .queue_owner_aws_account_id(account_id)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("GetQueueUrl", error))?
+            .queue_url()
+            .ok_or_else(|| {
+                AwsError::from_message(
+                    "GetQueueUrl",
+                    format!("No URL of {queue_name} was returned"),
+                )
+            })?
+            .to_string();
+
+        let mut messages = Vec::new();
+        let mut message_ids = HashSet::new();
+        while messages.len() < limit {
+            let response = self
+                .client
+                .receive_message()
+                .queue_url(&queue_url)
+                .max_number_of_messages(RECEIVE_SIZE.min((limit - messages.len()) as i32))
+                .visibility_timeout(0)
+                .message_system_attribute_names(MessageSystemAttributeName::SentTimestamp)
+                .message_system_attribute_names(MessageSystemAttributeName::ApproximateReceiveCount)
+                .message_attribute_names("All")
+                .send()
+                .await
+                .map_err(|error| AwsError::new("ReceiveMessage", error))?;
+
+            // Messages stay visible, so the same ones come back once the queue is exhausted.
+            let received: Vec<Message> = response
+                .messages()
+                .iter()
+                .map(to_message)
+                .filter(|message| message_ids.insert(message.message_id.clone()))
+                .collect();
+            if received.is_empty() {
+                break;
+            }
+            messages.extend(received);
+        }
+
+        messages.sort_by_key(|message| message.sent_timestamp);
+        Ok(messages)
+    }
+}
+
+fn to_message(message: &aws_sdk_sqs::types::Message) -> Message {
+    let attribute = |name: MessageSystemAttributeName| {
+        message
+            .attributes()
+            .and_then(|attributes| attributes.get(&name))
+            .map(String::as_str)
+    };
+    let mut attributes: Vec<(String, String)> = message
+        .message_attributes()
+        .map(|attributes| {
+            attributes
+                .iter()
+                .filter_map(|(name, value)| Some((name.clone(), value.string_value()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    attributes.sort();
+
+    Message {
+        message_id: message.message_id().unwrap_or_default().to_string(),
+        body: message.body().unwrap_or_default().to_string(),
+        sent_timestamp: attribute(MessageSystemAttributeName::SentTimestamp)
+            .and_then(|sent_timestamp| sent_timestamp.parse().ok()),
+        receive_count: attribute(MessageSystemAttributeName::ApproximateReceiveCount)
+            .and_then(|receive_count| receive_count.parse().ok()),
+        attributes,
+    }
+}
@@ -0,0 +1,389 @@
+//! Client for AWS Step Functions.
+//!
+//! Provides optimized methods for accessing the executions of state machines, and the AWS
+//! Lambda functions they invoke.
+use aws_config::SdkConfig;
+use aws_sdk_sfn;
+use aws_sdk_sfn::primitives::DateTime;
+use aws_sdk_sfn::types::HistoryEvent as SdkHistoryEvent;
+use serde_json::Value;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+/// The name of the AWS Lambda function `function`, which may be a name, a partial or full
+/// ARN, and may be qualified by a version or alias.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::step_functions;
+///
+/// assert_eq!(step_functions::function_name("my-function"), "my-function");
+/// assert_eq!(step_functions::function_name("my-function:prod"), "my-function");
+/// assert_eq!(
+///     step_functions::function_name("arn:aws:lambda:us-east-1:123456789012:function:my-function:1"),
+///     "my-function"
+/// );
+/// ```
+pub fn function_name(function: &str) -> &str {
+    let name = match function.split_once(":function:") {
+        Some((_, name)) => name,
+        None => function,
+    };
+    name.split(':').next().unwrap_or(name)
+}
+
+/// The name of the AWS Lambda function invoked by the task of a state, given the `resource`
+/// of the task and its `parameters` as JSON, if it invokes one.
+///
+/// Tasks either invoke the function of their resource directly, or the function named by the
+/// `FunctionName` parameter of the `lambda:invoke` integration.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::step_functions;
+///
+/// assert_eq!(
+///     step_functions::invoked_function_name(
+///         "arn:aws:states:::lambda:invoke",
+///         Some(r#"{"FunctionName": "my-function:$LATEST", "Payload": {}}"#),
+///     ),
+///     Some("my-function".to_string())
+/// );
+/// assert_eq!(
+///     step_functions::invoked_function_name(
+///         "arn:aws:lambda:us-east-1:123456789012:function:my-function",
+///         None,
+///     ),
+///     Some("my-function".to_string())
+/// );
+/// assert_eq!(step_functions::invoked_function_name("arn:aws:states:::sqs:sendMessage", None), None);
+/// ```
+pub fn invoked_function_name(resource: &str, parameters: Option<&str>) -> Option<String> {
+    if resource.contains(":lambda:invoke") {
+        let parameters: Value = serde_json::from_str(parameters?).ok()?;
+        let function = parameters["FunctionName"].as_str()?;
+        return Some(function_name(function).to_string());
+    }
+    resource
+        .contains(":function:")
+        .then(|| function_name(resource).to_string())
+}
+
+#[derive(Clone, Debug, Ord, Eq, Hash, PartialOrd, PartialEq)]
+pub struct StateMachine {
+    pub name: String,
+    pub arn: String,
+}
+
+/// An execution of a state machine.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Execution {
+    pub name: String,
+    pub arn: String,
+    /// Such as `RUNNING`, `SUCCEEDED`, or `FAILED`.
+    pub status: String,
+    /// When the execution started, and stopped if it has, in milliseconds since the Unix epoch.
+    pub start_date: i64,
+    pub stop_date: Option<i64>,
+}
+
+impl Execution {
+    /// Whether the execution failed, timed out, or was aborted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::step_functions::Execution;
+    ///
+    /// let execution = Execution {
+    ///     status: "TIMED_OUT".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(execution.failed());
+    /// ```
+    pub fn failed(&self) -> bool {
+        matches!(self.status.as_str(), "FAILED" | "TIMED_OUT" | "ABORTED")
+    }
+}
+
+/// An event in the history of an execution, such as a state being entered.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HistoryEvent {
+    pub id: i64,
+    /// When the event happened, in milliseconds since the Unix epoch.
+    pub timestamp: i64,
+    /// Such as `TaskStateEntered` or `LambdaFunctionFailed`.
+    pub event_type: String,
+    /// The state entered, or exited, by the event.
+    pub state_name: Option<String>,
+    /// The AWS Lambda function scheduled to be invoked by the event.
+    pub function_name: Option<String>,
+    /// The error, and its cause, of a failure.
+    pub error: Option<String>,
+}
+
+impl HistoryEvent {
+    /// Whether the event is a failure, such as a task timing out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::step_functions::HistoryEvent;
+    ///
+    /// let event = HistoryEvent {
+    ///     event_type: "LambdaFunctionTimedOut".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(event.failed());
+    /// ```
+    pub fn failed(&self) -> bool {
+        ["Failed", "TimedOut", "Aborted"]
+            .iter()
+            .any(|suffix| self.event_type.ends_with(suffix))
+    }
+}
+
+/// The names of the AWS Lambda functions invoked in the history `events`, in the order they
+/// were first invoked.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::step_functions::{self, HistoryEvent};
+///
+/// let invoke = |function_name: &str| HistoryEvent {
+///     function_name: Some(function_name.to_string()),
+///     ..Default::default()
+/// };
+/// let events = [invoke("validate"), invoke("charge"), invoke("validate")];
+///
+/// assert_eq!(step_functions::invoked_functions(&events), ["validate", "charge"]);
+/// ```
+pub fn invoked_functions(events: &[HistoryEvent]) -> Vec<&str> {
+    let mut function_names = Vec::new();
+    for function_name in events
+        .iter()
+        .filter_map(|event| event.function_name.as_deref())
+    {
+        if !function_names.contains(&function_name) {
+            function_names.push(function_name);
+        }
+    }
+    function_names
+}
+
+fn to_millis(date: &DateTime) -> i64 {
+    date.to_millis().unwrap_or_default()
+}
+
+impl From<&SdkHistoryEvent> for HistoryEvent {
+    fn from(event: &SdkHistoryEvent) -> Self {
+        let state_name = event
+            .state_entered_event_details()
+            .map(|details| details.name())
+            .or(event
+                .state_exited_event_details()
+                .map(|details| details.name()));
+        let function_name = event
+            .task_scheduled_event_details()
+            .and_then(|details| {
+                invoked_function_name(details.resource(), Some(details.parameters()))
+            })
+            .or(event
+                .lambda_function_scheduled_event_details()
+                .and_then(|details| invoked_function_name(details.resource(), None)));
+        let failure = event
+            .execution_failed_event_details()
+            .map(|details| (details.error(), details.cause()))
+            .or(event
+                .task_failed_event_details()
+                .map(|details| (details.error(), details.cause())))
+            .or(event
+                .lambda_function_failed_event_details()
+                .map(|details| (details.error(), details.cause())));
+        let error = failure.map(|(error, cause)| match (error, cause) {
+            (Some(error), Some(cause)) => format!("{error}: {cause}"),
+            (error, cause) => error.or(cause).unwrap_or_default().to_string(),
+        });
+
+        Self {
+            id: event.id(),
+            timestamp: to_millis(event.timestamp()),
+            event_type: event.r#type().as_str().to_string(),
+            state_name: state_name.map(String::from),
+            function_name,
+            error,
+        }
+    }
+}
+
+/// Client instance for AWS Step Functions
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_sfn::Client,
+}
+
+impl Client {
+    /// Create a new AWS Step Functions client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{config, step_functions};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let sfn_client = step_functions::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_sfn::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_sfn::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get _all_ state machines, in sorted order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of state machines fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, step_functions};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let sfn_client = step_functions::Client::new(&sdk_config);
+    ///
+    /// for state_machine in sfn_client.get_all_state_machines().await? {
+    ///     println!("{}", state_machine.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_state_machines(&self) -> Result<Vec<StateMachine>, AwsError> {
+        let mut pages = self.client.list_state_machines().into_paginator().send();
+
+        let mut state_machines = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("ListStateMachines", error))?;
+            state_machines.extend(
+                page.state_machines()
+                    .iter()
+                    .map(|state_machine| StateMachine {
+                        name: state_machine.name().to_string(),
+                        arn: state_machine.state_machine_arn().to_string(),
+                    }),
+            );
+        }
+
+        state_machines.sort();
+
+        Ok(state_machines)
+    }
+
+    /// Get up to `limit` of the most recent executions of the state machine
+    /// `state_machine_arn`, most recent first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the executions fail to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, step_functions};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let sfn_client = step_functions::Client::new(&sdk_config);
+    ///
+    /// let state_machine_arn = "arn:aws:states:us-east-1:123456789012:stateMachine:my-machine";
+    /// for execution in sfn_client.get_recent_executions(state_machine_arn, 50).await? {
+    ///     println!("{} {}", execution.name, execution.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_recent_executions(
+        &self,
+        state_machine_arn: &str,
+        limit: i32,
+    ) -> Result<Vec<Execution>, AwsError> {
+        let response = self
+            .client
+            .list_executions()
+            .state_machine_arn(state_machine_arn)
+            .max_results(limit)
+            .send()
+            .await
+            .map_err(|error| AwsError::new("ListExecutions", error))?;
+
+        Ok(response
+            .executions()
+            .iter()
+            .map(|execution| Execution {
+                name: execution.name().to_string(),
+                arn: execution.execution_arn().to_string(),
+                status: execution.status().as_str().to_string(),
+                start_date: to_millis(execution.start_date()),
+                stop_date: execution.stop_date().map(to_millis),
+            })
+            .collect())
+    }
+
+    /// Get the whole event history of the execution `execution_arn`, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if any page of the history fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, step_functions};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let sfn_client = step_functions::Client::new(&sdk_config);
+    ///
+    /// let execution_arn = "arn:aws:states:us-east-1:123456789012:execution:my-machine:my-run";
+    /// for event in sfn_client.get_execution_history(execution_arn).await? {
+    ///     println!("{} {}", event.event_type, event.state_name.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_execution_history(
+        &self,
+        execution_arn: &str,
+    ) -> Result<Vec<HistoryEvent>, AwsError> {
+        let mut pages = self
+            .client
+            .get_execution_history()
+            .execution_arn(execution_arn)
+            .into_paginator()
+            .send();
+
+        let mut events = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("GetExecutionHistory", error))?;
+            events.extend(page.events().iter().map(HistoryEvent::from));
+        }
+
+        Ok(events)
+    }
+}
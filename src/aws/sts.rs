@@ -0,0 +1,87 @@
+//! Client for AWS Security Token Service (STS).
+//!
+//! Provides methods for telling which account, and which user or role, credentials are of.
+use aws_config::SdkConfig;
+use aws_sdk_sts;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+/// Who the credentials used to call AWS are of.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallerIdentity {
+    /// The ID of the AWS account, such as `123456789012`.
+    pub account: String,
+    /// The ARN of the IAM user, or of the assumed role session.
+    pub arn: String,
+}
+
+/// Client instance for AWS STS
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_sts::Client,
+}
+
+impl Client {
+    /// Create a new AWS STS client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{config, sts};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let sts_client = sts::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_sts::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_sts::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get the account, and the user or role, that the credentials are of.
+    ///
+    /// Any credentials may call this, so failing means they are missing, invalid, or expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the identity fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, sts};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let sts_client = sts::Client::new(&sdk_config);
+    ///
+    /// let identity = sts_client.get_caller_identity().await?;
+    /// println!("{} in account {}", identity.arn, identity.account);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_caller_identity(&self) -> Result<CallerIdentity, AwsError> {
+        let output = self
+            .client
+            .get_caller_identity()
+            .send()
+            .await
+            .map_err(|error| AwsError::new("GetCallerIdentity", error))?;
+
+        Ok(CallerIdentity {
+            account: output.account.unwrap_or_default(),
+            arn: output.arn.unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,230 @@
+//! Logs of Amazon Virtual Private Cloud.
+//!
+//! VPC Flow Logs record the traffic of network interfaces as space-separated fields, in the
+//! default format of version 2 unless customized.
+use std::cmp::Ordering;
+use std::net::IpAddr;
+
+// Fields of a record in the default format, the first of which is its version.
+const DEFAULT_FORMAT_FIELDS: usize = 14;
+const DEFAULT_FORMAT_VERSION: &str = "2";
+
+/// A field of a [`FlowLog`], to sort or filter records by.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlowField {
+    SourceAddress,
+    SourcePort,
+    DestinationAddress,
+    DestinationPort,
+    Protocol,
+    Action,
+    Packets,
+    Bytes,
+}
+
+impl FlowField {
+    pub const ALL: [FlowField; 8] = [
+        FlowField::SourceAddress,
+        FlowField::SourcePort,
+        FlowField::DestinationAddress,
+        FlowField::DestinationPort,
+        FlowField::Protocol,
+        FlowField::Action,
+        FlowField::Packets,
+        FlowField::Bytes,
+    ];
+
+    /// The name of the field in the format of flow logs, such as `dstport`.
+    pub fn name(self) -> &'static str {
+        match self {
+            FlowField::SourceAddress => "srcaddr",
+            FlowField::SourcePort => "srcport",
+            FlowField::DestinationAddress => "dstaddr",
+            FlowField::DestinationPort => "dstport",
+            FlowField::Protocol => "protocol",
+            FlowField::Action => "action",
+            FlowField::Packets => "packets",
+            FlowField::Bytes => "bytes",
+        }
+    }
+
+    /// The field named `name` in the format of flow logs, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// A record of the traffic of a network interface, in the default format of VPC Flow Logs.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FlowLog {
+    pub interface_id: String,
+    pub source_address: String,
+    pub source_port: u16,
+    pub destination_address: String,
+    pub destination_port: u16,
+    /// The IANA number of the protocol, such as 6 for TCP.
+    pub protocol: u8,
+    pub packets: u64,
+    pub bytes: u64,
+    /// Whether the traffic was `ACCEPT`ed or `REJECT`ed.
+    pub action: String,
+}
+
+impl FlowLog {
+    /// Parse the flow log record `message`, or `None` if it isn't in the default format or has
+    /// no data, such as when the interface had no traffic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::vpc::FlowLog;
+    ///
+    /// let flow_log = FlowLog::parse(
+    ///     "2 123456789012 eni-0a1b2c3d 203.0.113.12 10.0.1.5 49152 22 6 20 4249 1717243200 1717243260 REJECT OK",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(flow_log.source_address, "203.0.113.12");
+    /// assert_eq!(flow_log.destination_port, 22);
+    /// assert_eq!(flow_log.bytes, 4249);
+    /// assert_eq!(flow_log.action, "REJECT");
+    /// assert_eq!(
+    ///     FlowLog::parse("2 123456789012 eni-0a1b2c3d - - - - - - - 1717243200 1717243260 - NODATA"),
+    ///     None
+    /// );
+    /// ```
+    pub fn parse(message: &str) -> Option<Self> {
+        let fields: Vec<&str> = message.split_whitespace().collect();
+        if fields.len() != DEFAULT_FORMAT_FIELDS || fields[0] != DEFAULT_FORMAT_VERSION {
+            return None;
+        }
+
+        Some(Self {
+            interface_id: fields[2].to_string(),
+            source_address: fields[3].parse::<IpAddr>().ok()?.to_string(),
+            destination_address: fields[4].parse::<IpAddr>().ok()?.to_string(),
+            source_port: fields[5].parse().ok()?,
+            destination_port: fields[6].parse().ok()?,
+            protocol: fields[7].parse().ok()?,
+            packets: fields[8].parse().ok()?,
+            bytes: fields[9].parse().ok()?,
+            action: fields[12].to_string(),
+        })
+    }
+
+    /// The value of `field`, with well-known protocols named, such as `TCP`.
+    pub fn value(&self, field: FlowField) -> String {
+        match field {
+            FlowField::SourceAddress => self.source_address.clone(),
+            FlowField::SourcePort => self.source_port.to_string(),
+            FlowField::DestinationAddress => self.destination_address.clone(),
+            FlowField::DestinationPort => self.destination_port.to_string(),
+            FlowField::Protocol => match self.protocol {
+                1 => "ICMP".to_string(),
+                6 => "TCP".to_string(),
+                17 => "UDP".to_string(),
+                58 => "ICMPv6".to_string(),
+                protocol => protocol.to_string(),
+            },
+            FlowField::Action => self.action.clone(),
+            FlowField::Packets => self.packets.to_string(),
+            FlowField::Bytes => self.bytes.to_string(),
+        }
+    }
+
+    /// Compare the record to `other` by `field`, numerically for addresses and numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use aws_logs_tui::aws::vpc::{FlowField, FlowLog};
+    ///
+    /// let a = FlowLog { source_address: "10.0.0.9".to_string(), ..Default::default() };
+    /// let b = FlowLog { source_address: "10.0.0.10".to_string(), ..Default::default() };
+    /// assert_eq!(a.compare(&b, FlowField::SourceAddress), Ordering::Less);
+    /// ```
+    pub fn compare(&self, other: &Self, field: FlowField) -> Ordering {
+        let address = |address: &str| address.parse::<IpAddr>().ok();
+        match field {
+            FlowField::SourceAddress => {
+                address(&self.source_address).cmp(&address(&other.source_address))
+            }
+            FlowField::DestinationAddress => {
+                address(&self.destination_address).cmp(&address(&other.destination_address))
+            }
+            FlowField::SourcePort => self.source_port.cmp(&other.source_port),
+            FlowField::DestinationPort => self.destination_port.cmp(&other.destination_port),
+            FlowField::Protocol => self.protocol.cmp(&other.protocol),
+            FlowField::Action => self.action.cmp(&other.action),
+            FlowField::Packets => self.packets.cmp(&other.packets),
+            FlowField::Bytes => self.bytes.cmp(&other.bytes),
+        }
+    }
+}
+
+/// Conditions on the fields of flow log records, such as `action=REJECT dstport=22`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FlowFilter {
+    conditions: Vec<(FlowField, String)>,
+}
+
+impl FlowFilter {
+    /// Parse the space-separated `field=value` conditions of `text`, or `None` if any isn't a
+    /// condition on a known field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::vpc::FlowFilter;
+    ///
+    /// assert!(FlowFilter::parse("action=REJECT dstport=22").is_some());
+    /// assert_eq!(FlowFilter::parse("port=22"), None);
+    /// assert_eq!(FlowFilter::parse("REJECT"), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let conditions = text
+            .split_whitespace()
+            .map(|condition| {
+                let (name, value) = condition.split_once('=')?;
+                Some((FlowField::from_name(name)?, value.to_string()))
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self { conditions })
+    }
+
+    /// Whether `flow_log` meets every condition, ignoring case.
+    ///
+    /// Addresses only need to start with the value, to match the addresses of a subnet such as
+    /// `10.0.1.`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::vpc::{FlowFilter, FlowLog};
+    ///
+    /// let flow_log = FlowLog::parse(
+    ///     "2 123456789012 eni-0a1b2c3d 203.0.113.12 10.0.1.5 49152 22 6 20 4249 1717243200 1717243260 REJECT OK",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(FlowFilter::parse("action=reject dstaddr=10.0.1.").unwrap().matches(&flow_log));
+    /// assert!(FlowFilter::parse("protocol=TCP").unwrap().matches(&flow_log));
+    /// assert!(!FlowFilter::parse("dstport=443").unwrap().matches(&flow_log));
+    /// ```
+    pub fn matches(&self, flow_log: &FlowLog) -> bool {
+        self.conditions.iter().all(|(field, value)| {
+            let actual = flow_log.value(*field);
+            match field {
+                FlowField::SourceAddress | FlowField::DestinationAddress => actual
+                    .get(..value.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(value)),
+                _ => actual.eq_ignore_ascii_case(value),
+            }
+        })
+    }
+}
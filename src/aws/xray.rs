@@ -0,0 +1,232 @@
+//! Client for AWS X-Ray.
+//!
+//! Provides optimized methods for accessing the traces of AWS Lambda invocations.
+use aws_config::SdkConfig;
+use aws_sdk_xray;
+use serde_json::Value;
+
+use super::error::AwsError;
+use super::retry::ThrottlingInterceptor;
+
+// Length of the hex digits of the time, and of the unique part, of a trace ID.
+const TRACE_ID_TIME_LEN: usize = 8;
+const TRACE_ID_UNIQUE_LEN: usize = 24;
+
+/// The first X-Ray trace ID in `text`, such as `1-5759e988-bd862e3fe1be46a994272793`.
+///
+/// Lambda logs the trace ID of traced invocations in their `REPORT` line, and runtimes often
+/// log it in the `_X_AMZN_TRACE_ID` header of requests.
+///
+/// # Examples
+///
+/// ```
+/// use aws_logs_tui::aws::xray;
+///
+/// assert_eq!(
+///     xray::find_trace_id("XRAY TraceId: 1-5759e988-bd862e3fe1be46a994272793\tSampled: true"),
+///     Some("1-5759e988-bd862e3fe1be46a994272793")
+/// );
+/// assert_eq!(xray::find_trace_id("Root=1-5759e988-bd862e3f;Sampled=1"), None);
+/// ```
+pub fn find_trace_id(text: &str) -> Option<&str> {
+    let len = 2 + TRACE_ID_TIME_LEN + 1 + TRACE_ID_UNIQUE_LEN;
+    text.match_indices("1-").find_map(|(start, _)| {
+        let candidate = text.get(start..start + len)?;
+        let (time, unique) = candidate[2..].split_once('-')?;
+        let is_hex = |part: &str| part.bytes().all(|byte| byte.is_ascii_hexdigit());
+        (time.len() == TRACE_ID_TIME_LEN && is_hex(time) && is_hex(unique)).then_some(candidate)
+    })
+}
+
+/// A segment, or subsegment, of an X-Ray trace, such as a call to a downstream service.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Segment {
+    pub name: String,
+    /// When the segment started, and ended if it has, in seconds since the Unix epoch.
+    pub start_time: f64,
+    pub end_time: Option<f64>,
+    /// How deeply the segment is nested in subsegments, 0 for a segment.
+    pub depth: usize,
+    /// Whether the segment failed, by error, fault, or throttling.
+    pub failed: bool,
+}
+
+/// An X-Ray trace, with its segments and their subsegments in the order they started.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trace {
+    pub id: String,
+    /// How long the trace took, in seconds.
+    pub duration: Option<f64>,
+    /// Subsegments follow the segment they are nested in.
+    pub segments: Vec<Segment>,
+}
+
+impl Trace {
+    /// The trace `id` of the segment `documents`, which X-Ray stores as JSON.
+    ///
+    /// Documents that aren't valid JSON are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aws_logs_tui::aws::xray::Trace;
+    ///
+    /// let trace = Trace::from_documents(
+    ///     "1-5759e988-bd862e3fe1be46a994272793",
+    ///     Some(0.5),
+    ///     [r#"{
+    ///         "name": "my-function", "start_time": 1.0, "end_time": 1.5,
+    ///         "subsegments": [
+    ///             {"name": "DynamoDB", "start_time": 1.1, "end_time": 1.3, "fault": true}
+    ///         ]
+    ///     }"#],
+    /// );
+    ///
+    /// assert_eq!(trace.segments.len(), 2);
+    /// assert_eq!(trace.segments[0].name, "my-function");
+    /// assert_eq!(trace.segments[1].depth, 1);
+    /// assert!(trace.segments[1].failed);
+    /// ```
+    pub fn from_documents<'a>(
+        id: &str,
+        duration: Option<f64>,
+        documents: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let mut documents: Vec<Value> = documents
+            .into_iter()
+            .filter_map(|document| serde_json::from_str(document).ok())
+            .collect();
+        documents.sort_by(|a, b| start_time(a).total_cmp(&start_time(b)));
+
+        let mut segments = Vec::new();
+        for document in &documents {
+            flatten(document, 0, &mut segments);
+        }
+
+        Self {
+            id: id.to_string(),
+            duration,
+            segments,
+        }
+    }
+
+    /// When the earliest segment started, in seconds since the Unix epoch.
+    pub fn start_time(&self) -> Option<f64> {
+        self.segments
+            .iter()
+            .map(|segment| segment.start_time)
+            .min_by(f64::total_cmp)
+    }
+}
+
+fn start_time(document: &Value) -> f64 {
+    document["start_time"].as_f64().unwrap_or_default()
+}
+
+/// Add the segment `document`, then its subsegments in the order they started, to `segments`.
+fn flatten(document: &Value, depth: usize, segments: &mut Vec<Segment>) {
+    let flag = |name: &str| document[name].as_bool().unwrap_or_default();
+    segments.push(Segment {
+        name: document["name"].as_str().unwrap_or_default().to_string(),
+        start_time: start_time(document),
+        end_time: document["end_time"].as_f64(),
+        depth,
+        failed: flag("error") || flag("fault") || flag("throttle"),
+    });
+
+    let mut subsegments: Vec<&Value> = document["subsegments"]
+        .as_array()
+        .map(|subsegments| subsegments.iter().collect())
+        .unwrap_or_default();
+    subsegments.sort_by(|a, b| start_time(a).total_cmp(&start_time(b)));
+    for subsegment in subsegments {
+        flatten(subsegment, depth + 1, segments);
+    }
+}
+
+/// Client instance for AWS X-Ray
+#[derive(Clone, Debug)]
+pub struct Client {
+    client: aws_sdk_xray::Client,
+}
+
+impl Client {
+    /// Create a new AWS X-Ray client with the provided [`SdkConfig`].
+    ///
+    /// Using [`config::load_config()`](super::config::load_config()) is recommended to get an
+    /// `SdkConfig` instance from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// use aws_logs_tui::aws::{config, xray};
+    ///
+    /// let sdk_config = config::load_config(None, None).await;
+    ///
+    /// let xray_client = xray::Client::new(&sdk_config);
+    /// # }
+    /// ```
+    pub fn new(config: &SdkConfig) -> Self {
+        let client_config = aws_sdk_xray::config::Builder::from(config)
+            .interceptor(ThrottlingInterceptor)
+            .build();
+        let client = aws_sdk_xray::Client::from_conf(client_config);
+
+        Self { client }
+    }
+
+    /// Get the X-Ray trace `trace_id`, or `None` if X-Ray has no such trace (yet).
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AwsError`] if the trace fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), aws_logs_tui::aws::error::AwsError> {
+    /// # use aws_logs_tui::aws::{config, xray};
+    /// # let sdk_config = config::load_config(None, None).await;
+    /// let xray_client = xray::Client::new(&sdk_config);
+    ///
+    /// if let Some(trace) = xray_client.get_trace("1-5759e988-bd862e3fe1be46a994272793").await? {
+    ///     for segment in trace.segments {
+    ///         println!("{}{}", "  ".repeat(segment.depth), segment.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_trace(&self, trace_id: &str) -> Result<Option<Trace>, AwsError> {
+        let mut pages = self
+            .client
+            .batch_get_traces()
+            .trace_ids(trace_id)
+            .into_paginator()
+            .send();
+
+        let mut documents = Vec::new();
+        let mut duration = None;
+        let mut found = false;
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|error| AwsError::new("BatchGetTraces", error))?;
+            for trace in page.traces() {
+                found = true;
+                duration = duration.or(trace.duration());
+                documents.extend(
+                    trace
+                        .segments()
+                        .iter()
+                        .filter_map(|segment| segment.document().map(String::from)),
+                );
+            }
+        }
+
+        Ok(found.then(|| {
+            Trace::from_documents(trace_id, duration, documents.iter().map(String::as_str))
+        }))
+    }
+}
@@ -0,0 +1,99 @@
+//! Functions listed by earlier runs of the app.
+//!
+//! Kept as JSON in the `aws-logs-tui` directory of the user's cache directory, such as
+//! `~/.cache/aws-logs-tui/` on Linux, with a file for each profile, region, and role, so
+//! the app lists functions as soon as it starts while they load again.
+use std::{fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use aws_logs_tui::aws;
+
+const CACHE_DIR_NAME: &str = "aws-logs-tui";
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CachedFunction {
+    name: String,
+    region: Option<String>,
+}
+
+/// The functions cached for the `profile`, `region`, and assumed role `role_arn`, if any were.
+///
+/// A cache that can't be read is as good as none.
+pub fn load_functions(
+    profile: Option<&str>,
+    region: Option<&str>,
+    role_arn: Option<&str>,
+) -> Option<Vec<aws::lambda::Function>> {
+    let path = functions_path(profile, region, role_arn)?;
+    let contents = fs::read_to_string(path).ok()?;
+    let functions: Vec<CachedFunction> = serde_json::from_str(&contents).ok()?;
+
+    Some(
+        functions
+            .into_iter()
+            .map(|function| aws::lambda::Function {
+                name: function.name,
+                region: function.region,
+            })
+            .collect(),
+    )
+}
+
+/// Cache the `functions` of the `profile`, `region`, and assumed role `role_arn`.
+pub fn save_functions(
+    profile: Option<&str>,
+    region: Option<&str>,
+    role_arn: Option<&str>,
+    functions: &[aws::lambda::Function],
+) -> io::Result<()> {
+    let Some(path) = functions_path(profile, region, role_arn) else {
+        return Ok(());
+    };
+
+    let functions: Vec<CachedFunction> = functions
+        .iter()
+        .map(|function| CachedFunction {
+            name: function.name.clone(),
+            region: function.region.clone(),
+        })
+        .collect();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string(&functions)?)
+}
+
+fn functions_path(
+    profile: Option<&str>,
+    region: Option<&str>,
+    role_arn: Option<&str>,
+) -> Option<PathBuf> {
+    let mut name = format!(
+        "functions-{}-{}",
+        profile.unwrap_or("default"),
+        region.unwrap_or("default")
+    );
+    if let Some(role_arn) = role_arn {
+        name.push('-');
+        name.push_str(role_arn);
+    }
+    // Regions are comma-separated, and ARNs have colons and slashes, none of which belong in
+    // the name of a file.
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    Some(
+        dirs::cache_dir()?
+            .join(CACHE_DIR_NAME)
+            .join(format!("{name}.json")),
+    )
+}
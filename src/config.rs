@@ -0,0 +1,217 @@
+//! User configuration of the app.
+//!
+//! Read from `config.toml` in the `aws-logs-tui` directory of the user's config
+//! directory, such as `~/.config/aws-logs-tui/config.toml` on Linux. Every setting
+//! is optional, and command-line args take precedence over the file.
+use std::{collections::HashMap, fs, io, path::PathBuf, time::Duration};
+
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::{
+    session::SavedQuery,
+    theme::{DEFAULT_THEME_NAME, Theme, ThemeConfig},
+    timestamp::{TimeZone, TimestampFormat, TimestampStyle},
+};
+
+const CONFIG_DIR_NAME: &str = "aws-logs-tui";
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// AWS Profile to use.
+    pub profile: Option<String>,
+    /// AWS Region to use, or comma-separated Regions (or `all`) to list functions from several.
+    pub region: Option<String>,
+    /// ARN of an IAM role to assume with the credentials of the profile, such as a role in
+    /// another account.
+    pub role_arn: Option<String>,
+    /// How often to refresh the list of functions, in seconds, if at all.
+    pub refresh_interval: Option<u64>,
+    /// Name of the theme to use, either built-in or from `themes`.
+    pub theme: Option<String>,
+    /// Whether to capture the mouse, true unless set.
+    ///
+    /// Leave the mouse to the terminal to select text with it instead.
+    pub mouse: Option<bool>,
+    /// Time zone of timestamps, `utc` unless set.
+    pub timezone: Option<TimeZone>,
+    /// Style of timestamps, `iso` unless set, or `relative` to show the time elapsed since.
+    pub timestamps: Option<TimestampStyle>,
+    /// User-defined themes, by name.
+    pub themes: HashMap<String, ThemeConfig>,
+    /// Logs Insights queries to pick from, by name.
+    pub queries: HashMap<String, String>,
+    /// Whether Logs Insights queries saved in the app are saved to CloudWatch Logs too, as
+    /// query definitions, false unless set.
+    pub sync_queries: Option<bool>,
+    /// How long log events newly streamed by Live Tail are marked as new for, in seconds, 3
+    /// unless set, or 0 not to mark them.
+    pub new_event_highlight: Option<u64>,
+    /// Log events to alert of when Live Tail streams them.
+    pub alerts: Vec<AlertConfig>,
+    /// Regex matching the account ID, profile, or user or role ARN of production accounts, such
+    /// as `prod|123456789012`, to warn loudly when viewing one.
+    pub production_pattern: Option<String>,
+    /// Fields of JSON log messages to show as the columns of a table.
+    pub columns: Vec<ColumnConfig>,
+    /// S3 bucket, and key prefix, to export log groups to unless another is input, such as
+    /// `my-log-exports/cloudwatch`.
+    pub export_bucket: Option<String>,
+}
+
+/// A column of the table of JSON log messages.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnConfig {
+    /// Dot-separated path of the field in the JSON, such as `level` or `request.id`.
+    pub path: String,
+    /// How wide the column is, unless it is the last one, which is as wide as its fields.
+    pub width: Option<usize>,
+}
+
+/// Log events to alert of, ringing the terminal bell, when Live Tail streams them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AlertConfig {
+    /// Regex matching the messages of the log events, such as `ERROR|Task timed out`.
+    pub pattern: String,
+    /// Whether to show a desktop notification too, while the terminal isn't focused.
+    pub notify: bool,
+}
+
+/// A pattern of log events to alert of, as parsed.
+#[derive(Debug)]
+pub struct AlertPattern {
+    pub regex: Regex,
+    pub notify: bool,
+}
+
+impl Config {
+    /// Load the config file, or the default config if there is no config file.
+    pub fn load() -> Result<Self> {
+        let Some(path) = config_path() else {
+            return Ok(Self::default());
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => {
+                return Err(error).wrap_err_with(|| format!("Failed to read {}", path.display()));
+            }
+        };
+
+        toml::from_str(&contents).wrap_err_with(|| format!("Invalid config in {}", path.display()))
+    }
+
+    /// How often to refresh the list of functions, if at all.
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval
+            .filter(|&secs| secs > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// How long newly streamed log events are marked as new for.
+    pub fn new_event_highlight(&self) -> Duration {
+        Duration::from_secs(self.new_event_highlight.unwrap_or(3))
+    }
+
+    /// Whether to capture the mouse.
+    pub fn mouse(&self) -> bool {
+        self.mouse.unwrap_or(true)
+    }
+
+    /// The Logs Insights queries named in the config, sorted by name.
+    pub fn configured_queries(&self) -> Vec<SavedQuery> {
+        let mut queries: Vec<SavedQuery> = self
+            .queries
+            .iter()
+            .map(|(name, query)| SavedQuery {
+                name: name.clone(),
+                query: query.clone(),
+            })
+            .collect();
+        queries.sort_by(|a, b| a.name.cmp(&b.name));
+        queries
+    }
+
+    /// Whether to save Logs Insights queries to CloudWatch Logs too.
+    pub fn sync_queries(&self) -> bool {
+        self.sync_queries.unwrap_or(false)
+    }
+
+    /// The regex matching production accounts, if set.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the pattern is not a valid regex.
+    pub fn production_pattern(&self) -> Result<Option<Regex>> {
+        self.production_pattern
+            .as_deref()
+            .map(|pattern| {
+                Regex::new(pattern)
+                    .wrap_err_with(|| format!("Invalid production_pattern {pattern}"))
+            })
+            .transpose()
+    }
+
+    /// The patterns of the log events to alert of.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any pattern is not a valid regex.
+    pub fn alert_patterns(&self) -> Result<Vec<AlertPattern>> {
+        self.alerts
+            .iter()
+            .map(|alert| {
+                let regex = Regex::new(&alert.pattern)
+                    .wrap_err_with(|| format!("Invalid alert pattern {}", alert.pattern))?;
+                Ok(AlertPattern {
+                    regex,
+                    notify: alert.notify,
+                })
+            })
+            .collect()
+    }
+
+    /// How to show timestamps.
+    pub fn timestamp_format(&self) -> TimestampFormat {
+        TimestampFormat {
+            time_zone: self.timezone.unwrap_or_default(),
+            style: self.timestamps.unwrap_or_default(),
+        }
+    }
+
+    /// The theme to use, `dark` unless set.
+    ///
+    /// # Errors
+    ///
+    /// Fails if there is no built-in or user-defined theme with the name.
+    pub fn theme(&self) -> Result<Theme> {
+        let name = self.theme.as_deref().unwrap_or(DEFAULT_THEME_NAME);
+        let theme = match self.themes.get(name) {
+            Some(theme_config) => theme_config.to_theme().ok_or_else(|| {
+                eyre!("Theme {name} is based on an unknown theme, use dark or light")
+            })?,
+            None => Theme::built_in(name).ok_or_else(|| {
+                eyre!("Unknown theme {name}, use dark, light, or a theme from the config file")
+            })?,
+        };
+
+        Ok(theme)
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    Some(
+        dirs::config_dir()?
+            .join(CONFIG_DIR_NAME)
+            .join(CONFIG_FILE_NAME),
+    )
+}
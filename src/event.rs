@@ -0,0 +1,262 @@
+//! Events driving the app.
+//!
+//! Terminal input, regular ticks, and the results of background tasks are all
+//! delivered through a single channel, so the app never blocks waiting on any one of them.
+use std::time::Duration;
+
+use crossterm::event::{Event, EventStream, KeyEvent, MouseEvent};
+use futures::StreamExt;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use aws_logs_tui::aws::{self, error::AwsError};
+
+use crate::time_range::TimeRange;
+
+/// An event for the app to handle.
+#[derive(Debug)]
+pub enum AppEvent {
+    /// A key was pressed, or released.
+    Key(KeyEvent),
+    /// The mouse was clicked, moved, or scrolled, while captured.
+    Mouse(MouseEvent),
+    /// The terminal was resized.
+    Resize,
+    /// The terminal gained focus, or lost it.
+    Focus(bool),
+    /// The tick rate elapsed.
+    Tick,
+    /// A page of Lambda functions was loaded, the last page has no next marker.
+    FunctionsPageLoaded(Result<aws::lambda::FunctionPage, AwsError>),
+    /// The AWS config of a newly selected profile or region was loaded, or loaded again only
+    /// for new credentials, keeping everything loaded with the expired ones.
+    SdkConfigLoaded {
+        profile: Option<String>,
+        region: Option<String>,
+        sdk_config: Box<aws_config::SdkConfig>,
+        credentials_only: bool,
+    },
+    /// Who the credentials of the profile and region are of was loaded.
+    CallerIdentityLoaded {
+        profile: Option<String>,
+        region: Option<String>,
+        identity: Result<aws::sts::CallerIdentity, AwsError>,
+    },
+    /// The configuration of a selected Lambda function was loaded.
+    FunctionConfigurationLoaded {
+        function: aws::lambda::Function,
+        configuration: Box<Result<aws::lambda::FunctionConfiguration, AwsError>>,
+    },
+    /// When a Lambda function last logged was loaded.
+    FunctionActivityLoaded {
+        function: aws::lambda::Function,
+        last_event_time: Result<Option<i64>, AwsError>,
+    },
+    /// The tags of a Lambda function were loaded.
+    FunctionTagsLoaded {
+        function: aws::lambda::Function,
+        tags: Result<Vec<(String, String)>, AwsError>,
+    },
+    /// The metrics of a Lambda function over a time range were loaded.
+    FunctionMetricsLoaded {
+        function: aws::lambda::Function,
+        time_range: TimeRange,
+        metrics: Result<aws::cloudwatch_metrics::FunctionMetrics, AwsError>,
+    },
+    /// The versions of a Lambda function were loaded, with their aliases.
+    FunctionVersionsLoaded {
+        function: aws::lambda::Function,
+        versions: Result<Vec<aws::lambda::FunctionVersion>, AwsError>,
+    },
+    /// A Lambda function was invoked, or only checked that it can be, for a dry run.
+    FunctionInvoked {
+        function: aws::lambda::Function,
+        dry_run: bool,
+        invocation: Result<aws::lambda::Invocation, AwsError>,
+    },
+    /// An X-Ray trace was loaded, if X-Ray has it.
+    TraceLoaded {
+        trace_id: String,
+        trace: Result<Option<aws::xray::Trace>, AwsError>,
+    },
+    /// All Lambda functions were loaded again, to refresh the listed functions.
+    FunctionsRefreshed(Result<aws::lambda::FunctionPage, AwsError>),
+    /// All log groups were loaded.
+    LogGroupsLoaded(Result<Vec<aws::cloudwatch_logs::LogGroup>, AwsError>),
+    /// All ECS services were loaded.
+    ServicesLoaded(Result<Vec<aws::ecs::Service>, AwsError>),
+    /// Where the containers of a selected ECS service log to, and its tasks, were loaded.
+    ServiceLogsLoaded {
+        service: aws::ecs::Service,
+        service_logs: Result<aws::ecs::ServiceLogs, AwsError>,
+    },
+    /// All Step Functions state machines were loaded.
+    StateMachinesLoaded(Result<Vec<aws::step_functions::StateMachine>, AwsError>),
+    /// All stages of API Gateway REST APIs were loaded.
+    StagesLoaded(Result<Vec<aws::api_gateway::Stage>, AwsError>),
+    /// All RDS DB clusters, and DB instances outside of them, were loaded.
+    DatabasesLoaded(Result<Vec<aws::rds::Database>, AwsError>),
+    /// All CodeBuild projects were loaded.
+    ProjectsLoaded(Result<Vec<aws::codebuild::Project>, AwsError>),
+    /// The recent executions of a state machine were loaded.
+    ExecutionsLoaded {
+        state_machine: aws::step_functions::StateMachine,
+        executions: Result<Vec<aws::step_functions::Execution>, AwsError>,
+    },
+    /// The event history of an execution was loaded.
+    ExecutionHistoryLoaded {
+        execution_arn: String,
+        history: Result<Vec<aws::step_functions::HistoryEvent>, AwsError>,
+    },
+    /// The event source mappings of a function were loaded.
+    EventSourceMappingsLoaded {
+        function: aws::lambda::Function,
+        mappings: Result<Vec<aws::lambda::EventSourceMapping>, AwsError>,
+    },
+    /// The messages of the dead-letter queue of a function were peeked at.
+    FailedEventsLoaded {
+        queue_arn: String,
+        messages: Result<Vec<aws::sqs::Message>, AwsError>,
+    },
+    /// The recent builds of a CodeBuild project were loaded.
+    BuildsLoaded {
+        project: aws::codebuild::Project,
+        builds: Result<Vec<aws::codebuild::Build>, AwsError>,
+    },
+    /// The CloudTrail events of a resource, named like an event name, were looked up.
+    CloudTrailEventsLoaded {
+        resource_name: String,
+        event_name: String,
+        events: Result<Vec<aws::cloudtrail::TrailEvent>, AwsError>,
+    },
+    /// The recent log streams of a log group were loaded.
+    LogStreamsLoaded {
+        log_group_name: String,
+        log_streams: Result<Vec<aws::cloudwatch_logs::LogStream>, AwsError>,
+    },
+    /// The recent log events of a log group, or some of its log streams, were loaded.
+    LogEventsLoaded {
+        log_group_name: String,
+        log_stream_names: Vec<String>,
+        events: Result<Vec<aws::cloudwatch_logs::LogEvent>, AwsError>,
+    },
+    /// Log events older than those in the log viewer were loaded, none when no more remain.
+    OlderLogEventsLoaded {
+        log_group_name: String,
+        log_stream_names: Vec<String>,
+        events: Result<Vec<aws::cloudwatch_logs::LogEvent>, AwsError>,
+    },
+    /// New log events were streamed by a Live Tail session, or the session failed.
+    LiveTailEvents {
+        log_group_name: String,
+        log_stream_names: Vec<String>,
+        events: Result<Vec<aws::cloudwatch_logs::LogEvent>, AwsError>,
+    },
+    /// The Logs Insights queries saved in CloudWatch Logs were loaded.
+    QueryDefinitionsLoaded(Result<Vec<aws::cloudwatch_logs::QueryDefinition>, AwsError>),
+    /// A Logs Insights query finished.
+    QueryResultsLoaded {
+        log_group_name: String,
+        results: Result<aws::cloudwatch_logs::QueryResults, AwsError>,
+    },
+    /// The retention of a log group was set, unless it failed to be.
+    RetentionSet {
+        log_group_name: String,
+        retention_in_days: Option<i32>,
+        result: Result<(), AwsError>,
+    },
+    /// Another page of log events was downloaded to a file.
+    DownloadProgress {
+        id: u64,
+        count: usize,
+        /// How far through the time range the download is, from 0 to 1.
+        progress: f64,
+    },
+    /// A download of log events to a file ended, with what became of it.
+    DownloadFinished {
+        id: u64,
+        outcome: Result<String, String>,
+    },
+    /// The log event of a Logs Insights result was loaded, if it still exists.
+    LogRecordLoaded(Result<Option<aws::cloudwatch_logs::LogEvent>, AwsError>),
+    /// A line was printed by `aws sso login`, such as the code to authorize the device with.
+    SsoLoginOutput(String),
+    /// `aws sso login` exited, having signed in unless it failed.
+    SsoLoginFinished(Result<(), String>),
+    /// A background task did what was asked, with an outcome to tell the user.
+    Notice(String),
+    /// A background task could not do what was asked, for a reason other than AWS failing.
+    Error(String),
+}
+
+/// Source of [`AppEvent`]s, from the terminal and from background tasks.
+#[derive(Debug)]
+pub struct EventHandler {
+    sender: mpsc::UnboundedSender<AppEvent>,
+    receiver: mpsc::UnboundedReceiver<AppEvent>,
+    task: JoinHandle<()>,
+}
+
+impl EventHandler {
+    /// Start reading terminal events, with a [`AppEvent::Tick`] every `tick_rate`.
+    pub fn new(tick_rate: Duration) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let task = tokio::spawn(read_terminal_events(sender.clone(), tick_rate));
+
+        Self {
+            sender,
+            receiver,
+            task,
+        }
+    }
+
+    /// A sender for background tasks to deliver their own events.
+    pub fn sender(&self) -> mpsc::UnboundedSender<AppEvent> {
+        self.sender.clone()
+    }
+
+    /// Run `task` in the background, delivering the event it produces when done.
+    pub fn spawn<F>(&self, task: F) -> JoinHandle<()>
+    where
+        F: Future<Output = AppEvent> + Send + 'static,
+    {
+        let sender = self.sender();
+        tokio::spawn(async move {
+            // The app has exited if nobody is receiving, so the event can be dropped.
+            let _ = sender.send(task.await);
+        })
+    }
+
+    /// Wait for the next event.
+    pub async fn next(&mut self) -> Option<AppEvent> {
+        self.receiver.recv().await
+    }
+}
+
+impl Drop for EventHandler {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn read_terminal_events(sender: mpsc::UnboundedSender<AppEvent>, tick_rate: Duration) {
+    let mut reader = EventStream::new();
+    let mut tick = tokio::time::interval(tick_rate);
+
+    loop {
+        let event = tokio::select! {
+            _ = tick.tick() => AppEvent::Tick,
+            Some(Ok(event)) = reader.next() => match event {
+                Event::Key(key) => AppEvent::Key(key),
+                Event::Mouse(mouse) => AppEvent::Mouse(mouse),
+                Event::Resize(_, _) => AppEvent::Resize,
+                Event::FocusGained => AppEvent::Focus(true),
+                Event::FocusLost => AppEvent::Focus(false),
+                _ => continue,
+            },
+        };
+
+        if sender.send(event).is_err() {
+            break;
+        }
+    }
+}
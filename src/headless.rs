@@ -0,0 +1,161 @@
+//! Commands that print to stdout instead of running the TUI.
+//!
+//! They use the same AWS clients as the TUI, so functions can be listed, and logs read, from
+//! scripts and CI.
+use std::time::{Duration, SystemTime};
+
+use aws_config::SdkConfig;
+use chrono::Utc;
+use color_eyre::{Result, eyre::eyre};
+use futures::future;
+
+use aws_logs_tui::aws::{self, cloudwatch_logs::EventFilter};
+
+use crate::{
+    time_range::epoch_millis,
+    timestamp::{TimestampFormat, TimestampStyle},
+};
+
+// Upper bound on the log events printed before following new log events.
+const MAX_LOG_EVENTS: usize = 10_000;
+
+/// Print the names of the Lambda functions of every region of `regions`, or of the region of
+/// `sdk_config` if there are none, or the names of all log groups if `log_groups`.
+///
+/// Functions are printed with their region, tab-separated, when listed from several regions.
+pub async fn list(sdk_config: &SdkConfig, regions: &[String], log_groups: bool) -> Result<()> {
+    if log_groups {
+        let logs_client = aws::cloudwatch_logs::Client::new(sdk_config);
+        for log_group in logs_client.get_all_log_groups().await? {
+            println!("{}", log_group.name);
+        }
+        return Ok(());
+    }
+
+    let lambda_clients: Vec<_> = if regions.len() <= 1 {
+        vec![aws::lambda::Client::new(sdk_config)]
+    } else {
+        regions
+            .iter()
+            .map(|region| aws::lambda::Client::new(&aws::config::with_region(sdk_config, region)))
+            .collect()
+    };
+    let multi_region = lambda_clients.len() > 1;
+
+    let results = future::join_all(
+        lambda_clients
+            .iter()
+            .map(aws::lambda::Client::get_all_functions),
+    )
+    .await;
+    for functions in results {
+        for function in functions? {
+            match &function.region {
+                Some(region) if multi_region => println!("{}\t{region}", function.name),
+                _ => println!("{}", function.name),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the log events of the last `since` of the function, or log group, `target`, after
+/// their timestamps, and then its new log events as they are ingested if `follow`.
+///
+/// Only log events matching the filter `pattern` are printed, if set.
+pub async fn tail(
+    sdk_config: &SdkConfig,
+    target: &str,
+    since: Duration,
+    pattern: Option<String>,
+    follow: bool,
+    timestamp_format: TimestampFormat,
+) -> Result<()> {
+    let logs_client = aws::cloudwatch_logs::Client::new(sdk_config);
+    let log_group_name = log_group_name(target);
+    let filter = EventFilter {
+        start_time: Some(epoch_millis(since_start(SystemTime::now(), since))),
+        pattern,
+        ..Default::default()
+    };
+    // Printed timestamps are never relative, as they would soon be wrong.
+    let timestamp_format = TimestampFormat {
+        style: TimestampStyle::Iso,
+        ..timestamp_format
+    };
+    let print = |log_events: Vec<aws::cloudwatch_logs::LogEvent>| {
+        let now = Utc::now();
+        for log_event in log_events {
+            let timestamp = timestamp_format.format(log_event.timestamp, now);
+            println!("{timestamp} {}", log_event.message.trim_end());
+        }
+    };
+
+    print(
+        logs_client
+            .get_recent_log_events(&log_group_name, &filter, MAX_LOG_EVENTS)
+            .await?,
+    );
+    if !follow {
+        return Ok(());
+    }
+
+    let log_group_arn = logs_client
+        .get_log_group(&log_group_name)
+        .await?
+        .and_then(|log_group| log_group.arn)
+        .ok_or_else(|| eyre!("Log group {log_group_name} does not exist yet, nothing to tail"))?;
+    let mut live_tail = logs_client
+        .start_live_tail(&[log_group_arn], &filter)
+        .await?;
+    while let Some(log_events) = live_tail.next_events().await? {
+        print(log_events);
+    }
+
+    Ok(())
+}
+
+/// Run the Logs Insights `query` over the last `since` of the function, or log group, `target`,
+/// printing the results as tab-separated rows after a header row.
+pub async fn query(
+    sdk_config: &SdkConfig,
+    target: &str,
+    query: &str,
+    since: Duration,
+) -> Result<()> {
+    let logs_client = aws::cloudwatch_logs::Client::new(sdk_config);
+    let now = SystemTime::now();
+    // Logs Insights takes times in seconds.
+    let start_time = epoch_millis(since_start(now, since)) / 1000;
+    let end_time = epoch_millis(now) / 1000;
+
+    let results = logs_client
+        .run_query(&[log_group_name(target)], query, start_time, end_time)
+        .await?;
+    if results.status != "Complete" {
+        return Err(eyre!("Query did not complete, it is {}", results.status));
+    }
+
+    println!("{}", results.columns.join("\t"));
+    for row in results.rows {
+        println!("{}", row.values.join("\t"));
+    }
+
+    Ok(())
+}
+
+/// The log group named by `target`, which is either a log group name starting with `/`, or
+/// the name of a Lambda function.
+fn log_group_name(target: &str) -> String {
+    if target.starts_with('/') {
+        target.to_string()
+    } else {
+        aws::cloudwatch_logs::lambda_log_group_name(target)
+    }
+}
+
+/// When the last `since` before `now` started, or the Unix epoch if that is longer ago.
+fn since_start(now: SystemTime, since: Duration) -> SystemTime {
+    now.checked_sub(since).unwrap_or(SystemTime::UNIX_EPOCH)
+}
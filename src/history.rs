@@ -0,0 +1,75 @@
+//! Filter patterns and Logs Insights queries input before, to recall with Up and Down.
+//!
+//! Kept as JSON in `history.json` in the `aws-logs-tui` directory of the user's state
+//! directory, such as `~/.local/state/aws-logs-tui/history.json` on Linux, or of their data
+//! directory on platforms without one. Unlike the session, the history is shared by every
+//! profile and region.
+use std::{fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+const HISTORY_DIR_NAME: &str = "aws-logs-tui";
+const HISTORY_FILE_NAME: &str = "history.json";
+
+// Most entries kept of each history, dropping the oldest.
+const MAX_ENTRIES: usize = 100;
+
+/// What was input before, oldest first.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct History {
+    pub filter_patterns: Vec<String>,
+    pub queries: Vec<String>,
+}
+
+impl History {
+    /// The history, or a new one if there was none, or it can't be read.
+    pub fn load() -> Self {
+        history_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = history_path() else {
+            return Ok(());
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// Remember `pattern` as the most recent filter pattern.
+    pub fn add_filter_pattern(&mut self, pattern: &str) {
+        add(&mut self.filter_patterns, pattern);
+    }
+
+    /// Remember `query` as the most recent Logs Insights query.
+    pub fn add_query(&mut self, query: &str) {
+        add(&mut self.queries, query);
+    }
+}
+
+fn add(entries: &mut Vec<String>, entry: &str) {
+    if entry.trim().is_empty() {
+        return;
+    }
+
+    entries.retain(|existing| existing != entry);
+    entries.push(entry.to_string());
+    if entries.len() > MAX_ENTRIES {
+        entries.drain(..entries.len() - MAX_ENTRIES);
+    }
+}
+
+fn history_path() -> Option<PathBuf> {
+    Some(
+        dirs::state_dir()
+            .or_else(dirs::data_dir)?
+            .join(HISTORY_DIR_NAME)
+            .join(HISTORY_FILE_NAME),
+    )
+}
@@ -0,0 +1,100 @@
+//! Detection of the level of log messages, such as `ERROR` or `"level":"warn"`.
+//!
+//! The rules are kept together here, as tables, so that they can be configured later.
+
+/// How severe a log message is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+/// Names of each level, matched case-insensitively as values of `LEVEL_KEYS`, or as words when
+/// in upper case, such as the `ERROR` that Lambda runtimes log.
+const LEVEL_NAMES: &[(&str, LogLevel)] = &[
+    ("error", LogLevel::Error),
+    ("err", LogLevel::Error),
+    ("fatal", LogLevel::Error),
+    ("critical", LogLevel::Error),
+    ("crit", LogLevel::Error),
+    ("warn", LogLevel::Warn),
+    ("warning", LogLevel::Warn),
+    ("info", LogLevel::Info),
+    ("notice", LogLevel::Info),
+    ("debug", LogLevel::Debug),
+    ("trace", LogLevel::Debug),
+];
+
+/// Keys of the level in structured messages, such as JSON `"level":"error"` or `level=error`.
+const LEVEL_KEYS: &[&str] = &["level", "levelname", "log_level", "loglevel", "severity"];
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 4] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+    ];
+
+    /// The name of the level, as Lambda runtimes log it.
+    pub fn label(self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+        }
+    }
+
+    /// The level of `message`, if it has one.
+    ///
+    /// A level in a structured message is preferred over a level word, as words such as
+    /// `INFO` may be part of the message itself.
+    pub fn detect(message: &str) -> Option<Self> {
+        Self::detect_structured(message).or_else(|| Self::detect_word(message))
+    }
+
+    /// The level with the name `name`, in any case.
+    fn from_name(name: &str) -> Option<Self> {
+        LEVEL_NAMES
+            .iter()
+            .find(|(level_name, _)| level_name.eq_ignore_ascii_case(name))
+            .map(|&(_, level)| level)
+    }
+
+    /// The level that is the value of one of `LEVEL_KEYS`.
+    fn detect_structured(message: &str) -> Option<Self> {
+        let lowercase = message.to_ascii_lowercase();
+
+        LEVEL_KEYS.iter().find_map(|key| {
+            lowercase.match_indices(key).find_map(|(start, _)| {
+                // Only whole keys, so `level` doesn't match the end of `loglevel`.
+                if lowercase[..start].ends_with(is_word_char) {
+                    return None;
+                }
+
+                let rest = &lowercase[start + key.len()..];
+                let rest = rest.trim_start_matches(['"', '\'', ' ']);
+                let rest = rest.strip_prefix([':', '='])?;
+                let rest = rest.trim_start_matches(['"', '\'', ' ']);
+                let value = rest.split(|c| !is_word_char(c)).next()?;
+
+                Self::from_name(value)
+            })
+        })
+    }
+
+    /// The level of the first upper case word naming a level, such as `ERROR` or `[WARN]`.
+    fn detect_word(message: &str) -> Option<Self> {
+        message
+            .split(|c| !is_word_char(c))
+            .filter(|word| !word.is_empty() && !word.contains(|c: char| c.is_ascii_lowercase()))
+            .find_map(Self::from_name)
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
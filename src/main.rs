@@ -1,31 +1,26 @@
 #![allow(dead_code, unused_imports)]
+use std::time::Duration;
+
 use color_eyre::Result;
-use ratatui::{
-    DefaultTerminal, Frame,
-    buffer::Buffer,
-    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
-    layout::{Constraint, Layout, Rect},
-    style::{
-        Color, Modifier, Style, Stylize,
-        palette::tailwind::{BLUE, GREEN, SLATE},
-    },
-    symbols,
-    text::{Line, Text},
-    widgets::{
-        Block, Borders, HighlightSpacing, List, ListItem, ListState, Padding, Paragraph,
-        StatefulWidget, Widget, Wrap,
-    },
-};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
 use aws_logs_tui::aws;
 
-const FUNCTION_HEADER_STYLE: Style = Style::new().fg(SLATE.c100).bg(BLUE.c800);
-const NORMAL_ROW_BG: Color = SLATE.c950;
-const ALT_ROW_BG_COLOR: Color = SLATE.c900;
-const SELECTED_STYLE: Style = Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD);
-const TEXT_FG_COLOR: Color = SLATE.c200;
+use app::App;
+use config::Config;
+
+mod app;
+mod cache;
+mod config;
+mod event;
+mod headless;
+mod history;
+mod log_level;
+mod session;
+mod theme;
+mod time_range;
+mod timestamp;
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -34,220 +29,159 @@ struct Cli {
     #[arg(short, long)]
     profile: Option<String>,
 
-    /// AWS Region to use
+    /// AWS Region to use, or comma-separated Regions (or `all`) to list functions from several
     #[arg(short, long)]
     region: Option<String>,
-}
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    color_eyre::install()?;
+    /// ARN of an IAM role to assume, such as a role in another account
+    #[arg(long, value_name = "ARN")]
+    role_arn: Option<String>,
 
-    let cli = Cli::parse();
+    /// How often to refresh the list of functions, in seconds [default: never]
+    #[arg(long, value_name = "SECONDS")]
+    refresh_interval: Option<u64>,
 
-    let config = aws::config::load_config(cli.profile, cli.region).await;
+    /// Color theme, either `dark`, `light`, or a theme from the config file [default: dark]
+    #[arg(long, value_name = "NAME")]
+    theme: Option<String>,
 
-    let lambda_client = aws::lambda::Client::new(&config);
-    let lambda_functions = lambda_client.get_all_functions().await;
+    /// Leave the mouse to the terminal, to select text with it
+    #[arg(long)]
+    no_mouse: bool,
 
-    println!("Found [{}] lambda functions:", lambda_functions.len());
-    for function in &lambda_functions {
-        println!("{}", function.name)
-    }
+    /// Print to stdout instead of running the TUI
+    #[command(subcommand)]
+    command: Option<Command>,
+}
 
-    // TODO The app should load the function names itself? Or do we treat this
-    // as a static list? Or do we offer an option to refresh? Or automatically
-    // refresh?
-    let app = App {
-        function_list: {
-            FunctionList {
-                functions: Some(lambda_functions),
-                state: ListState::default(),
-            }
-        },
-        ..Default::default()
-    };
+// Commands that print to stdout, for scripts and CI.
+#[derive(Subcommand)]
+enum Command {
+    /// List Lambda functions, with their regions when listed from several regions
+    List {
+        /// List log groups instead
+        #[arg(long)]
+        log_groups: bool,
+    },
+    /// Print recent log events of a function or log group, optionally following new log events
+    Tail {
+        /// Function name, or log group name starting with `/`
+        target: String,
+
+        /// How far back to print log events from, such as `15m`, `6h`, or `7d`
+        #[arg(long, default_value = "1h", value_parser = parse_duration)]
+        since: Duration,
+
+        /// CloudWatch Logs filter pattern that log events must match
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Keep printing new log events as they are ingested, with Live Tail
+        #[arg(short, long)]
+        follow: bool,
+    },
+    /// Run a Logs Insights query, printing tab-separated rows
+    Query {
+        /// Function name, or log group name starting with `/`
+        target: String,
 
-    let terminal = ratatui::init();
-    let app_result = app.run(terminal);
-    ratatui::restore();
-    app_result
-}
+        /// Logs Insights query, such as `fields @timestamp, @message | limit 20`
+        query: String,
 
-#[derive(Debug, Default)]
-struct FunctionList {
-    functions: Option<Vec<aws::lambda::Function>>,
-    state: ListState,
+        /// How far back to query log events from, such as `15m`, `6h`, or `7d`
+        #[arg(long, default_value = "1h", value_parser = parse_duration)]
+        since: Duration,
+    },
 }
 
-#[derive(Debug, Default)]
-struct App {
-    function_list: FunctionList,
-    should_exit: bool,
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    time_range::parse_duration(input)
+        .ok_or_else(|| format!("invalid duration `{input}`, expected a number and s, m, h, or d"))
 }
 
-impl App {
-    fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
-        while !self.should_exit {
-            terminal.draw(|frame| frame.render_widget(&mut self, frame.area()))?;
-            if let Event::Key(key) = event::read()? {
-                self.handle_key(key)
-            };
-        }
-        Ok(())
-    }
-
-    fn handle_key(&mut self, key: KeyEvent) {
-        if key.kind != KeyEventKind::Press {
-            return;
-        }
-        match key.code {
-            KeyCode::Char('q') | KeyCode::Esc => self.should_exit = true,
-            KeyCode::Char('h') | KeyCode::Left => self.select_none(),
-            KeyCode::Char('j') | KeyCode::Down => self.select_next(),
-            KeyCode::Char('k') | KeyCode::Up => self.select_previous(),
-            KeyCode::Char('g') | KeyCode::Home => self.select_first(),
-            KeyCode::Char('G') | KeyCode::End => self.select_last(),
-            _ => {}
-        }
-    }
+#[tokio::main]
+async fn main() -> Result<()> {
+    color_eyre::install()?;
 
-    fn select_none(&mut self) {
-        self.function_list.state.select(None);
-    }
+    let cli = Cli::parse();
 
-    fn select_next(&mut self) {
-        self.function_list.state.select_next();
-    }
-    fn select_previous(&mut self) {
-        self.function_list.state.select_previous();
+    let mut config = Config::load()?;
+    if cli.profile.is_some() {
+        config.profile = cli.profile;
     }
-
-    fn select_first(&mut self) {
-        self.function_list.state.select_first();
+    if cli.region.is_some() {
+        config.region = cli.region;
     }
-
-    fn select_last(&mut self) {
-        self.function_list.state.select_last();
+    if cli.role_arn.is_some() {
+        config.role_arn = cli.role_arn;
     }
-}
-
-impl Widget for &mut App {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        let [header_area, main_area, footer_area] = Layout::vertical([
-            Constraint::Length(2),
-            Constraint::Fill(1),
-            Constraint::Length(1),
-        ])
-        .areas(area);
-
-        let [list_area, item_area] =
-            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(main_area);
-
-        App::render_header(header_area, buf);
-        App::render_footer(footer_area, buf);
-        self.render_list(list_area, buf);
-        self.render_selected_item(item_area, buf);
+    if cli.refresh_interval.is_some() {
+        config.refresh_interval = cli.refresh_interval;
     }
-}
-
-/// Rendering logic for the app
-impl App {
-    fn render_header(area: Rect, buf: &mut Buffer) {
-        Paragraph::new("AWS Logs TUI")
-            .bold()
-            .centered()
-            .render(area, buf);
+    if cli.theme.is_some() {
+        config.theme = cli.theme;
     }
-
-    fn render_footer(area: Rect, buf: &mut Buffer) {
-        Paragraph::new("Use ↓↑ to move, ← to unselect, → to change status, g/G to go top/bottom.")
-            .centered()
-            .render(area, buf);
+    if cli.no_mouse {
+        config.mouse = Some(false);
     }
+    let theme = config.theme()?;
+    let production_pattern = config.production_pattern()?;
+    let alert_patterns = config.alert_patterns()?;
 
-    fn render_list(&mut self, area: Rect, buf: &mut Buffer) {
-        let block = Block::new()
-            .title(Line::raw("TODO List").centered())
-            .borders(Borders::TOP)
-            .border_set(symbols::border::EMPTY)
-            .border_style(FUNCTION_HEADER_STYLE)
-            .bg(NORMAL_ROW_BG);
-
-        // Iterate through all elements in the `functions` and stylize them.
-        let functions: Vec<ListItem> = self
-            .function_list
-            .functions
-            .clone()
-            .unwrap()
-            .iter()
-            .enumerate()
-            .map(|(i, function)| {
-                let color = alternate_colors(i);
-                ListItem::from(ListItemFunction(function.clone())).bg(color)
-            })
-            .collect();
-
-        // Create a List from all list functions and highlight the currently selected one
-        let list = List::new(functions)
-            .block(block)
-            .highlight_style(SELECTED_STYLE)
-            .highlight_symbol(">")
-            .highlight_spacing(HighlightSpacing::Always);
-
-        // We need to disambiguate this trait method as both `Widget` and `StatefulWidget` share the
-        // same method name `render`.
-        StatefulWidget::render(list, area, buf, &mut self.function_list.state);
+    // Other regions are derived from the first, when listing functions from several.
+    let first_region = config
+        .region
+        .as_deref()
+        .and_then(|region| aws::config::parse_regions(region).into_iter().next());
+    let mut sdk_config = aws::config::load_config(config.profile.clone(), first_region).await;
+    if let Some(role_arn) = &config.role_arn {
+        sdk_config = aws::config::assume_role(&sdk_config, role_arn).await;
     }
 
-    fn render_selected_item(&self, area: Rect, buf: &mut Buffer) {
-        let info = if let Some(i) = self.function_list.state.selected() {
-            match &self.function_list.functions {
-                None => "No functions available...".to_string(),
-                Some(functions) => functions[i].name.clone(),
+    if let Some(command) = cli.command {
+        return match command {
+            Command::List { log_groups } => {
+                let regions = config
+                    .region
+                    .as_deref()
+                    .map(aws::config::parse_regions)
+                    .unwrap_or_default();
+                headless::list(&sdk_config, &regions, log_groups).await
+            }
+            Command::Tail {
+                target,
+                since,
+                filter,
+                follow,
+            } => {
+                headless::tail(
+                    &sdk_config,
+                    &target,
+                    since,
+                    filter,
+                    follow,
+                    config.timestamp_format(),
+                )
+                .await
             }
-        } else {
-            "Nothing selected...".to_string()
+            Command::Query {
+                target,
+                query,
+                since,
+            } => headless::query(&sdk_config, &target, &query, since).await,
         };
-
-        // We show the function's info under the list in this paragraph
-        let block = Block::new()
-            .title(Line::raw("Function Info").centered())
-            .borders(Borders::TOP)
-            .border_set(symbols::border::EMPTY)
-            .border_style(FUNCTION_HEADER_STYLE)
-            .bg(NORMAL_ROW_BG)
-            .padding(Padding::horizontal(1));
-
-        // We can now render the item info
-        Paragraph::new(info)
-            .block(block)
-            .fg(TEXT_FG_COLOR)
-            .wrap(Wrap { trim: false })
-            .render(area, buf);
     }
-}
-
-const fn alternate_colors(i: usize) -> Color {
-    if i % 2 == 0 {
-        NORMAL_ROW_BG
-    } else {
-        ALT_ROW_BG_COLOR
-    }
-}
 
-struct ListItemFunction(aws::lambda::Function);
+    let app = App::new(
+        &sdk_config,
+        &config,
+        theme,
+        production_pattern,
+        alert_patterns,
+    );
 
-impl From<&ListItemFunction> for ListItem<'_> {
-    fn from(value: &ListItemFunction) -> Self {
-        let line = Line::styled(value.0.name.clone(), TEXT_FG_COLOR);
-        ListItem::new(line)
-    }
-}
-
-impl From<ListItemFunction> for Text<'_> {
-    fn from(value: ListItemFunction) -> Self {
-        let line = Line::styled(value.0.name, TEXT_FG_COLOR);
-        Text::from(line)
-    }
+    let terminal = ratatui::init();
+    let app_result = app.run(terminal).await;
+    ratatui::restore();
+    app_result
 }
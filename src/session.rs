@@ -0,0 +1,96 @@
+//! Where the user left off, restored on the next run.
+//!
+//! Kept as JSON in `session.json` in the `aws-logs-tui` directory of the user's state
+//! directory, such as `~/.local/state/aws-logs-tui/session.json` on Linux, or of their data
+//! directory on platforms without one. Each profile and region has its own session.
+use std::{collections::HashMap, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::time_range::TimeRange;
+
+const SESSION_DIR_NAME: &str = "aws-logs-tui";
+const SESSION_FILE_NAME: &str = "session.json";
+
+/// Where the user left off with a profile and region.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Session {
+    /// The function selected last, by name and region.
+    pub function_name: Option<String>,
+    pub function_region: Option<String>,
+    /// How far the functions were scrolled, as the index of the first one shown.
+    pub list_offset: usize,
+    /// The time range log events were loaded from.
+    pub time_range: TimeRange,
+    /// Filter patterns applied to each log group, by log group name.
+    pub filter_patterns: HashMap<String, String>,
+    /// Functions starred as favorites, listed first.
+    pub favorites: Vec<FavoriteFunction>,
+    /// The log groups viewed most recently, most recent first.
+    pub recent_log_groups: Vec<String>,
+    /// Logs Insights queries saved by name.
+    pub saved_queries: Vec<SavedQuery>,
+}
+
+/// A Logs Insights query saved by name.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SavedQuery {
+    pub name: String,
+    pub query: String,
+}
+
+/// A function starred as a favorite.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FavoriteFunction {
+    pub name: String,
+    pub region: Option<String>,
+}
+
+impl Session {
+    /// The session of the `profile` and `region`, or a new one if there was none, or it can't
+    /// be read.
+    pub fn load(profile: Option<&str>, region: Option<&str>) -> Self {
+        load_sessions()
+            .remove(&session_key(profile, region))
+            .unwrap_or_default()
+    }
+
+    /// Save the session as that of the `profile` and `region`, keeping those of the others.
+    pub fn save(&self, profile: Option<&str>, region: Option<&str>) -> io::Result<()> {
+        let Some(path) = session_path() else {
+            return Ok(());
+        };
+
+        let mut sessions = load_sessions();
+        sessions.insert(session_key(profile, region), self.clone());
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&sessions)?)
+    }
+}
+
+fn load_sessions() -> HashMap<String, Session> {
+    session_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn session_key(profile: Option<&str>, region: Option<&str>) -> String {
+    format!(
+        "{}@{}",
+        profile.unwrap_or("default"),
+        region.unwrap_or("default")
+    )
+}
+
+fn session_path() -> Option<PathBuf> {
+    Some(
+        dirs::state_dir()
+            .or_else(dirs::data_dir)?
+            .join(SESSION_DIR_NAME)
+            .join(SESSION_FILE_NAME),
+    )
+}
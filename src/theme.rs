@@ -0,0 +1,249 @@
+//! Colors of the TUI.
+//!
+//! There are built-in `dark` and `light` themes, and users can define their own themes
+//! in the config file, each overriding the colors of a built-in theme.
+use ratatui::style::{
+    Color, Modifier, Style,
+    palette::tailwind::{
+        AMBER, BLUE, EMERALD, FUCHSIA, INDIGO, LIME, ORANGE, PINK, RED, SKY, SLATE, TEAL, VIOLET,
+        YELLOW,
+    },
+};
+use serde::Deserialize;
+
+use crate::log_level::LogLevel;
+
+pub const DEFAULT_THEME_NAME: &str = "dark";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// Pane titles, and table headers.
+    pub header: Style,
+    /// Background of panes, and of every other row of a list.
+    pub row_bg: Color,
+    /// Background of the other rows of a list.
+    pub alt_row_bg: Color,
+    /// The selected row of a list.
+    pub selected: Style,
+    /// Text, such as function names and log messages.
+    pub text: Color,
+    /// The badge of a Live Tail session.
+    pub live: Style,
+    /// Characters matching a fuzzy find query.
+    pub matched: Style,
+    /// Text of log messages matching a search.
+    pub search_match: Style,
+    /// Secondary text, such as the region of a function.
+    pub dimmed: Style,
+    /// Failures.
+    pub error: Style,
+    /// Log messages, by their level.
+    pub log_error: Color,
+    pub log_warn: Color,
+    pub log_info: Color,
+    pub log_debug: Color,
+    /// Log messages of cold starts, where Lambda initializes a new execution environment.
+    pub cold_start: Color,
+    /// Labels of the functions, or log streams, that merged log events came from.
+    pub sources: [Color; 8],
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            header: Style::new().fg(SLATE.c100).bg(BLUE.c800),
+            row_bg: SLATE.c950,
+            alt_row_bg: SLATE.c900,
+            selected: Style::new().bg(SLATE.c800).add_modifier(Modifier::BOLD),
+            text: SLATE.c200,
+            live: Style::new()
+                .fg(SLATE.c100)
+                .bg(RED.c700)
+                .add_modifier(Modifier::BOLD),
+            matched: Style::new().fg(AMBER.c400).add_modifier(Modifier::BOLD),
+            search_match: Style::new().fg(SLATE.c950).bg(AMBER.c400),
+            dimmed: Style::new().fg(SLATE.c400),
+            error: Style::new().fg(RED.c400).add_modifier(Modifier::BOLD),
+            log_error: RED.c400,
+            log_warn: AMBER.c300,
+            log_info: EMERALD.c300,
+            log_debug: SLATE.c500,
+            cold_start: SKY.c300,
+            sources: [
+                SKY.c300,
+                VIOLET.c300,
+                LIME.c300,
+                PINK.c300,
+                ORANGE.c300,
+                TEAL.c300,
+                FUCHSIA.c300,
+                YELLOW.c300,
+            ],
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            header: Style::new().fg(SLATE.c50).bg(BLUE.c600),
+            row_bg: SLATE.c50,
+            alt_row_bg: SLATE.c100,
+            selected: Style::new().bg(SLATE.c300).add_modifier(Modifier::BOLD),
+            text: SLATE.c900,
+            live: Style::new()
+                .fg(SLATE.c50)
+                .bg(RED.c600)
+                .add_modifier(Modifier::BOLD),
+            matched: Style::new().fg(AMBER.c700).add_modifier(Modifier::BOLD),
+            search_match: Style::new().fg(SLATE.c50).bg(AMBER.c600),
+            dimmed: Style::new().fg(SLATE.c500),
+            error: Style::new().fg(RED.c700).add_modifier(Modifier::BOLD),
+            log_error: RED.c700,
+            log_warn: AMBER.c700,
+            log_info: EMERALD.c700,
+            log_debug: SLATE.c500,
+            cold_start: SKY.c700,
+            sources: [
+                SKY.c700,
+                VIOLET.c700,
+                LIME.c700,
+                PINK.c700,
+                ORANGE.c700,
+                TEAL.c700,
+                FUCHSIA.c700,
+                INDIGO.c700,
+            ],
+        }
+    }
+
+    /// The built-in theme named `name`, if there is one.
+    pub fn built_in(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            _ => None,
+        }
+    }
+
+    /// The color of log messages of `level`, or of messages without a level.
+    pub const fn log_level(&self, level: Option<LogLevel>) -> Color {
+        match level {
+            Some(LogLevel::Error) => self.log_error,
+            Some(LogLevel::Warn) => self.log_warn,
+            Some(LogLevel::Info) => self.log_info,
+            Some(LogLevel::Debug) => self.log_debug,
+            None => self.text,
+        }
+    }
+
+    /// The color of the label of the source `name` of log events, always the same for a name.
+    pub fn source(&self, name: &str) -> Color {
+        // FNV-1a, which unlike the hashers of the standard library is the same in every build.
+        let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.sources[(hash % self.sources.len() as u64) as usize]
+    }
+
+    /// The background of the `i`th row of a list, alternating to tell rows apart.
+    pub const fn alternate_bg(&self, i: usize) -> Color {
+        if i.is_multiple_of(2) {
+            self.row_bg
+        } else {
+            self.alt_row_bg
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// A user-defined theme, overriding some colors of a built-in theme.
+///
+/// Colors are names such as `red` or `lightblue`, indexes such as `42`, or hex such as `#1e293b`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeConfig {
+    /// The built-in theme to override, `dark` unless set.
+    pub base: Option<String>,
+    pub header_fg: Option<Color>,
+    pub header_bg: Option<Color>,
+    pub row_bg: Option<Color>,
+    pub alt_row_bg: Option<Color>,
+    pub selected_bg: Option<Color>,
+    pub text: Option<Color>,
+    pub live_fg: Option<Color>,
+    pub live_bg: Option<Color>,
+    pub matched: Option<Color>,
+    pub search_match_bg: Option<Color>,
+    pub dimmed: Option<Color>,
+    pub error: Option<Color>,
+    pub log_error: Option<Color>,
+    pub log_warn: Option<Color>,
+    pub log_info: Option<Color>,
+    pub log_debug: Option<Color>,
+    pub cold_start: Option<Color>,
+}
+
+impl ThemeConfig {
+    /// The theme with these colors, or `None` if the base theme isn't a built-in theme.
+    pub fn to_theme(&self) -> Option<Theme> {
+        let mut theme = Theme::built_in(self.base.as_deref().unwrap_or(DEFAULT_THEME_NAME))?;
+
+        if let Some(color) = self.header_fg {
+            theme.header = theme.header.fg(color);
+        }
+        if let Some(color) = self.header_bg {
+            theme.header = theme.header.bg(color);
+        }
+        if let Some(color) = self.row_bg {
+            theme.row_bg = color;
+        }
+        if let Some(color) = self.alt_row_bg {
+            theme.alt_row_bg = color;
+        }
+        if let Some(color) = self.selected_bg {
+            theme.selected = theme.selected.bg(color);
+        }
+        if let Some(color) = self.text {
+            theme.text = color;
+        }
+        if let Some(color) = self.live_fg {
+            theme.live = theme.live.fg(color);
+        }
+        if let Some(color) = self.live_bg {
+            theme.live = theme.live.bg(color);
+        }
+        if let Some(color) = self.matched {
+            theme.matched = theme.matched.fg(color);
+        }
+        if let Some(color) = self.search_match_bg {
+            theme.search_match = theme.search_match.bg(color);
+        }
+        if let Some(color) = self.dimmed {
+            theme.dimmed = theme.dimmed.fg(color);
+        }
+        if let Some(color) = self.error {
+            theme.error = theme.error.fg(color);
+        }
+        if let Some(color) = self.log_error {
+            theme.log_error = color;
+        }
+        if let Some(color) = self.log_warn {
+            theme.log_warn = color;
+        }
+        if let Some(color) = self.log_info {
+            theme.log_info = color;
+        }
+        if let Some(color) = self.log_debug {
+            theme.log_debug = color;
+        }
+        if let Some(color) = self.cold_start {
+            theme.cold_start = color;
+        }
+
+        Some(theme)
+    }
+}
@@ -0,0 +1,146 @@
+//! Time ranges that log events are loaded, and queried, from.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone as _, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::timestamp::TimeZone;
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+
+/// How far back log events can be loaded from, by label.
+pub const PRESETS: &[(&str, Duration)] = &[
+    ("5m", Duration::from_secs(5 * MINUTE)),
+    ("15m", Duration::from_secs(15 * MINUTE)),
+    ("1h", Duration::from_secs(HOUR)),
+    ("6h", Duration::from_secs(6 * HOUR)),
+    ("24h", Duration::from_secs(DAY)),
+    ("7d", Duration::from_secs(7 * DAY)),
+];
+
+// Formats of times input for a time range, tried in order, such as `2024-06-01 13:00`.
+const INPUT_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+];
+// Format of times of a time range when shown, or edited.
+const DISPLAY_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+// How far back log events are loaded from, unless another time range is picked.
+const DEFAULT_DURATION: Duration = Duration::from_secs(HOUR);
+
+/// The time range to load log events from.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TimeRange {
+    /// The most recent duration, up to now.
+    Last(Duration),
+    /// From the start to the end, in milliseconds since the Unix epoch.
+    Between { start: i64, end: i64 },
+}
+
+impl Default for TimeRange {
+    fn default() -> Self {
+        Self::Last(DEFAULT_DURATION)
+    }
+}
+
+impl TimeRange {
+    /// The time range, as shown in pane titles, such as `last 1h`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Last(duration) => {
+                let preset = PRESETS
+                    .iter()
+                    .find(|(_, preset_duration)| preset_duration == duration);
+                match preset {
+                    Some((label, _)) => format!("last {label}"),
+                    None => format!("last {}m", duration.as_secs() / MINUTE),
+                }
+            }
+            Self::Between { start, end } => format!(
+                "{} to {} UTC",
+                format_time(*start, TimeZone::Utc),
+                format_time(*end, TimeZone::Utc)
+            ),
+        }
+    }
+
+    /// The start and end of the time range as of `now`, in milliseconds since the Unix epoch.
+    ///
+    /// There is no end when the time range is up to now, so newer events are included too.
+    pub fn bounds(&self, now: SystemTime) -> (i64, Option<i64>) {
+        match self {
+            Self::Last(duration) => (epoch_millis(now - *duration), None),
+            Self::Between { start, end } => (*start, Some(*end)),
+        }
+    }
+}
+
+/// The duration `input`, a number of seconds, minutes, hours, or days such as `15m` or `7d`.
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let input = input.trim();
+    let unit = match input.chars().last()? {
+        's' => 1,
+        'm' => MINUTE,
+        'h' => HOUR,
+        'd' => DAY,
+        _ => return None,
+    };
+    let count: u64 = input[..input.len() - 1].parse().ok()?;
+
+    Some(Duration::from_secs(count.checked_mul(unit)?))
+}
+
+/// The time `input`, such as `2024-06-01 13:00`, in milliseconds since the Unix epoch.
+///
+/// Times without an offset are in `time_zone`, and dates alone are at midnight.
+pub fn parse_time(input: &str, time_zone: TimeZone) -> Option<i64> {
+    let input = input.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(input) {
+        return Some(time.timestamp_millis());
+    }
+
+    let naive = INPUT_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(input, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        })?;
+    let millis = match time_zone {
+        TimeZone::Utc => naive.and_utc().timestamp_millis(),
+        TimeZone::Local => Local
+            .from_local_datetime(&naive)
+            .earliest()?
+            .timestamp_millis(),
+    };
+
+    Some(millis)
+}
+
+/// The time `millis`, in milliseconds since the Unix epoch, as it is input in `time_zone`.
+pub fn format_time(millis: i64, time_zone: TimeZone) -> String {
+    let Some(time) = DateTime::<Utc>::from_timestamp_millis(millis) else {
+        return String::new();
+    };
+
+    match time_zone {
+        TimeZone::Utc => time.format(DISPLAY_FORMAT).to_string(),
+        TimeZone::Local => time
+            .with_timezone(&Local)
+            .format(DISPLAY_FORMAT)
+            .to_string(),
+    }
+}
+
+/// Milliseconds since the Unix epoch, as used by AWS CloudWatch Logs.
+pub fn epoch_millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
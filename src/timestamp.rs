@@ -0,0 +1,84 @@
+//! Formatting of the timestamps of log events and log streams.
+use chrono::{DateTime, Local, Utc};
+use serde::Deserialize;
+
+/// The time zone timestamps are shown in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeZone {
+    #[default]
+    Utc,
+    Local,
+}
+
+/// Whether timestamps are shown as ISO-8601 times, or as the time elapsed since, like `2m ago`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampStyle {
+    #[default]
+    Iso,
+    Relative,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimestampFormat {
+    pub time_zone: TimeZone,
+    pub style: TimestampStyle,
+}
+
+impl TimestampFormat {
+    /// Switch between UTC and local time.
+    pub fn toggle_time_zone(&mut self) {
+        self.time_zone = match self.time_zone {
+            TimeZone::Utc => TimeZone::Local,
+            TimeZone::Local => TimeZone::Utc,
+        };
+    }
+
+    /// Switch between ISO-8601 and relative times.
+    pub fn toggle_style(&mut self) {
+        self.style = match self.style {
+            TimestampStyle::Iso => TimestampStyle::Relative,
+            TimestampStyle::Relative => TimestampStyle::Iso,
+        };
+    }
+
+    /// Width of every formatted timestamp, to line them up in a column.
+    pub fn width(&self) -> usize {
+        match (self.style, self.time_zone) {
+            (TimestampStyle::Relative, _) => 8,
+            (TimestampStyle::Iso, TimeZone::Utc) => 24,
+            (TimestampStyle::Iso, TimeZone::Local) => 29,
+        }
+    }
+
+    /// The timestamp `millis`, in milliseconds since the Unix epoch, as of `now`.
+    pub fn format(&self, millis: i64, now: DateTime<Utc>) -> String {
+        let Some(time) = DateTime::from_timestamp_millis(millis) else {
+            return String::new();
+        };
+
+        match (self.style, self.time_zone) {
+            (TimestampStyle::Relative, _) => format_elapsed(now - time),
+            (TimestampStyle::Iso, TimeZone::Utc) => {
+                time.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+            }
+            (TimestampStyle::Iso, TimeZone::Local) => time
+                .with_timezone(&Local)
+                .format("%Y-%m-%dT%H:%M:%S%.3f%:z")
+                .to_string(),
+        }
+    }
+}
+
+/// The `elapsed` time in its largest unit, such as `2m ago`.
+fn format_elapsed(elapsed: chrono::TimeDelta) -> String {
+    let seconds = elapsed.num_seconds();
+    match seconds {
+        ..0 => "future".to_string(),
+        0..60 => format!("{seconds}s ago"),
+        60..3_600 => format!("{}m ago", seconds / 60),
+        3_600..86_400 => format!("{}h ago", seconds / 3_600),
+        _ => format!("{}d ago", seconds / 86_400),
+    }
+}